@@ -2,9 +2,30 @@
 //!
 //! Blueprint can be used to create a Balancer style linear bootstrap pool, where the weights of the pool change linearly over time.
 //! This can be used to distribute tokens in a fair way, while only needed a small initial (liquidity) investment.
+//!
+//! Optionally, late participants can contribute single-sided liquidity of the paired resource (the resource of which only a small initial amount was provided).
+//! Part of such a contribution is swapped against the pool to contribute both resources, so it experiences price impact comparable to a regular swap of that part.
+//! The contributor receives a claim token, redeemable for their share of the pool after the bootstrap has finished.
+//!
+//! Optionally, buyers can be rewarded for holding on to their purchase. While a participation bonus is set, buying the bootstrapped resource mints a participation NFT recording the purchase.
+//! The NFT can't be transferred, and after the bootstrap has finished it is redeemable for a bonus proportional to the purchase, at the bonus rate set at the time of purchase.
+//! If the bonus vault can't pay the whole bonus, the available part is paid and the rest stays claimable.
+//! If holding is required, purchases are escrowed until the bootstrap has finished and released on the first claim, whether or not the bonus is funded, so tokens can't be sold and bought again to collect multiple bonuses.
 
 use scrypto::prelude::*;
 
+/// Participation NFT structure, minted to buyers of the bootstrapped resource while a participation bonus is set.
+#[derive(ScryptoSbor, NonFungibleData)]
+pub struct BootstrapParticipation {
+    pub purchase_amount: Decimal,
+    pub purchased_at: Instant,
+    pub bonus_rate: Decimal,
+    #[mutable]
+    pub escrowed: bool,
+    #[mutable]
+    pub bonus_paid: Decimal,
+}
+
 #[blueprint]
 #[types(
     u64,
@@ -20,6 +41,17 @@ mod bootstrap {
             send_raised_liquidity => restrict_to: [OWNER];
             start_bootstrap => PUBLIC;
             reclaim_initial => PUBLIC;
+            get_reclaimable_amounts => PUBLIC;
+            get_reclaimable => PUBLIC;
+            get_post_bootstrap_balances => PUBLIC;
+            is_bootstrap_active => PUBLIC;
+            add_single_sided => PUBLIC;
+            redeem_single_sided => PUBLIC;
+            set_single_sided => restrict_to: [OWNER];
+            fund_participation_bonus => PUBLIC;
+            claim_participation_bonus => PUBLIC;
+            set_participation_bonus => restrict_to: [OWNER];
+            prune_ledger => restrict_to: [OWNER];
         }
     }
 
@@ -77,6 +109,8 @@ mod bootstrap {
         ledger: KeyValueStore<u64, Vec<(Decimal, (Decimal, Decimal))>>,
         /// counter for the ledger, so a single vec doesn't experience some tasty state explosion...
         ledger_counter: u64,
+        /// first ledger counter that hasn't been pruned
+        ledger_pruned_until: u64,
         /// whether initial contribution is returned to the provider
         refund_initial: bool,
         /// vault for resource 1, after bootstrap has ended
@@ -85,6 +119,24 @@ mod bootstrap {
         resource2_vault: Vault,
         /// vault for mother refund
         mother_refund_vault: Vault,
+        /// whether single-sided contributions of the paired resource are allowed
+        single_sided_enabled: bool,
+        /// vault holding the LP tokens of single-sided contributors
+        single_sided_lp_vault: Vault,
+        /// resource manager of the claims on single-sided contributions, redeemable 1:1 for LP tokens after the bootstrap
+        single_sided_claim_manager: ResourceManager,
+        /// resource manager of the participation NFTs, redeemable for a bonus after the bootstrap
+        participation_manager: ResourceManager,
+        /// counter for the participation NFTs
+        participation_counter: u64,
+        /// bonus per purchased token paid to participants, participation NFTs are only minted if set
+        participation_bonus: Option<Decimal>,
+        /// whether purchases are escrowed until the bootstrap has finished, so participants have to hold their purchased tokens to earn their bonus
+        participation_requires_holding: bool,
+        /// vault holding the participation bonuses, in the bootstrapped resource
+        participation_bonus_vault: Vault,
+        /// vault holding the escrowed purchases of participants, in the bootstrapped resource
+        participation_escrow_vault: Vault,
     }
 
     impl LinearBootstrapPool {
@@ -179,6 +231,51 @@ mod bootstrap {
             let (lp_bucket, little_idiot_bucket): (Bucket, Option<Bucket>) = bootstrap_badge
                 .authorize_with_all(|| pool_component.contribute((resource1, resource2)));
 
+            let single_sided_claim_manager: ResourceManager =
+                ResourceBuilder::new_fungible(OwnerRole::None)
+                    .divisibility(DIVISIBILITY_MAXIMUM)
+                    .metadata(metadata! (
+                        init {
+                            "name" => "bootstrap contribution claim", locked;
+                            "symbol" => "BCLAIM", locked;
+                        }
+                    ))
+                    .mint_roles(mint_roles!(
+                        minter => rule!(require(global_caller(component_address)));
+                        minter_updater => rule!(deny_all);
+                    ))
+                    .burn_roles(burn_roles!(
+                        burner => rule!(require(global_caller(component_address)));
+                        burner_updater => rule!(deny_all);
+                    ))
+                    .create_with_no_initial_supply()
+                    .into();
+
+            let participation_manager: ResourceManager =
+                ResourceBuilder::new_integer_non_fungible::<BootstrapParticipation>(
+                    OwnerRole::None,
+                )
+                .metadata(metadata! (
+                    init {
+                        "name" => "bootstrap participation", locked;
+                        "symbol" => "BPART", locked;
+                    }
+                ))
+                .mint_roles(mint_roles!(
+                    minter => rule!(require(global_caller(component_address)));
+                    minter_updater => rule!(deny_all);
+                ))
+                .withdraw_roles(withdraw_roles!(
+                    withdrawer => rule!(deny_all);
+                    withdrawer_updater => rule!(deny_all);
+                ))
+                .non_fungible_data_update_roles(non_fungible_data_update_roles!(
+                    non_fungible_data_updater => rule!(require(global_caller(component_address)));
+                    non_fungible_data_updater_updater => rule!(deny_all);
+                ))
+                .create_with_no_initial_supply()
+                .into();
+
             let component = Self {
                 pool_component,
                 fee,
@@ -195,16 +292,26 @@ mod bootstrap {
                 end: None,
                 initial_little_address,
                 initial_little_amount,
+                single_sided_lp_vault: Vault::new(lp_bucket.resource_address()),
                 lp_vault: Vault::with_bucket(lp_bucket),
                 reclaimable_resource: Vault::new(initial_little_address),
                 bootstrap_badge_vault: Vault::with_bucket(bootstrap_badge.take(1)),
                 oci_dapp_definition,
                 ledger,
                 ledger_counter,
+                ledger_pruned_until: 0,
                 refund_initial,
                 resource1_vault: Vault::new(resource1_address),
                 resource2_vault: Vault::new(resource2_address),
                 mother_refund_vault: Vault::new(initial_big_address),
+                single_sided_enabled: false,
+                single_sided_claim_manager,
+                participation_manager,
+                participation_counter: 0,
+                participation_bonus: None,
+                participation_requires_holding: false,
+                participation_bonus_vault: Vault::new(initial_big_address),
+                participation_escrow_vault: Vault::new(initial_big_address),
             }
             .instantiate()
             .prepare_to_globalize(OwnerRole::Fixed(rule!(require(dao_admin_badge))))
@@ -242,70 +349,280 @@ mod bootstrap {
         ///
         /// # Input
         /// - `input_bucket`: Bucket containing the input resource
+        /// - `min_output`: Minimum amount of the output resource to receive
         ///
         /// # Output
-        /// - `Bucket`: Bucket containing the output resource
+        /// - `Bucket`: Bucket containing the output resource (empty if the purchase is escrowed)
+        /// - `Option<Bucket>`: Participation NFT, if the bootstrapped resource was bought while a participation bonus is set
         ///
         /// # Logic
+        /// - Checks whether the bootstrap is active, swapping is disabled once its scheduled end has passed
         /// - Updates the weights of the pool
-        /// - Calculates the output amount based on the input amount and the reserves
+        /// - Calculates the output amount based on the input amount, the reserves and the weights
+        /// - Checks whether the output amount is at least the minimum output
         /// - Deposits the input resource in the pool
         /// - Withdraws the output resource from the pool
         /// - Calculates the output resource
         /// - Updates the ledger with the new reserves, used to keep track of price history
+        /// - If the bootstrapped resource was bought while a participation bonus is set, mints a participation NFT recording the purchase and the current bonus rate
+        /// - If holding is required, escrows the purchase until the bootstrap has finished
         /// - Returns the output resource
-        pub fn swap(&mut self, input_bucket: Bucket) -> Bucket {
-            assert!(self.end.is_none(), "Bootstrap already finished.");
+        pub fn swap(
+            &mut self,
+            input_bucket: Bucket,
+            min_output: Decimal,
+        ) -> (Bucket, Option<Bucket>) {
+            assert!(
+                self.is_bootstrap_active(),
+                "Bootstrap is not active, it has not started or its end has passed."
+            );
             self.set_weights();
-            let mut reserves = self.vault_reserves();
 
-            let input_reserves = reserves
-                .swap_remove(&input_bucket.resource_address())
-                .expect("Resource does not belong to the pool");
-            let (output_resource_address, output_reserves) = reserves.into_iter().next().unwrap();
+            let (output_resource_address, output_amount): (ResourceAddress, Decimal) =
+                self.calculate_swap(input_bucket.resource_address(), input_bucket.amount());
+            assert!(
+                output_amount >= min_output,
+                "Swap output of {} is below the minimum output of {}.",
+                output_amount,
+                min_output
+            );
 
-            let input_amount = input_bucket.amount();
+            self.deposit(input_bucket);
+            let mut return_bucket: Bucket = self.withdraw(output_resource_address, output_amount);
 
-            // Get the weights based on the resource
-            let (input_weight, output_weight) = if input_bucket.resource_address() == self.resource1
-            {
-                (self.weight1, self.weight2)
+            self.update_ledger();
+
+            let mut participation: Option<Bucket> = None;
+            if let Some(bonus_rate) = self.participation_bonus {
+                if output_resource_address != self.initial_little_address {
+                    let purchase_amount: Decimal = return_bucket.amount();
+                    if self.participation_requires_holding {
+                        self.participation_escrow_vault
+                            .put(return_bucket.take(purchase_amount));
+                    }
+                    self.participation_counter += 1;
+                    participation = Some(self.participation_manager.mint_non_fungible(
+                        &NonFungibleLocalId::integer(self.participation_counter),
+                        BootstrapParticipation {
+                            purchase_amount,
+                            purchased_at: Clock::current_time_rounded_to_seconds(),
+                            bonus_rate,
+                            escrowed: self.participation_requires_holding,
+                            bonus_paid: dec!(0),
+                        },
+                    ));
+                }
+            }
+
+            (return_bucket, participation)
+        }
+
+        /// Contributes single-sided liquidity of the paired resource to the pool.
+        ///
+        /// # Input
+        /// - `payment`: Bucket containing the paired resource (the resource with the small initial amount)
+        ///
+        /// # Output
+        /// - `Bucket`: Claim tokens, redeemable for the contributed liquidity after the bootstrap
+        /// - `Option<Bucket>`: Leftover of the payment that couldn't be contributed
+        ///
+        /// # Logic
+        /// - Checks whether single-sided contributions are enabled and the bootstrap is ongoing
+        /// - Updates the weights of the pool
+        /// - Swaps the part of the payment corresponding to the weight of the other resource against the pool (this part experiences price impact like a normal swap)
+        /// - Contributes the rest of the payment and the swapped resource to the pool
+        /// - Stores the LP tokens and mints an equal amount of claim tokens
+        /// - Updates the ledger with the new reserves
+        pub fn add_single_sided(&mut self, mut payment: Bucket) -> (Bucket, Option<Bucket>) {
+            assert!(
+                self.single_sided_enabled,
+                "Single-sided contributions are not enabled."
+            );
+            assert!(
+                self.is_bootstrap_active(),
+                "Bootstrap is not active, it has not started or its end has passed."
+            );
+            assert!(
+                payment.resource_address() == self.initial_little_address,
+                "Only the paired resource can be contributed single-sided."
+            );
+            self.set_weights();
+
+            let other_weight: Decimal = if payment.resource_address() == self.resource1 {
+                self.weight2
             } else {
-                (self.weight2, self.weight1)
+                self.weight1
             };
+            let to_swap: Bucket = payment.take_advanced(
+                payment.amount() * other_weight,
+                WithdrawStrategy::Rounded(RoundingMode::ToZero),
+            );
 
-            // Balancer-style swap formula considering weights
-            let output_amount =
-                (input_amount * output_reserves * output_weight * (dec!("1") - self.fee))
-                    / (input_reserves * input_weight
-                        + input_amount * output_weight * (dec!("1") - self.fee));
+            let (other_address, other_amount): (ResourceAddress, Decimal) =
+                self.calculate_swap(to_swap.resource_address(), to_swap.amount());
+            self.deposit(to_swap);
+            let other: Bucket = self.withdraw(other_address, other_amount);
 
-            self.deposit(input_bucket);
-            let return_bucket: Bucket = self.withdraw(output_resource_address, output_amount);
+            let (lp_bucket, leftover): (Bucket, Option<Bucket>) =
+                if other.resource_address() == self.resource1 {
+                    self.pool_component.contribute((other, payment))
+                } else {
+                    self.pool_component.contribute((payment, other))
+                };
 
-            reserves = self.vault_reserves();
-            let resource1_reserve = *reserves.get(&self.resource1).unwrap();
-            let resource2_reserve = *reserves.get(&self.resource2).unwrap();
-            let progress = self.get_progress();
+            let claim: Bucket = self.single_sided_claim_manager.mint(lp_bucket.amount());
+            self.single_sided_lp_vault.put(lp_bucket);
 
-            if self.ledger.get(&self.ledger_counter).is_some() {
-                let mut ledger_vector = self.ledger.get_mut(&self.ledger_counter).unwrap();
-                if ledger_vector.len() > 99 {
-                    self.ledger_counter += 1;
-                }
-                ledger_vector.push((progress, (resource1_reserve, resource2_reserve)));
-            } else {
-                self.ledger.insert(
-                    self.ledger_counter,
-                    vec![(progress, (resource1_reserve, resource2_reserve))],
-                );
+            self.update_ledger();
+
+            (claim, leftover)
+        }
+
+        /// Redeems claim tokens for the single-sided liquidity they represent.
+        ///
+        /// # Input
+        /// - `claim`: Bucket containing the claim tokens
+        ///
+        /// # Output
+        /// - `Bucket`: Bucket containing the first resource
+        /// - `Bucket`: Bucket containing the second resource
+        ///
+        /// # Logic
+        /// - Checks if the bootstrap has finished
+        /// - Burns the claim tokens
+        /// - Redeems an equal amount of the stored LP tokens from the pool component
+        pub fn redeem_single_sided(&mut self, claim: Bucket) -> (Bucket, Bucket) {
+            assert!(self.end.is_some(), "Bootstrap not finished yet.");
+            assert!(
+                claim.resource_address() == self.single_sided_claim_manager.address(),
+                "Invalid claim supplied."
+            );
+            let lp_amount: Decimal = claim.amount();
+            claim.burn();
+            self.pool_component
+                .redeem(self.single_sided_lp_vault.take(lp_amount))
+        }
+
+        /// Enables or disables single-sided contributions.
+        pub fn set_single_sided(&mut self, enabled: bool) {
+            self.single_sided_enabled = enabled;
+        }
+
+        /// Sets the bonus per purchased token paid to participants (none to stop minting participation NFTs), and whether purchases are escrowed until the bootstrap has finished.
+        pub fn set_participation_bonus(&mut self, bonus: Option<Decimal>, requires_holding: bool) {
+            if let Some(bonus) = bonus {
+                assert!(bonus >= dec!(0), "Participation bonus can't be negative.");
             }
+            self.participation_bonus = bonus;
+            self.participation_requires_holding = requires_holding;
+        }
+
+        /// Adds bootstrapped tokens to the participation bonus vault, from which participation bonuses are paid.
+        pub fn fund_participation_bonus(&mut self, bonus_tokens: Bucket) {
+            self.participation_bonus_vault.put(bonus_tokens);
+        }
+
+        /// Claims the participation bonus of a participation NFT.
+        ///
+        /// # Input
+        /// - `participation_proof`: Proof of the participation NFT
+        ///
+        /// # Output
+        /// - `Bucket`: Bucket containing the participation bonus, and the escrowed purchase if it was escrowed
+        ///
+        /// # Logic
+        /// - Checks if the bootstrap has finished
+        /// - Checks if the participation NFT is valid
+        /// - Pays the unpaid part of the bonus for the recorded purchase at the recorded bonus rate, as far as the participation bonus vault can pay it, so the rest stays claimable once the vault is funded
+        /// - Releases the escrowed purchase, regardless of whether the bonus is funded
+        /// - Checks if anything was paid or released, and records it on the participation NFT
+        pub fn claim_participation_bonus(
+            &mut self,
+            participation_proof: NonFungibleProof,
+        ) -> Bucket {
+            assert!(self.end.is_some(), "Bootstrap not finished yet.");
+            let participation_proof = participation_proof.check_with_message(
+                self.participation_manager.address(),
+                "Invalid participation NFT supplied.",
+            );
+            let participation = participation_proof.non_fungible::<BootstrapParticipation>();
+            let participation_data: BootstrapParticipation = participation.data();
+
+            let unpaid_bonus: Decimal = participation_data.purchase_amount
+                * participation_data.bonus_rate
+                - participation_data.bonus_paid;
+            let mut payout: Bucket = self.participation_bonus_vault.take_advanced(
+                unpaid_bonus.min(self.participation_bonus_vault.amount()),
+                WithdrawStrategy::Rounded(RoundingMode::ToZero),
+            );
+            let bonus_paid: Decimal = payout.amount();
 
-            if self.get_progress() >= dec!(1) {
-                self.finish_bootstrap();
+            if participation_data.escrowed {
+                payout.put(
+                    self.participation_escrow_vault
+                        .take(participation_data.purchase_amount),
+                );
+                self.participation_manager.update_non_fungible_data(
+                    participation.local_id(),
+                    "escrowed",
+                    false,
+                );
             }
 
-            return_bucket
+            assert!(
+                payout.amount() > dec!(0),
+                "No participation bonus left to claim."
+            );
+
+            self.participation_manager.update_non_fungible_data(
+                participation.local_id(),
+                "bonus_paid",
+                participation_data.bonus_paid + bonus_paid,
+            );
+
+            payout
+        }
+
+        /// Prunes ledger entries recorded before a cutoff, to prevent unbounded state growth.
+        /// Pruning invalidates any price history (and TWAP) before the cutoff.
+        ///
+        /// # Input
+        /// - `before`: Cutoff time, entries recorded before this time are removed
+        ///
+        /// # Output
+        /// - None
+        ///
+        /// # Logic
+        /// - Checks if the bootstrap has finished
+        /// - Converts the cutoff time to bootstrap progress, as ledger entries are recorded by progress
+        /// - Removes all ledger vectors that only hold entries before the cutoff
+        /// - Removes entries before the cutoff from the first ledger vector that holds entries after the cutoff
+        pub fn prune_ledger(&mut self, before: Instant) {
+            assert!(self.end.is_some(), "Bootstrap not finished yet.");
+            let start = self.start.unwrap();
+            let cutoff: Decimal = Decimal::from(
+                before.seconds_since_unix_epoch - start.seconds_since_unix_epoch,
+            ) / Decimal::from(
+                start.add_days(self.duration).unwrap().seconds_since_unix_epoch
+                    - start.seconds_since_unix_epoch,
+            );
+
+            while self.ledger_pruned_until <= self.ledger_counter {
+                let fully_pruned: bool = match self.ledger.get_mut(&self.ledger_pruned_until) {
+                    Some(mut ledger_vector) => {
+                        ledger_vector.retain(|(progress, _)| *progress >= cutoff);
+                        ledger_vector.is_empty()
+                    }
+                    None => true,
+                };
+
+                if !fully_pruned {
+                    break;
+                }
+
+                self.ledger.remove(&self.ledger_pruned_until);
+                self.ledger_pruned_until += 1;
+            }
         }
 
         /// Returns the price of the first resource in the pool.
@@ -338,14 +655,18 @@ mod bootstrap {
         /// - None
         ///
         /// # Logic
+        /// - Checks whether the scheduled end of the bootstrap has passed, after which anyone can finish it
+        /// - Clamps the weights of the pool to the target weights
         /// - Redeems the LP tokens from the pool component
-        /// - Checks which resource has the initial_little_amount and puts it in the reclaimable_resource vault
-        /// - Puts the other resource in the resource vaults and mother refund vault, to send to Dex and DAO respectively
+        /// - If the initial contribution is refunded, checks which resource has the initial_little_amount and puts it in the reclaimable_resource vault, and puts the other resource's equivalent at the final price in the mother refund vault
+        /// - If the initial contribution is not refunded, it stays part of the raised liquidity
+        /// - Puts the remaining resources in the resource vaults, to send to Dex or DAO
         pub fn finish_bootstrap(&mut self) {
             let progress = self.get_progress();
             assert!(self.end.is_none(), "Bootstrap already finished before.");
             assert!(progress >= dec!(1), "Bootstrap not ready to finish yet.");
             self.end = Some(Clock::current_time_rounded_to_seconds());
+            self.set_weights();
 
             let (mut resource1, mut resource2): (Bucket, Bucket) =
                 self.pool_component.redeem(self.lp_vault.take_all());
@@ -383,8 +704,9 @@ mod bootstrap {
         ///
         /// # Logic
         /// - Checks if the bootstrap has already finished
-        /// - Sends the LP tokens to the DEX
-        /// - Returns mother refund and resulting LP tokens
+        /// - Takes the mother refund (only filled if the initial contribution is refunded), which is returned separately regardless of the DEX flag
+        /// - If sending to the DEX, creates a DEX pool with the raised liquidity and returns the resulting LP tokens
+        /// - If not sending to the DEX, returns the raised resources themselves
         pub fn send_raised_liquidity(
             &mut self,
             to_dex: bool,
@@ -448,16 +770,86 @@ mod bootstrap {
         /// - `Bucket`: Bucket containing the initial resources
         ///
         /// # Logic
+        /// - Checks if the bootstrap has finished and the initial contribution is refundable
         /// - Checks if the bootstrap badge is correct
         /// - Puts the bootstrap badge in the bootstrap_badge_vault
         /// - Takes all resources from the reclaimable_resource vault
         pub fn reclaim_initial(&mut self, boot_badge: Bucket) -> Bucket {
             assert!(self.end.is_some(), "Bootstrap not finished yet.");
+            assert!(
+                self.refund_initial,
+                "Initial contribution is not refunded, it is part of the raised liquidity."
+            );
             assert!(boot_badge.resource_address() == self.bootstrap_badge_vault.resource_address());
             self.bootstrap_badge_vault.put(boot_badge);
             self.reclaimable_resource.take_all()
         }
 
+        /// Returns the amounts that are refunded after the bootstrap has finished.
+        ///
+        /// # Input
+        /// - None
+        ///
+        /// # Output
+        /// - `bool`: Whether the initial contribution is refunded
+        /// - `Decimal`: Amount of the initial little resource reclaimable with the bootstrap badge
+        /// - `Decimal`: Amount of the other resource refunded to the DAO when the raised liquidity is sent
+        ///
+        /// # Logic
+        /// - Returns the amounts in the reclaimable_resource and mother_refund vaults, which are only filled when the bootstrap finishes with the initial contribution being refunded
+        pub fn get_reclaimable_amounts(&self) -> (bool, Decimal, Decimal) {
+            let (reclaimable, mother_refund): (Decimal, Decimal) = self.get_reclaimable();
+            (self.refund_initial, reclaimable, mother_refund)
+        }
+
+        /// Returns the balances of the reclaimable and mother refund vaults.
+        ///
+        /// # Input
+        /// - None
+        ///
+        /// # Output
+        /// - `Decimal`: Amount of the initial little resource that `reclaim_initial` hands to the bootstrap badge holder
+        /// - `Decimal`: Amount of the other resource that `send_raised_liquidity` refunds to the DAO
+        ///
+        /// # Logic
+        /// - Returns the amounts in the reclaimable_resource and mother_refund vaults
+        pub fn get_reclaimable(&self) -> (Decimal, Decimal) {
+            (
+                self.reclaimable_resource.amount(),
+                self.mother_refund_vault.amount(),
+            )
+        }
+
+        /// Returns the balances of the raised liquidity after the bootstrap has finished.
+        ///
+        /// # Input
+        /// - None
+        ///
+        /// # Output
+        /// - `Decimal`: Amount of resource1 that `send_raised_liquidity` sends on
+        /// - `Decimal`: Amount of resource2 that `send_raised_liquidity` sends on
+        ///
+        /// # Logic
+        /// - Returns the amounts in the resource1 and resource2 vaults, which are only filled when the bootstrap finishes
+        pub fn get_post_bootstrap_balances(&self) -> (Decimal, Decimal) {
+            (self.resource1_vault.amount(), self.resource2_vault.amount())
+        }
+
+        /// Returns whether the bootstrap is active, being started, not finished, and before its scheduled end.
+        ///
+        /// # Input
+        /// - None
+        ///
+        /// # Output
+        /// - `bool`: Whether the bootstrap is active
+        ///
+        /// # Logic
+        /// - Checks whether the bootstrap has started and hasn't finished
+        /// - Checks whether the scheduled end of the bootstrap (start plus duration) hasn't passed yet
+        pub fn is_bootstrap_active(&self) -> bool {
+            self.start.is_some() && self.end.is_none() && self.get_progress() < dec!(1)
+        }
+
         fn set_weights(&mut self) {
             let progress: Decimal = self.get_progress();
 
@@ -496,6 +888,56 @@ mod bootstrap {
             Decimal::from(elapsed_time) / Decimal::from(time_to_elapse)
         }
 
+        /// Calculates the output of a swap, using the current reserves and weights.
+        fn calculate_swap(
+            &self,
+            input_address: ResourceAddress,
+            input_amount: Decimal,
+        ) -> (ResourceAddress, Decimal) {
+            let mut reserves = self.vault_reserves();
+
+            let input_reserves = reserves
+                .swap_remove(&input_address)
+                .expect("Resource does not belong to the pool");
+            let (output_resource_address, output_reserves) = reserves.into_iter().next().unwrap();
+
+            // Get the weights based on the resource
+            let (input_weight, output_weight) = if input_address == self.resource1 {
+                (self.weight1, self.weight2)
+            } else {
+                (self.weight2, self.weight1)
+            };
+
+            // Balancer-style swap formula considering weights
+            let output_amount =
+                (input_amount * output_reserves * output_weight * (dec!("1") - self.fee))
+                    / (input_reserves * input_weight
+                        + input_amount * output_weight * (dec!("1") - self.fee));
+
+            (output_resource_address, output_amount)
+        }
+
+        /// Updates the ledger with the current reserves, used to keep track of price history.
+        fn update_ledger(&mut self) {
+            let reserves = self.vault_reserves();
+            let resource1_reserve = *reserves.get(&self.resource1).unwrap();
+            let resource2_reserve = *reserves.get(&self.resource2).unwrap();
+            let progress = self.get_progress();
+
+            if self.ledger.get(&self.ledger_counter).is_some() {
+                let mut ledger_vector = self.ledger.get_mut(&self.ledger_counter).unwrap();
+                if ledger_vector.len() > 99 {
+                    self.ledger_counter += 1;
+                }
+                ledger_vector.push((progress, (resource1_reserve, resource2_reserve)));
+            } else {
+                self.ledger.insert(
+                    self.ledger_counter,
+                    vec![(progress, (resource1_reserve, resource2_reserve))],
+                );
+            }
+        }
+
         /// Returns the reserves of the pool.
         fn vault_reserves(&self) -> IndexMap<ResourceAddress, Decimal> {
             self.pool_component.get_vault_amounts()
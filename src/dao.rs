@@ -6,11 +6,22 @@
 use crate::bootstrap::bootstrap::*;
 use crate::governance::governance::*;
 use crate::incentives::incentives::*;
+use crate::incentives::BootstrapBonus;
 use crate::reentrancy::reentrancy::*;
 use crate::staking::staking::*;
 use scrypto::prelude::*;
 
 type AnnouncementType = (String, Option<Vec<File>>);
+type ReserveFloorType = (Decimal, Option<(Decimal, Instant)>);
+
+/// Amount of days that need to pass between proposing and accepting an owner transfer.
+const OWNER_TRANSFER_DELAY: i64 = 7;
+
+/// Amount of days that need to pass before a new reserve floor takes effect.
+const RESERVE_FLOOR_DELAY: i64 = 7;
+
+/// Amount of days over which treasury sends without approval are added up, to compare them to the approval threshold.
+const SEND_APPROVAL_WINDOW: i64 = 1;
 
 /// Job structure, holding all information about a job in the DAO component.
 #[derive(ScryptoSbor)]
@@ -23,10 +34,29 @@ pub struct Job {
     pub recurring: bool,
     pub title: String,
     pub description: String,
+    pub nft_grant: Option<(ResourceAddress, IndexSet<NonFungibleLocalId>)>,
+    pub late_payment_bonus_rate: Option<Decimal>,
 }
 
-/// File structure, holding all information to lookup a file stored on the Radix Ledger.
+/// Pending send structure, holding a treasury send that needs approval by approver badges before it's executed.
 #[derive(ScryptoSbor)]
+pub struct PendingSend {
+    pub address: ResourceAddress,
+    pub tokens: ResourceSpecifier,
+    pub receiver_address: ComponentAddress,
+    pub put_method: String,
+    pub approvals: IndexSet<ResourceAddress>,
+}
+
+/// StakingSystem enum, identifying the two components the DAO offers to stake the mother token with.
+#[derive(ScryptoSbor, PartialEq, Clone, Copy)]
+pub enum StakingSystem {
+    Staking,
+    Incentives,
+}
+
+/// File structure, holding all information to lookup a file stored on the Radix Ledger.
+#[derive(ScryptoSbor, Clone)]
 pub struct File {
     pub kvs_address: String,
     pub component_address: ComponentAddress,
@@ -43,31 +73,63 @@ pub struct File {
     Global<Account>,
     Job,
     AnnouncementType,
+    (Decimal, Decimal),
+    PendingSend,
+    ReserveFloorType,
+    (Decimal, Instant),
 )]
 mod dao {
     enable_method_auth! {
         methods {
             put_tokens => PUBLIC;
             send_tokens => restrict_to: [OWNER];
+            cancel_pending_send => restrict_to: [OWNER];
+            set_send_approval => restrict_to: [OWNER];
+            send_tokens_multi => restrict_to: [OWNER];
             take_tokens => restrict_to: [OWNER];
             create_job => restrict_to: [OWNER];
             employ => restrict_to: [OWNER];
+            set_max_jobs_per_employee => restrict_to: [OWNER];
             fire => restrict_to: [OWNER];
+            set_late_payment_bonus_rate => restrict_to: [OWNER];
+            set_allowed_payment_tokens => restrict_to: [OWNER];
             airdrop_tokens => restrict_to: [OWNER];
+            airdrop_to_components => restrict_to: [OWNER];
             airdrop_membered_tokens => restrict_to: [OWNER];
             airdrop_staked_tokens => restrict_to: [OWNER];
             post_announcement => restrict_to: [OWNER];
             remove_announcement => restrict_to: [OWNER];
             set_update_reward => restrict_to: [OWNER];
+            set_auto_reward_topup => restrict_to: [OWNER];
             add_rewarded_call => restrict_to: [OWNER];
             remove_rewarded_calls => restrict_to: [OWNER];
             set_staking_component => restrict_to: [OWNER];
             set_incentives_component => restrict_to: [OWNER];
             add_claimed_website => restrict_to: [OWNER];
+            add_claimed_entity => restrict_to: [OWNER];
+            set_dao_metadata => restrict_to: [OWNER];
+            set_reserve_floor => restrict_to: [OWNER];
+            propose_owner_transfer => restrict_to: [OWNER];
+            cancel_owner_transfer => restrict_to: [OWNER];
+            accept_owner_transfer => PUBLIC;
             send_salary_to_employee => PUBLIC;
+            migrate_position => PUBLIC;
+            approve_pending_send => PUBLIC;
+            execute_pending_send => PUBLIC;
+            propose_add_stakable => PUBLIC;
+            propose_edit_stakable => PUBLIC;
+            propose_send_tokens => PUBLIC;
+            propose_airdrop_tokens => PUBLIC;
             rewarded_update => PUBLIC;
             use_raised_liquidity => PUBLIC;
             get_token_amount => PUBLIC;
+            get_treasury_flow => PUBLIC;
+            get_payroll_summary => PUBLIC;
+            count_controller_badges => PUBLIC;
+            get_claimed_entities => PUBLIC;
+            can_airdrop => PUBLIC;
+            get_announcements => PUBLIC;
+            get_reserve_floor => PUBLIC;
         }
     }
 
@@ -107,12 +169,38 @@ mod dao {
         pub jobs: KeyValueStore<u64, Job>,
         /// Counter for jobs
         pub job_counter: u64,
+        /// Maximum number of jobs an employee can hold, no cap if None.
+        pub max_jobs_per_employee: Option<u64>,
         /// Governance component of the DAO.
         pub governance: Global<Governance>,
         /// Whether to send LBP liq to dex
         pub send_raised_liquidity_to_dex: bool,
         /// The dapp definition of the DAO.
         pub dapp_def_account: Global<Account>,
+        /// Cumulative inflow and outflow of the DAO treasury per token.
+        pub treasury_flows: KeyValueStore<ResourceAddress, (Decimal, Decimal)>,
+        /// Proposed new owner rule of the DAO components and the time from which it can be accepted.
+        pub pending_owner_transfer: Option<(AccessRule, Instant)>,
+        /// Tokens that can be used for salaries and airdrops, any token if None.
+        pub allowed_payment_tokens: Option<IndexSet<ResourceAddress>>,
+        /// Badges that can approve treasury sends above the approval threshold.
+        pub approver_badges: IndexSet<ResourceAddress>,
+        /// Number of approver badges required to approve a treasury send above the approval threshold.
+        pub required_approvals: u64,
+        /// Amount of a token that can be sent without approval within a send approval window, sends of tokens without a threshold need no approval.
+        pub send_approval_thresholds: IndexMap<ResourceAddress, Decimal>,
+        /// Amount of a token sent without approval in the current send approval window, and the time the window started.
+        pub unapproved_sends: KeyValueStore<ResourceAddress, (Decimal, Instant)>,
+        /// Treasury sends waiting for approval.
+        pub pending_sends: KeyValueStore<u64, PendingSend>,
+        /// Counter for the pending sends.
+        pub pending_send_counter: u64,
+        /// Amount of mother tokens moved from the treasury to the staking reward vault when it runs low, no top-up if zero.
+        pub auto_reward_topup_amount: Decimal,
+        /// Amount of tokens in the staking reward vault below which it is topped up.
+        pub auto_reward_topup_threshold: Decimal,
+        /// Minimum amount of a token the treasury has to keep, paired with a pending new floor and the time from which it takes effect.
+        pub reserve_floors: KeyValueStore<ResourceAddress, ReserveFloorType>,
     }
 
     impl Dao {
@@ -123,6 +211,7 @@ mod dao {
         /// - `founder_allocation`: Percentage of the total supply to allocate to the founder.
         /// - `bootstrap_allocation`: Percentage of the total supply to allocate to the bootstrap pool.
         /// - `staking_allocation`: Percentage of the total supply to allocate to the staking pool.
+        /// - `incentives_allocation`: Percentage of the total supply to allocate to the incentives pool.
         /// - `controller_badge`: Controller badge of the DAO.
         /// - `rewarded_calls`: Method calls that are rewarded.
         /// - `dao_name`: Name of the DAO.
@@ -138,6 +227,7 @@ mod dao {
         /// - the bootstrap badge bucket used to reclaim initial bootstrap funds.
         ///
         /// # Logic
+        /// - Assert that all allocations are between 0 and 1, and sum to at most 1
         /// - Instantiate an AccountLocker
         /// - Mint DAO governance tokens (referred to as mother tokens)
         /// - Create the LinearBootstrapPool for the initial bootstrap
@@ -181,6 +271,23 @@ mod dao {
             ResourceAddress,
             ResourceAddress,
         ) {
+            for allocation in [
+                founder_allocation,
+                bootstrap_allocation,
+                staking_allocation,
+                incentives_allocation,
+            ] {
+                assert!(
+                    allocation >= dec!(0) && allocation <= dec!(1),
+                    "Allocations must be between 0 and 1."
+                );
+            }
+            assert!(
+                founder_allocation + bootstrap_allocation + staking_allocation + incentives_allocation
+                    <= dec!(1),
+                "Allocations can't sum to more than 1 (100% of the supply)."
+            );
+
             let controller_badge_address: ResourceAddress = controller_badge.resource_address();
 
             let (address_reservation, component_address) =
@@ -344,12 +451,27 @@ mod dao {
                 employees: DaoKeyValueStore::new_with_registered_type(),
                 jobs: DaoKeyValueStore::new_with_registered_type(),
                 job_counter: 0,
+                max_jobs_per_employee: None,
                 governance,
                 send_raised_liquidity_to_dex,
                 dapp_def_account,
+                treasury_flows: DaoKeyValueStore::new_with_registered_type(),
+                pending_owner_transfer: None,
+                allowed_payment_tokens: None,
+                approver_badges: IndexSet::new(),
+                required_approvals: 0,
+                send_approval_thresholds: IndexMap::new(),
+                unapproved_sends: DaoKeyValueStore::new_with_registered_type(),
+                pending_sends: DaoKeyValueStore::new_with_registered_type(),
+                pending_send_counter: 0,
+                auto_reward_topup_amount: dec!(0),
+                auto_reward_topup_threshold: dec!(0),
+                reserve_floors: DaoKeyValueStore::new_with_registered_type(),
             }
             .instantiate()
-            .prepare_to_globalize(OwnerRole::Fixed(rule!(require(controller_badge_address))))
+            .prepare_to_globalize(OwnerRole::Updatable(rule!(require(
+                controller_badge_address
+            ))))
             .with_address(address_reservation)
             .metadata(metadata! {
                 init {
@@ -419,9 +541,11 @@ mod dao {
         /// - None
         ///
         /// # Logic
+        /// - Record the inflow of the tokens
         /// - If the resource address of the tokens is already in the vaults, put the tokens into the vault
         /// - Otherwise, create a new vault with the tokens and store it
         pub fn put_tokens(&mut self, tokens: Bucket) {
+            self.record_flow(tokens.resource_address(), tokens.amount(), dec!(0));
             if self.vaults.get(&tokens.resource_address()).is_some() {
                 self.vaults
                     .get_mut(&tokens.resource_address())
@@ -444,8 +568,8 @@ mod dao {
         /// - None
         ///
         /// # Logic
-        /// - Take the tokens from the vault
-        /// - Send the tokens to the receiver using the `put_tokens` method of the receiver component
+        /// - If the tokens sent without approval within the send approval window would exceed the token's approval threshold, store the send as a pending send, to be executed after approval
+        /// - Otherwise, record the tokens as sent without approval and transfer them using the `transfer_tokens` method
         pub fn send_tokens(
             &mut self,
             address: ResourceAddress,
@@ -453,29 +577,151 @@ mod dao {
             receiver_address: ComponentAddress,
             put_method: String,
         ) {
-            let payment: Bucket = match tokens {
-                ResourceSpecifier::Fungible(amount) => self
-                    .vaults
-                    .get_mut(&address)
-                    .unwrap()
-                    .as_fungible()
-                    .take_advanced(
-                        amount,
-                        WithdrawStrategy::Rounded(RoundingMode::ToNegativeInfinity),
-                    )
-                    .into(),
-                ResourceSpecifier::NonFungible(ids) => self
-                    .vaults
-                    .get_mut(&address)
-                    .unwrap()
-                    .as_non_fungible()
-                    .take_non_fungibles(&ids)
-                    .into(),
-            };
+            if self.requires_approval(address, &tokens) {
+                self.pending_sends.insert(
+                    self.pending_send_counter,
+                    PendingSend {
+                        address,
+                        tokens,
+                        receiver_address,
+                        put_method,
+                        approvals: IndexSet::new(),
+                    },
+                );
+                self.pending_send_counter += 1;
+            } else {
+                self.record_unapproved_send(address, &tokens);
+                self.transfer_tokens(address, tokens, receiver_address, put_method);
+            }
+        }
+
+        /// Approves a pending treasury send with an approver badge
+        ///
+        /// # Input
+        /// - `send_id`: ID of the pending send to approve
+        /// - `approver_proof`: Proof of an approver badge
+        ///
+        /// # Output
+        /// - None
+        ///
+        /// # Logic
+        /// - Check whether the proof is of an approver badge
+        /// - Check whether the badge hasn't approved the send yet
+        /// - Add the badge to the approvals of the send
+        pub fn approve_pending_send(&mut self, send_id: u64, approver_proof: Proof) {
+            let approver_badge: ResourceAddress = approver_proof.resource_address();
+            assert!(
+                self.approver_badges.contains(&approver_badge),
+                "Proof is not of an approver badge"
+            );
+            let _checked_proof = approver_proof.check(approver_badge);
+
+            let mut pending_send = self
+                .pending_sends
+                .get_mut(&send_id)
+                .expect("Pending send does not exist");
+            assert!(
+                pending_send.approvals.insert(approver_badge),
+                "Send already approved with this badge"
+            );
+        }
+
+        /// Executes a pending treasury send that has been approved by enough approver badges
+        ///
+        /// # Input
+        /// - `send_id`: ID of the pending send to execute
+        ///
+        /// # Output
+        /// - None
+        ///
+        /// # Logic
+        /// - Remove the pending send
+        /// - Check whether enough approver badges, that are still approver badges, approved the send
+        /// - Transfer the tokens using the `transfer_tokens` method
+        pub fn execute_pending_send(&mut self, send_id: u64) {
+            let pending_send: PendingSend = self
+                .pending_sends
+                .remove(&send_id)
+                .expect("Pending send does not exist");
+            let approvals: u64 = pending_send
+                .approvals
+                .iter()
+                .filter(|badge| self.approver_badges.contains(*badge))
+                .count() as u64;
+            assert!(
+                approvals >= self.required_approvals,
+                "Not enough approvals to execute this send"
+            );
+
+            self.transfer_tokens(
+                pending_send.address,
+                pending_send.tokens,
+                pending_send.receiver_address,
+                pending_send.put_method,
+            );
+        }
+
+        /// Cancel a pending treasury send
+        pub fn cancel_pending_send(&mut self, send_id: u64) {
+            self.pending_sends
+                .remove(&send_id)
+                .expect("Pending send does not exist");
+        }
+
+        /// Set the approver badges, the number of approvals required, and per token the amount that can be sent without approval within a send approval window (no approval needed for tokens without a threshold)
+        pub fn set_send_approval(
+            &mut self,
+            approver_badges: IndexSet<ResourceAddress>,
+            required_approvals: u64,
+            send_approval_thresholds: IndexMap<ResourceAddress, Decimal>,
+        ) {
+            if !send_approval_thresholds.is_empty() {
+                assert!(
+                    required_approvals > 0 && required_approvals <= approver_badges.len() as u64,
+                    "Required approvals must be between 1 and the number of approver badges"
+                );
+            }
+            self.approver_badges = approver_badges;
+            self.required_approvals = required_approvals;
+            self.send_approval_thresholds = send_approval_thresholds;
+        }
+
+        /// Sends tokens from the DAO treasury to a receiver, without checking whether approval is required
+        fn transfer_tokens(
+            &mut self,
+            address: ResourceAddress,
+            tokens: ResourceSpecifier,
+            receiver_address: ComponentAddress,
+            put_method: String,
+        ) {
+            let payment: Bucket = self.withdraw_from_treasury(address, &tokens);
             let receiver: Global<AnyComponent> = Global::from(receiver_address);
             receiver.call_raw::<()>(&put_method, scrypto_args!(payment));
         }
 
+        /// Sends multiple (fungible or non-fungible) tokens from the DAO treasury to a single receiver
+        ///
+        /// # Input
+        /// - `sends`: Addresses of the tokens to send, paired with the tokens to send
+        /// - `receiver_address`: Component address to send tokens to
+        /// - `put_method`: Method of the receiver component to put the tokens in
+        ///
+        /// # Output
+        /// - None
+        ///
+        /// # Logic
+        /// - Send every token to the receiver sequentially, using the `send_tokens` method
+        pub fn send_tokens_multi(
+            &mut self,
+            sends: Vec<(ResourceAddress, ResourceSpecifier)>,
+            receiver_address: ComponentAddress,
+            put_method: String,
+        ) {
+            for (address, tokens) in sends {
+                self.send_tokens(address, tokens, receiver_address, put_method.clone());
+            }
+        }
+
         /// Takes tokens from the DAO treasury
         ///
         /// # Input
@@ -486,32 +732,20 @@ mod dao {
         /// - The tokens taken
         ///
         /// # Logic
-        /// - Take the tokens from the vault
+        /// - Check whether the tokens taken without approval within the send approval window don't exceed the approval threshold, as taken tokens can't wait for approval, and record them
+        /// - Take the tokens from the treasury, which checks whether it still holds at least the reserve floor of the token and records the outflow
         /// - Return the tokens taken
         pub fn take_tokens(
             &mut self,
             address: ResourceAddress,
             tokens: ResourceSpecifier,
         ) -> Bucket {
-            let payment: Bucket = match tokens {
-                ResourceSpecifier::Fungible(amount) => self
-                    .vaults
-                    .get_mut(&address)
-                    .unwrap()
-                    .as_fungible()
-                    .take_advanced(
-                        amount,
-                        WithdrawStrategy::Rounded(RoundingMode::ToNegativeInfinity),
-                    )
-                    .into(),
-                ResourceSpecifier::NonFungible(ids) => self
-                    .vaults
-                    .get_mut(&address)
-                    .unwrap()
-                    .as_non_fungible()
-                    .take_non_fungibles(&ids)
-                    .into(),
-            };
+            assert!(
+                !self.requires_approval(address, &tokens),
+                "Amount exceeds the approval threshold, use send_tokens to send it after approval"
+            );
+            self.record_unapproved_send(address, &tokens);
+            let payment: Bucket = self.withdraw_from_treasury(address, &tokens);
             payment
         }
 
@@ -529,7 +763,7 @@ mod dao {
         /// - Assert that there are less than 21 claimants as airdropping too many at a time fails
         /// - Create a bucket to store the NFTs to airdrop
         /// - Create a map of claimants and their NFTs
-        /// - For each claimant, stake the tokens, lock/vote them if necessary, store the NFTs in the created bucket, and add the claimant to the map
+        /// - For each claimant, check the amount against the token's divisibility, stake the tokens, lock/vote them if necessary, store the NFTs in the created bucket, and add the claimant to the map
         /// - Airdrop the NFTs using the map of claimants and bucket, through the Payment Locker
         pub fn airdrop_membered_tokens(
             &mut self,
@@ -545,13 +779,11 @@ mod dao {
             let mut airdrop_map: IndexMap<Global<Account>, ResourceSpecifier> = IndexMap::new();
 
             for (receiver, amount) in claimants {
-                let payment: Bucket = self
-                    .vaults
-                    .get_mut(&self.mother_token_address)
-                    .unwrap()
-                    .as_fungible()
-                    .take(amount)
-                    .into();
+                Self::assert_divisibility(self.mother_token_address, amount);
+                let payment: Bucket = self.withdraw_from_treasury(
+                    self.mother_token_address,
+                    &ResourceSpecifier::Fungible(amount),
+                );
 
                 let (id_option, _empty_bucket): (Option<Bucket>, Option<Bucket>) =
                     self.staking.stake(payment, None);
@@ -593,6 +825,82 @@ mod dao {
             }
         }
 
+        /// Migrates staked mother tokens between the Staking and Incentives components, without waiting for an unstake delay
+        ///
+        /// # Input
+        /// - `from_system`: Component to take the staked tokens from
+        /// - `to_system`: Component to stake the tokens to
+        /// - `amount`: Amount to migrate (in pool tokens when migrating from the Staking component), everything if more than is staked
+        /// - `from_id_proof`: Proof of the ID holding the stake in the component migrated from
+        /// - `to_id_proof`: Optional proof of the ID to stake to in the component migrated to, a new ID is created if None
+        ///
+        /// # Output
+        /// - The new ID, if no ID to stake to was supplied
+        /// - A potential lock reward from staking to a locked ID
+        ///
+        /// # Logic
+        /// - Assert that the components differ and that the mother token is stakable in the Incentives component
+        /// - Assert that the unstake delay of the component migrated to is at least that of the component migrated from, so migrating can't shorten the wait for unstaked tokens
+        /// - Take the stake out of the ID in the component migrated from, authorized by the controller badge, so the checks of unstaking apply but the unstake delay doesn't
+        /// - Stake the tokens to the ID in the component migrated to, where they are treated as a new stake
+        pub fn migrate_position(
+            &mut self,
+            from_system: StakingSystem,
+            to_system: StakingSystem,
+            amount: Decimal,
+            from_id_proof: NonFungibleProof,
+            to_id_proof: Option<Proof>,
+        ) -> (Option<Bucket>, Option<Bucket>) {
+            assert!(
+                from_system != to_system,
+                "Position must be migrated between different staking systems."
+            );
+            assert!(
+                self.is_stakable(self.mother_token_address),
+                "Mother token is not stakable in the Incentives component."
+            );
+            let (from_delay, to_delay): (i64, i64) = match from_system {
+                StakingSystem::Staking => (
+                    self.staking.get_unstake_delay(),
+                    self.incentives.get_unstake_delay(),
+                ),
+                StakingSystem::Incentives => (
+                    self.incentives.get_unstake_delay(),
+                    self.staking.get_unstake_delay(),
+                ),
+            };
+            assert!(
+                to_delay >= from_delay,
+                "Position can't be migrated to a staking system with a shorter unstake delay."
+            );
+
+            let tokens: Bucket = self
+                .vaults
+                .get_mut(&self.controller_badge_address)
+                .unwrap()
+                .as_fungible()
+                .authorize_with_amount(dec!(1), || match from_system {
+                    StakingSystem::Staking => self.staking.migrate_stake(from_id_proof, amount),
+                    StakingSystem::Incentives => self.incentives.migrate_stake(
+                        from_id_proof,
+                        self.mother_token_address,
+                        amount,
+                    ),
+                });
+
+            match to_system {
+                StakingSystem::Staking => self.staking.stake(tokens, to_id_proof),
+                StakingSystem::Incentives => {
+                    let (id_option, lock_reward, _no_rewards): (
+                        Option<Bucket>,
+                        Option<Bucket>,
+                        Option<FungibleBucket>,
+                    ) = self.incentives.stake(tokens, to_id_proof, false);
+                    (id_option, lock_reward)
+                }
+            }
+        }
+
         /// Staking tokens to receive a Staking ID through the Staking component, and then airdropping them using the Payment Locker
         ///
         /// # Input
@@ -606,9 +914,10 @@ mod dao {
         ///
         /// # Logic
         /// - Assert that there are less than 21 claimants as airdropping too many at a time fails
+        /// - Assert that the token is allowed to be airdropped
         /// - Create a bucket to store the NFTs to airdrop
         /// - Create a map of claimants and their NFTs
-        /// - For each claimant, stake the tokens, lock/vote them if necessary, store the NFTs in the created bucket, and add the claimant to the map
+        /// - For each claimant, check the amount against the token's divisibility, stake the tokens, lock/vote them if necessary, store the NFTs in the created bucket, and add the claimant to the map
         /// - Airdrop the NFTs using the map of claimants and bucket, through the Payment Locker
         pub fn airdrop_staked_tokens(
             &mut self,
@@ -621,23 +930,20 @@ mod dao {
                 claimants.len() < 10,
                 "Too many accounts to airdrop to! Try at most 10."
             );
+            self.assert_allowed_payment_token(address);
             let mut to_airdrop_nfts: Option<Bucket> = None;
             let mut airdrop_map: IndexMap<Global<Account>, ResourceSpecifier> = IndexMap::new();
 
             for (receiver, amount) in claimants {
-                let payment: Bucket = self
-                    .vaults
-                    .get_mut(&address)
-                    .unwrap()
-                    .as_fungible()
-                    .take_advanced(
-                        amount,
-                        WithdrawStrategy::Rounded(RoundingMode::ToNegativeInfinity),
-                    )
-                    .into();
-
-                let (id_option, _empty_bucket): (Option<Bucket>, Option<Bucket>) =
-                    self.incentives.stake(payment, None);
+                Self::assert_divisibility(address, amount);
+                let payment: Bucket =
+                    self.withdraw_from_treasury(address, &ResourceSpecifier::Fungible(amount));
+
+                let (id_option, _empty_bucket, _no_rewards): (
+                    Option<Bucket>,
+                    Option<Bucket>,
+                    Option<FungibleBucket>,
+                ) = self.incentives.stake(payment, None, false);
                 let staking_id: Bucket = id_option.unwrap();
                 let staking_id_id: NonFungibleLocalId =
                     staking_id.as_non_fungible().non_fungible_local_id();
@@ -681,6 +987,57 @@ mod dao {
             }
         }
 
+        /// Checks whether the treasury holds enough tokens for an airdrop
+        ///
+        /// # Input
+        /// - `claimants`: Claimants and amount/id of tokens to airdrop to them
+        /// - `address`: Address of the tokens to airdrop
+        ///
+        /// # Output
+        /// - Whether the treasury holds enough tokens
+        /// - The shortfall, being the missing amount of tokens plus the number of missing non-fungibles
+        ///
+        /// # Logic
+        /// - Sum the amounts of all claimants, counting every non-fungible id as one
+        /// - Count the non-fungible ids of all claimants that are not in the vault, which are all of them for a fungible token
+        /// - Compare the amount of tokens that are in the vault to the amount available above the reserve floor
+        pub fn can_airdrop(
+            &self,
+            claimants: IndexMap<Global<Account>, ResourceSpecifier>,
+            address: ResourceAddress,
+        ) -> (bool, Decimal) {
+            let vault = self.vaults.get(&address);
+            let is_fungible: bool = matches!(
+                ResourceManager::from(address).resource_type(),
+                ResourceType::Fungible { .. }
+            );
+            let mut airdrop_amount: Decimal = dec!(0);
+            let mut missing_non_fungibles: Decimal = dec!(0);
+
+            for (_receiver, specifier) in &claimants {
+                airdrop_amount += Self::specified_amount(specifier);
+                if let ResourceSpecifier::NonFungible(ids) = specifier {
+                    for id in ids {
+                        let in_vault: bool = !is_fungible
+                            && vault.as_ref().map_or(false, |vault| {
+                                vault.as_non_fungible().contains_non_fungible(id)
+                            });
+                        if !in_vault {
+                            missing_non_fungibles += dec!(1);
+                        }
+                    }
+                }
+            }
+
+            let vault_amount: Decimal = vault.map_or(dec!(0), |vault| vault.amount());
+            let available: Decimal = (vault_amount - self.get_reserve_floor(address)).max(dec!(0));
+            let shortfall: Decimal = (airdrop_amount - missing_non_fungibles - available)
+                .max(dec!(0))
+                + missing_non_fungibles;
+
+            (shortfall == dec!(0), shortfall)
+        }
+
         /// Airdropping tokens through the Payment Locker
         ///
         /// # Input
@@ -692,8 +1049,9 @@ mod dao {
         ///
         /// # Logic
         /// - Assert that there are less than 31 claimants as airdropping too many at a time fails
+        /// - Assert that the token is allowed to be airdropped
         /// - Create a bucket to store the tokens to airdrop
-        /// - For each claimant take their to be airdropped tokens from the vault and put them in the bucket
+        /// - For each claimant check fungible amounts against the token's divisibility, take their to be airdropped tokens from the treasury (respecting the reserve floor) and put them in the bucket
         /// - Airdrop the tokens using the map of claimants and bucket, through the Payment Locker
         pub fn airdrop_tokens(
             &mut self,
@@ -704,39 +1062,17 @@ mod dao {
                 claimants.len() < 15,
                 "Too many accounts to airdrop to! Try at most 15."
             );
+            self.assert_allowed_payment_token(address);
             let mut to_airdrop_tokens: Option<Bucket> = None;
 
             for (_receiver, specifier) in &claimants {
-                match specifier {
-                    ResourceSpecifier::Fungible(amount) => {
-                        let payment: Bucket = self
-                            .vaults
-                            .get_mut(&address)
-                            .unwrap()
-                            .as_fungible()
-                            .take_advanced(
-                                *amount,
-                                WithdrawStrategy::Rounded(RoundingMode::ToNegativeInfinity),
-                            )
-                            .into();
-                        match &mut to_airdrop_tokens {
-                            Some(bucket) => bucket.put(payment),
-                            None => to_airdrop_tokens = Some(payment),
-                        }
-                    }
-                    ResourceSpecifier::NonFungible(ids) => {
-                        let payment: Bucket = self
-                            .vaults
-                            .get_mut(&address)
-                            .unwrap()
-                            .as_non_fungible()
-                            .take_non_fungibles(&ids)
-                            .into();
-                        match &mut to_airdrop_tokens {
-                            Some(bucket) => bucket.put(payment),
-                            None => to_airdrop_tokens = Some(payment),
-                        }
-                    }
+                if let ResourceSpecifier::Fungible(amount) = specifier {
+                    Self::assert_divisibility(address, *amount);
+                }
+                let payment: Bucket = self.withdraw_from_treasury(address, specifier);
+                match &mut to_airdrop_tokens {
+                    Some(bucket) => bucket.put(payment),
+                    None => to_airdrop_tokens = Some(payment),
                 }
             }
             if let Some(to_airdrop_tokens) = to_airdrop_tokens {
@@ -745,16 +1081,54 @@ mod dao {
             }
         }
 
+        /// Airdropping tokens to components, instead of accounts
+        ///
+        /// # Input
+        /// - `targets`: Components and amount/id of tokens to airdrop to them
+        /// - `address`: Address of the tokens to airdrop
+        /// - `put_method`: Method of the target components to put the tokens in
+        ///
+        /// # Output
+        /// - None
+        ///
+        /// # Logic
+        /// - Assert that there are less than 15 targets as airdropping too many at a time fails
+        /// - Assert that the token is allowed to be airdropped
+        /// - For each target check fungible amounts against the token's divisibility and send their tokens using the `send_tokens` method
+        pub fn airdrop_to_components(
+            &mut self,
+            targets: IndexMap<ComponentAddress, ResourceSpecifier>,
+            address: ResourceAddress,
+            put_method: String,
+        ) {
+            assert!(
+                targets.len() < 15,
+                "Too many components to airdrop to! Try at most 14."
+            );
+            self.assert_allowed_payment_token(address);
+
+            for (receiver, specifier) in targets {
+                if let ResourceSpecifier::Fungible(amount) = specifier {
+                    Self::assert_divisibility(address, amount);
+                }
+                self.send_tokens(address, specifier, receiver, put_method.clone());
+            }
+        }
+
         /// Creates a job (and can immediately employ if so desired)
         ///
         /// # Input
         /// - `job`: Job to create
+        /// - `nft_grant`: NFTs from the treasury to grant to the employee once (e.g. a role badge)
         ///
         /// # Output
         /// - None
         ///
         /// # Logic
-        /// - If the job has an employee, add the job to the employee's jobs in the employees KVS
+        /// - Check whether the salary token is allowed to be paid out
+        /// - If the job has an employee, check whether it is a genuine account, other than the DAO's dApp definition
+        /// - If the job has an NFT grant, check whether the treasury holds the NFTs
+        /// - If the job has an employee, add the job to the employee's jobs in the employees KVS, if the employee hasn't reached the maximum number of jobs
         /// - Insert the job in the jobs KVS.
         pub fn create_job(
             &mut self,
@@ -765,7 +1139,26 @@ mod dao {
             recurring: bool,
             title: String,
             description: String,
+            nft_grant: Option<(ResourceAddress, IndexSet<NonFungibleLocalId>)>,
         ) {
+            self.assert_allowed_payment_token(salary_token);
+            if let Some(employee) = &employee {
+                self.assert_valid_employee(employee);
+            }
+
+            if let Some((nft_address, nft_ids)) = &nft_grant {
+                let nft_vault = self
+                    .vaults
+                    .get(nft_address)
+                    .expect("NFTs to grant are not held by the DAO.");
+                for nft_id in nft_ids {
+                    assert!(
+                        nft_vault.as_non_fungible().contains_non_fungible(nft_id),
+                        "NFTs to grant are not held by the DAO."
+                    );
+                }
+            }
+
             let job = Job {
                 employee,
                 last_payment: Clock::current_time_rounded_to_seconds(),
@@ -775,16 +1168,11 @@ mod dao {
                 recurring,
                 title,
                 description,
+                nft_grant,
+                late_payment_bonus_rate: None,
             };
             if let Some(employee) = job.employee {
-                if self.employees.get(&employee).is_some() {
-                    self.employees
-                        .get_mut(&employee)
-                        .unwrap()
-                        .push(self.job_counter);
-                } else {
-                    self.employees.insert(employee, vec![self.job_counter]);
-                }
+                self.add_job_to_employee(employee, self.job_counter);
             }
             self.jobs.insert(self.job_counter, job);
             self.job_counter += 1;
@@ -800,24 +1188,32 @@ mod dao {
         ///
         /// # Logic
         /// - Check whether job_id corresponds to existing job and job is not taken.
+        /// - Check whether the employee is a genuine account, other than the DAO's dApp definition.
+        /// - Add the job_id to the employee's jobs in the employees KVS, if the employee hasn't reached the maximum number of jobs.
         /// - Assign job to employee in jobs KVS.
-        /// - Add the job_id to the employee's jobs in the employees KVS.
         pub fn employ(&mut self, job_id: u64, employee: Global<Account>) {
             assert!(self.jobs.get(&job_id).is_some(), "Job does not exist");
             assert!(
                 self.jobs.get(&job_id).unwrap().employee.is_none(),
                 "Job is already taken"
             );
+            self.assert_valid_employee(&employee);
+            self.add_job_to_employee(employee, job_id);
 
             let mut job = self.jobs.get_mut(&job_id).unwrap();
             job.employee = Some(employee);
             job.last_payment = Clock::current_time_rounded_to_seconds();
+        }
 
-            if self.employees.get(&employee).is_some() {
-                self.employees.get_mut(&employee).unwrap().push(job_id);
-            } else {
-                self.employees.insert(employee, vec![job_id]);
+        /// Set the maximum number of jobs an employee can hold, no cap if None
+        pub fn set_max_jobs_per_employee(&mut self, max_jobs_per_employee: Option<u64>) {
+            if let Some(max_jobs) = max_jobs_per_employee {
+                assert!(
+                    max_jobs > 0,
+                    "Maximum number of jobs per employee must be at least 1"
+                );
             }
+            self.max_jobs_per_employee = max_jobs_per_employee;
         }
 
         /// Send salary to an employee
@@ -830,21 +1226,32 @@ mod dao {
         ///
         /// # Logic
         /// - Get the employees jobs from the employees KVS
-        /// - For each job:
+        /// - If no single job is paid and the employee holds more jobs than the maximum, only pay out the first maximum number of jobs
+        /// - For each (paid out) job:
+        /// - If the job carries an NFT grant that hasn't been sent yet, send it to the employee through the Payment Locker
         /// - Calculate the periods worked by the employee
-        /// - Take the salary from the vault
+        /// - If the job has a late payment bonus rate and the treasury can't cover the salary above the reserve floor, skip the job
+        /// - If the job has a late payment bonus rate, add a bonus of rate * salary for every whole day each period's salary has been due, counted from the end of that period
+        /// - Take the salary from the treasury, respecting the reserve floor
         /// - Trying to airdrop the salary to the employee, but storing it in the Payment Locker if it fails
         /// - Update the last payment time of the job
         /// - If the job is not recurring, remove it from the employees jobs (and update job accordingly)
+        /// - If only part of the jobs was paid out, move the paid out jobs to the back of the employees jobs, so the next call pays out the others
         pub fn send_salary_to_employee(
             &mut self,
             employee: Global<Account>,
             single_job: Option<u64>,
         ) {
-            let mut employee_jobs = self.employees.get_mut(&employee).unwrap();
+            let max_jobs: Option<u64> = self.max_jobs_per_employee;
+            let employee_jobs: Vec<u64> = self.employees.get(&employee).unwrap().clone();
             let mut jobs_to_remove: Vec<u64> = Vec::new();
 
-            for job_id in employee_jobs.iter() {
+            let page_size: usize = match (single_job, max_jobs) {
+                (None, Some(max_jobs)) => (max_jobs as usize).min(employee_jobs.len()),
+                _ => employee_jobs.len(),
+            };
+
+            for job_id in employee_jobs.iter().take(page_size) {
                 if let Some(single_job) = single_job {
                     assert!(
                         employee_jobs.contains(&single_job),
@@ -855,32 +1262,58 @@ mod dao {
                     }
                 }
 
-                let mut job = self.jobs.get_mut(job_id).unwrap();
+                let nft_grant: Option<(ResourceAddress, IndexSet<NonFungibleLocalId>)> =
+                    self.jobs.get_mut(job_id).unwrap().nft_grant.take();
+                if let Some((nft_address, nft_ids)) = nft_grant {
+                    let grant: Bucket = self.withdraw_from_treasury(
+                        nft_address,
+                        &ResourceSpecifier::NonFungible(nft_ids),
+                    );
 
-                let periods_worked: Decimal = ((Clock::current_time_rounded_to_seconds()
-                    .seconds_since_unix_epoch
-                    - job.last_payment.seconds_since_unix_epoch)
-                    / (Decimal::from(job.duration) * dec!(86400)))
-                .checked_floor()
-                .unwrap();
+                    self.payment_locker.store(employee, grant, true);
+                }
 
-                let whole_periods_worked: i64 =
-                    i64::try_from(periods_worked.0 / Decimal::ONE.0).unwrap();
+                let (whole_periods_worked, salary, salary_token, late_payment_bonus_rate) = {
+                    let job = self.jobs.get(job_id).unwrap();
+                    (
+                        Self::whole_periods_worked(&job),
+                        job.salary,
+                        job.salary_token,
+                        job.late_payment_bonus_rate,
+                    )
+                };
 
                 if whole_periods_worked > 0 {
-                    let payment: Bucket = self
-                        .vaults
-                        .get_mut(&job.salary_token)
-                        .unwrap()
-                        .as_fungible()
-                        .take_advanced(
-                            job.salary * whole_periods_worked,
-                            WithdrawStrategy::Rounded(RoundingMode::ToNegativeInfinity),
-                        )
-                        .into();
+                    let mut payment_amount: Decimal = salary * whole_periods_worked;
+
+                    if let Some(bonus_rate) = late_payment_bonus_rate {
+                        let available: Decimal = self
+                            .vaults
+                            .get(&salary_token)
+                            .map(|vault| vault.amount())
+                            .unwrap_or(dec!(0))
+                            - self.get_reserve_floor(salary_token);
+
+                        if available < payment_amount {
+                            continue;
+                        }
+
+                        let days_overdue: i64 = Self::whole_days_overdue(
+                            &self.jobs.get(job_id).unwrap(),
+                            whole_periods_worked,
+                        );
+                        let bonus: Decimal = salary * bonus_rate * days_overdue;
+                        payment_amount += bonus.min(available - payment_amount);
+                    }
+
+                    let payment: Bucket = self.withdraw_from_treasury(
+                        salary_token,
+                        &ResourceSpecifier::Fungible(payment_amount),
+                    );
 
                     self.payment_locker.store(employee, payment, true);
 
+                    let mut job = self.jobs.get_mut(job_id).unwrap();
                     job.last_payment = job
                         .last_payment
                         .add_days(whole_periods_worked * job.duration)
@@ -893,6 +1326,11 @@ mod dao {
                 }
             }
 
+            let mut employee_jobs = self.employees.get_mut(&employee).unwrap();
+            if page_size < employee_jobs.len() {
+                employee_jobs.rotate_left(page_size);
+            }
+
             for job_id in jobs_to_remove {
                 employee_jobs.retain(|&x| x != job_id);
             }
@@ -909,7 +1347,7 @@ mod dao {
         ///
         /// # Logic
         /// - Send unclaimed salary to employee
-        /// - Take one more salary from the vault, multiplied by the salary_modifier
+        /// - Take one more salary from the treasury, multiplied by the salary_modifier, respecting the reserve floor
         /// - Send this final payment to the employee through the Payment Locker
         /// - Remove the job from the employees jobs and modify job accordingly
         pub fn fire(
@@ -919,37 +1357,328 @@ mod dao {
             salary_modifier: Option<Decimal>,
         ) {
             self.send_salary_to_employee(employee, Some(job_id));
-            let mut job = self.jobs.get_mut(&job_id).expect("Job does not exist");
-            let mut employee_jobs = self.employees.get_mut(&employee).unwrap();
-            let payment: Bucket = self
-                .vaults
-                .get_mut(&job.salary_token)
-                .unwrap()
-                .as_fungible()
-                .take_advanced(
-                    job.salary * salary_modifier.unwrap_or(dec!(1)),
-                    WithdrawStrategy::Rounded(RoundingMode::ToNegativeInfinity),
-                )
-                .into();
+            let (salary_token, salary) = {
+                let job = self.jobs.get(&job_id).expect("Job does not exist");
+                (job.salary_token, job.salary)
+            };
+            let payment: Bucket = self.withdraw_from_treasury(
+                salary_token,
+                &ResourceSpecifier::Fungible(salary * salary_modifier.unwrap_or(dec!(1))),
+            );
 
             self.payment_locker.store(employee, payment, true);
 
-            job.employee = None;
-            employee_jobs.retain(|&x| x != job_id);
-        }
-
-        /// Post an announcement to the DAO
-        pub fn post_announcement(&mut self, announcement: String, files: Option<Vec<File>>) {
-            self.text_announcements
-                .insert(self.text_announcement_counter, (announcement, files));
-            self.text_announcement_counter += 1;
+            self.jobs.get_mut(&job_id).unwrap().employee = None;
+            self.employees
+                .get_mut(&employee)
+                .unwrap()
+                .retain(|&x| x != job_id);
         }
 
-        /// Remove an announcement from the DAO
+        /// Set the late payment bonus rate of a job
+        ///
+        /// # Input
+        /// - `job_id`: Job to set the rate for
+        /// - `late_payment_bonus_rate`: Daily bonus rate on salary that is due but hasn't been paid, None to opt out
+        ///
+        /// # Output
+        /// - None
+        ///
+        /// # Logic
+        /// - Check whether the rate is not negative
+        /// - Set the rate of the job
+        pub fn set_late_payment_bonus_rate(
+            &mut self,
+            job_id: u64,
+            late_payment_bonus_rate: Option<Decimal>,
+        ) {
+            if let Some(rate) = late_payment_bonus_rate {
+                assert!(
+                    rate >= dec!(0),
+                    "Late payment bonus rate can't be negative."
+                );
+            }
+            let mut job = self.jobs.get_mut(&job_id).expect("Job does not exist");
+            job.late_payment_bonus_rate = late_payment_bonus_rate;
+        }
+
+        /// Set the tokens that can be used for salaries and airdrops, allowing any token if None
+        pub fn set_allowed_payment_tokens(
+            &mut self,
+            allowed_payment_tokens: Option<IndexSet<ResourceAddress>>,
+        ) {
+            self.allowed_payment_tokens = allowed_payment_tokens;
+        }
+
+        /// Create a proposal adding a stakable resource to the Incentives component
+        ///
+        /// # Input
+        /// - `title`: Title of the proposal
+        /// - `description`: Description of the proposal
+        /// - `address`: Address of the resource to make stakable
+        /// - `reward_amount`: Reward per period for stakers of the resource
+        /// - `payment`: Daily reward multiplier for locking the resource
+        /// - `max_duration`: Maximum lock duration in days
+        /// - `unlock_payment`: Daily payment multiplier for unlocking the resource early
+        /// - `reward_coefficient`: Coefficient applied to lock rewards
+        /// - `bootstrap_bonus`: Optional reward multiplier for early stakers of the resource
+        /// - `fee_payment`: Payment for the proposal fee
+        /// - `proposer_proof`: Optional proof of an account owner badge, identifying the proposer
+        /// - `proposer_id_proof`: Optional proof of the proposer's staking ID (required if a minimum proposer stake is set)
+        ///
+        /// # Output
+        /// - A bucket with the leftover fee payment
+        /// - A bucket with the incomplete proposal receipt
+        ///
+        /// # Logic
+        /// - Check whether the resource isn't stakable already
+        /// - Validate the stakable parameters
+        /// - Create a proposal calling `add_stakable` on the Incentives component, authorized by the controller badge
+        pub fn propose_add_stakable(
+            &mut self,
+            title: String,
+            description: String,
+            address: ResourceAddress,
+            reward_amount: Decimal,
+            payment: Decimal,
+            max_duration: i64,
+            unlock_payment: Decimal,
+            reward_coefficient: Decimal,
+            bootstrap_bonus: Option<BootstrapBonus>,
+            fee_payment: Bucket,
+            proposer_proof: Option<Proof>,
+            proposer_id_proof: Option<NonFungibleProof>,
+        ) -> (Bucket, Bucket) {
+            assert!(
+                !self.is_stakable(address),
+                "Resource is already stakable, propose to edit it instead."
+            );
+            self.propose_stakable_call(
+                title,
+                description,
+                "add_stakable",
+                address,
+                reward_amount,
+                payment,
+                max_duration,
+                unlock_payment,
+                reward_coefficient,
+                bootstrap_bonus,
+                fee_payment,
+                proposer_proof,
+                proposer_id_proof,
+            )
+        }
+
+        /// Create a proposal editing a stakable resource of the Incentives component
+        ///
+        /// # Input
+        /// - `title`: Title of the proposal
+        /// - `description`: Description of the proposal
+        /// - `address`: Address of the stakable resource to edit
+        /// - `reward_amount`: Reward per period for stakers of the resource
+        /// - `payment`: Daily reward multiplier for locking the resource
+        /// - `max_duration`: Maximum lock duration in days
+        /// - `unlock_payment`: Daily payment multiplier for unlocking the resource early
+        /// - `reward_coefficient`: Coefficient applied to lock rewards
+        /// - `fee_payment`: Payment for the proposal fee
+        /// - `proposer_proof`: Optional proof of an account owner badge, identifying the proposer
+        /// - `proposer_id_proof`: Optional proof of the proposer's staking ID (required if a minimum proposer stake is set)
+        ///
+        /// # Output
+        /// - A bucket with the leftover fee payment
+        /// - A bucket with the incomplete proposal receipt
+        ///
+        /// # Logic
+        /// - Check whether the resource is stakable
+        /// - Validate the stakable parameters
+        /// - Create a proposal calling `edit_stakable` on the Incentives component, authorized by the controller badge
+        pub fn propose_edit_stakable(
+            &mut self,
+            title: String,
+            description: String,
+            address: ResourceAddress,
+            reward_amount: Decimal,
+            payment: Decimal,
+            max_duration: i64,
+            unlock_payment: Decimal,
+            reward_coefficient: Decimal,
+            fee_payment: Bucket,
+            proposer_proof: Option<Proof>,
+            proposer_id_proof: Option<NonFungibleProof>,
+        ) -> (Bucket, Bucket) {
+            assert!(self.is_stakable(address), "Resource is not stakable.");
+            self.propose_stakable_call(
+                title,
+                description,
+                "edit_stakable",
+                address,
+                reward_amount,
+                payment,
+                max_duration,
+                unlock_payment,
+                reward_coefficient,
+                None,
+                fee_payment,
+                proposer_proof,
+                proposer_id_proof,
+            )
+        }
+
+        /// Create a proposal sending tokens from the DAO treasury to a receiver
+        ///
+        /// # Input
+        /// - `title`: Title of the proposal
+        /// - `description`: Description of the proposal
+        /// - `address`: Address of the tokens to send
+        /// - `tokens`: Tokens to send
+        /// - `receiver_address`: Component address to send tokens to
+        /// - `put_method`: Method of the receiver component to put the tokens in
+        /// - `fee_payment`: Payment for the proposal fee
+        /// - `proposer_proof`: Optional proof of an account owner badge, identifying the proposer
+        /// - `proposer_id_proof`: Optional proof of the proposer's staking ID (required if a minimum proposer stake is set)
+        ///
+        /// # Output
+        /// - A bucket with the leftover fee payment
+        /// - A bucket with the incomplete proposal receipt
+        ///
+        /// # Logic
+        /// - Validate the tokens to send against the treasury
+        /// - Check whether the receiver is an existing component
+        /// - Create a proposal calling `send_tokens` on the DAO component, authorized by the controller badge
+        pub fn propose_send_tokens(
+            &mut self,
+            title: String,
+            description: String,
+            address: ResourceAddress,
+            tokens: ResourceSpecifier,
+            receiver_address: ComponentAddress,
+            put_method: String,
+            fee_payment: Bucket,
+            proposer_proof: Option<Proof>,
+            proposer_id_proof: Option<NonFungibleProof>,
+        ) -> (Bucket, Bucket) {
+            self.assert_valid_treasury_spend(address, &tokens);
+            // Fails if the receiver doesn't exist
+            let _receiver_blueprint: BlueprintId =
+                ScryptoVmV1Api::object_get_blueprint_id(receiver_address.as_node_id());
+            assert!(!put_method.is_empty(), "Put method can't be empty.");
+
+            let args: ScryptoValue = scrypto_decode(&scrypto_args!(
+                address,
+                tokens,
+                receiver_address,
+                put_method
+            ))
+            .unwrap();
+
+            self.propose_treasury_call(
+                title,
+                description,
+                "send_tokens",
+                args,
+                fee_payment,
+                proposer_proof,
+                proposer_id_proof,
+            )
+        }
+
+        /// Create a proposal airdropping tokens from the DAO treasury through the Payment Locker
+        ///
+        /// # Input
+        /// - `title`: Title of the proposal
+        /// - `description`: Description of the proposal
+        /// - `claimants`: Claimants and amount/id of tokens to airdrop to them
+        /// - `address`: Address of the tokens to airdrop
+        /// - `fee_payment`: Payment for the proposal fee
+        /// - `proposer_proof`: Optional proof of an account owner badge, identifying the proposer
+        /// - `proposer_id_proof`: Optional proof of the proposer's staking ID (required if a minimum proposer stake is set)
+        ///
+        /// # Output
+        /// - A bucket with the leftover fee payment
+        /// - A bucket with the incomplete proposal receipt
+        ///
+        /// # Logic
+        /// - Check the amount of claimants and whether the token is allowed to be airdropped
+        /// - Validate the tokens to airdrop to every claimant against the treasury
+        /// - Create a proposal calling `airdrop_tokens` on the DAO component, authorized by the controller badge
+        pub fn propose_airdrop_tokens(
+            &mut self,
+            title: String,
+            description: String,
+            claimants: IndexMap<Global<Account>, ResourceSpecifier>,
+            address: ResourceAddress,
+            fee_payment: Bucket,
+            proposer_proof: Option<Proof>,
+            proposer_id_proof: Option<NonFungibleProof>,
+        ) -> (Bucket, Bucket) {
+            assert!(
+                !claimants.is_empty() && claimants.len() < 15,
+                "Airdrop needs between 1 and 15 claimants."
+            );
+            self.assert_allowed_payment_token(address);
+            for (_receiver, specifier) in &claimants {
+                self.assert_valid_treasury_spend(address, specifier);
+            }
+
+            let args: ScryptoValue = scrypto_decode(&scrypto_args!(claimants, address)).unwrap();
+
+            self.propose_treasury_call(
+                title,
+                description,
+                "airdrop_tokens",
+                args,
+                fee_payment,
+                proposer_proof,
+                proposer_id_proof,
+            )
+        }
+
+        /// Post an announcement to the DAO
+        pub fn post_announcement(&mut self, announcement: String, files: Option<Vec<File>>) {
+            self.text_announcements
+                .insert(self.text_announcement_counter, (announcement, files));
+            self.text_announcement_counter += 1;
+        }
+
+        /// Remove an announcement from the DAO
         pub fn remove_announcement(&mut self, announcement_id: u64) {
             self.text_announcements.remove(&announcement_id);
         }
 
+        /// Get the announcements of the DAO
+        ///
+        /// # Input
+        /// - `start`: First announcement id to include
+        /// - `limit`: Maximum amount of announcement ids to include
+        ///
+        /// # Output
+        /// - For every announcement in the range: its id, text and files
+        ///
+        /// # Logic
+        /// - Iterate over the announcement ids in the range (paginated, as the announcements KVS can't be iterated)
+        /// - Skip ids of removed announcements
+        pub fn get_announcements(
+            &self,
+            start: u64,
+            limit: u64,
+        ) -> Vec<(u64, String, Option<Vec<File>>)> {
+            let end: u64 = self
+                .text_announcement_counter
+                .min(start.saturating_add(limit));
+
+            (start..end)
+                .filter_map(|announcement_id| {
+                    self.text_announcements
+                        .get(&announcement_id)
+                        .map(|announcement| {
+                            let (text, files) = announcement.clone();
+                            (announcement_id, text, files)
+                        })
+                })
+                .collect()
+        }
+
         /// Call the rewarded methods
         ///
         /// # Input
@@ -962,6 +1691,7 @@ mod dao {
         /// - Calculate the time passed since the last update
         /// - Call all rewarded methods
         /// - Update the staking component (a standard rewarded method)
+        /// - If auto top-up is enabled and the staking reward vault is below the threshold, top it up from the treasury, without touching the caller reward or the reserve floor
         pub fn rewarded_update(&mut self) -> Bucket {
             let passed_minutes: Decimal = (Clock::current_time_rounded_to_seconds()
                 .seconds_since_unix_epoch
@@ -978,10 +1708,30 @@ mod dao {
             self.incentives.update_period();
             self.last_update = Clock::current_time_rounded_to_seconds();
 
+            let caller_reward: Decimal =
+                (passed_minutes * self.daily_update_reward) / (dec!(24) * dec!(60));
+
+            if self.auto_reward_topup_amount > dec!(0)
+                && self.staking.get_remaining_rewards() < self.auto_reward_topup_threshold
+            {
+                let topup_amount: Decimal = self.auto_reward_topup_amount.min(
+                    self.get_token_amount(self.mother_token_address)
+                        - caller_reward
+                        - self.get_reserve_floor(self.mother_token_address),
+                );
+                if topup_amount > dec!(0) {
+                    let topup: Bucket = self.withdraw_from_treasury(
+                        self.mother_token_address,
+                        &ResourceSpecifier::Fungible(topup_amount),
+                    );
+                    self.staking.put_tokens(topup);
+                }
+            }
+
             self.vaults
                 .get_mut(&self.mother_token_address)
                 .unwrap()
-                .take((passed_minutes * self.daily_update_reward) / (dec!(24) * dec!(60)))
+                .take(caller_reward)
         }
 
         /// Add a rewarded method call
@@ -1009,11 +1759,209 @@ mod dao {
             self.daily_update_reward = reward;
         }
 
+        /// Set the amount of mother tokens to top up the staking reward vault with when it drops below the threshold (zero amount to disable)
+        pub fn set_auto_reward_topup(&mut self, amount: Decimal, threshold: Decimal) {
+            assert!(
+                amount >= dec!(0) && threshold >= dec!(0),
+                "Top-up amount and threshold can't be negative."
+            );
+            self.auto_reward_topup_amount = amount;
+            self.auto_reward_topup_threshold = threshold;
+        }
+
         /// Get the amount of tokens in possession of the DAO
         pub fn get_token_amount(&self, address: ResourceAddress) -> Decimal {
             self.vaults.get(&address).unwrap().as_fungible().amount()
         }
 
+        /// Count the controller badges, to audit how the privileged badge is distributed
+        ///
+        /// # Input
+        /// - None
+        ///
+        /// # Output
+        /// - The total supply of the controller badge, if tracked
+        /// - The amount of controller badges held per known component (the DAO and the Governance component)
+        ///
+        /// # Logic
+        /// - Get the amount held by the DAO and by the Governance component
+        /// - Badges not held by these components (e.g. the one of the ReentrancyProxy) make up the remainder of the total supply
+        pub fn count_controller_badges(
+            &self,
+        ) -> (Option<Decimal>, IndexMap<ComponentAddress, Decimal>) {
+            let mut held: IndexMap<ComponentAddress, Decimal> = IndexMap::new();
+            held.insert(
+                Runtime::global_component().address(),
+                self.get_token_amount(self.controller_badge_address),
+            );
+            held.insert(
+                self.governance.address(),
+                self.governance
+                    .get_token_amount(self.controller_badge_address),
+            );
+
+            (
+                ResourceManager::from(self.controller_badge_address).total_supply(),
+                held,
+            )
+        }
+
+        /// Get the payroll obligations of the DAO
+        ///
+        /// # Input
+        /// - `start_job_id`: First job to include
+        /// - `limit`: Maximum amount of jobs to include
+        ///
+        /// # Output
+        /// - For every employee with a job in the range: the employee, their job ids, and their accrued but unpaid salary per salary token
+        ///
+        /// # Logic
+        /// - Iterate over the jobs in the range (paginated, as the employees KVS can't be iterated)
+        /// - For each job with an employee, calculate the accrued salary over the whole periods worked
+        /// - Group the jobs and accrued salaries by employee
+        pub fn get_payroll_summary(
+            &self,
+            start_job_id: u64,
+            limit: u64,
+        ) -> Vec<(Global<Account>, Vec<u64>, IndexMap<ResourceAddress, Decimal>)> {
+            let mut summary: IndexMap<
+                Global<Account>,
+                (Vec<u64>, IndexMap<ResourceAddress, Decimal>),
+            > = IndexMap::new();
+            let end_job_id: u64 = self.job_counter.min(start_job_id.saturating_add(limit));
+
+            for job_id in start_job_id..end_job_id {
+                let job = self.jobs.get(&job_id).unwrap();
+                if let Some(employee) = job.employee {
+                    let accrued_salary: Decimal = job.salary * Self::whole_periods_worked(&job);
+                    let (job_ids, liabilities) = summary
+                        .entry(employee)
+                        .or_insert((Vec::new(), IndexMap::new()));
+                    job_ids.push(job_id);
+                    *liabilities.entry(job.salary_token).or_insert(dec!(0)) += accrued_salary;
+                }
+            }
+
+            summary
+                .into_iter()
+                .map(|(employee, (job_ids, liabilities))| (employee, job_ids, liabilities))
+                .collect()
+        }
+
+        /// Get the cumulative inflow and outflow of a token through the DAO treasury
+        pub fn get_treasury_flow(&self, address: ResourceAddress) -> (Decimal, Decimal) {
+            self.treasury_flows
+                .get(&address)
+                .map_or((dec!(0), dec!(0)), |flow| *flow)
+        }
+
+        /// Sets a new reserve floor for a token, being the minimum amount of it the treasury has to keep.
+        ///
+        /// # Input
+        /// - `address`: Address of the token to set the reserve floor for
+        /// - `amount`: The new reserve floor, zero to remove it
+        ///
+        /// # Output
+        /// - None
+        ///
+        /// # Logic
+        /// - Check whether the new reserve floor isn't negative
+        /// - Store the new reserve floor, taking effect after the reserve floor delay has passed
+        /// - Overwrites any earlier pending reserve floor of the token, keeping the currently effective one until then
+        pub fn set_reserve_floor(&mut self, address: ResourceAddress, amount: Decimal) {
+            assert!(amount >= dec!(0), "Reserve floor can't be negative");
+            let effective_from: Instant = Clock::current_time_rounded_to_seconds()
+                .add_days(RESERVE_FLOOR_DELAY)
+                .unwrap();
+            let current_floor: Decimal = self.get_reserve_floor(address);
+            self.reserve_floors
+                .insert(address, (current_floor, Some((amount, effective_from))));
+        }
+
+        /// Get the reserve floor of a token currently in effect, zero if none is set
+        pub fn get_reserve_floor(&self, address: ResourceAddress) -> Decimal {
+            self.reserve_floors
+                .get(&address)
+                .map_or(dec!(0), |reserve_floor| match reserve_floor.1 {
+                    Some((new_floor, effective_from))
+                        if Clock::current_time_is_at_or_after(
+                            effective_from,
+                            TimePrecision::Second,
+                        ) =>
+                    {
+                        new_floor
+                    }
+                    _ => reserve_floor.0,
+                })
+        }
+
+        /// Proposes to transfer ownership of the DAO components to a new owner rule.
+        ///
+        /// # Input
+        /// - `new_owner_rule`: The access rule that will become the owner of the DAO components
+        ///
+        /// # Output
+        /// - None
+        ///
+        /// # Logic
+        /// - Store the new owner rule, acceptable after the owner transfer delay has passed
+        /// - Overwrites any earlier proposed owner transfer
+        pub fn propose_owner_transfer(&mut self, new_owner_rule: AccessRule) {
+            let acceptable_from: Instant = Clock::current_time_rounded_to_seconds()
+                .add_days(OWNER_TRANSFER_DELAY)
+                .unwrap();
+            self.pending_owner_transfer = Some((new_owner_rule, acceptable_from));
+        }
+
+        /// Cancels a proposed owner transfer.
+        pub fn cancel_owner_transfer(&mut self) {
+            assert!(
+                self.pending_owner_transfer.is_some(),
+                "No owner transfer proposed."
+            );
+            self.pending_owner_transfer = None;
+        }
+
+        /// Accepts a proposed owner transfer, to be called by the new owner.
+        ///
+        /// # Input
+        /// - None
+        ///
+        /// # Output
+        /// - None
+        ///
+        /// # Logic
+        /// - Check whether the owner transfer delay has passed
+        /// - Check whether the caller satisfies the new owner rule, so ownership can't be transferred to an unusable rule
+        /// - Set the new owner rule on the DAO, Staking, Incentives and Governance components and the dapp definition, using the controller badge
+        /// - This is the only way the owner roles are updated, as proposals can only call main module methods of components
+        /// - After this, the controller badge no longer controls these components, so governance can't call their owner methods anymore
+        /// - Methods that update the dapp definition with the controller badge (`add_claimed_website`, `add_claimed_entity` and `set_dao_metadata`) stop working, the new owner updates the dapp definition directly instead
+        pub fn accept_owner_transfer(&mut self) {
+            let (new_owner_rule, acceptable_from): (AccessRule, Instant) = self
+                .pending_owner_transfer
+                .take()
+                .expect("No owner transfer proposed.");
+            assert!(
+                Clock::current_time_is_at_or_after(acceptable_from, TimePrecision::Second),
+                "Owner transfer delay has not passed yet."
+            );
+            Runtime::assert_access_rule(new_owner_rule.clone());
+
+            let dao: Global<AnyComponent> = Global::from(Runtime::global_address());
+            self.vaults
+                .get_mut(&self.controller_badge_address)
+                .unwrap()
+                .as_fungible()
+                .authorize_with_amount(dec!(1), || {
+                    self.staking.set_owner_role(new_owner_rule.clone());
+                    self.incentives.set_owner_role(new_owner_rule.clone());
+                    self.governance.set_owner_role(new_owner_rule.clone());
+                    self.dapp_def_account.set_owner_role(new_owner_rule.clone());
+                    dao.set_owner_role(new_owner_rule);
+                });
+        }
+
         /// Adds claimed website to the dapp definition
         pub fn add_claimed_website(&mut self, website: Url) {
             let badge_vault = self
@@ -1038,5 +1986,360 @@ mod dao {
                 }
             }
         }
+
+        /// Adds claimed entity to the dapp definition
+        pub fn add_claimed_entity(&mut self, address: GlobalAddress) {
+            let mut claimed_entities: Vec<GlobalAddress> = self.get_claimed_entities();
+            assert!(
+                !claimed_entities.contains(&address),
+                "Entity is already claimed."
+            );
+            claimed_entities.push(address);
+            self.vaults
+                .get_mut(&self.controller_badge_address)
+                .unwrap()
+                .as_fungible()
+                .authorize_with_amount(dec!("1"), || {
+                    self.dapp_def_account
+                        .set_metadata("claimed_entities", claimed_entities);
+                });
+        }
+
+        /// Sets a metadata field of the dapp definition, except for its account type
+        pub fn set_dao_metadata(&mut self, key: String, value: MetadataValue) {
+            assert!(
+                key != "account_type",
+                "The account type of the dapp definition can't be changed."
+            );
+            self.vaults
+                .get_mut(&self.controller_badge_address)
+                .unwrap()
+                .as_fungible()
+                .authorize_with_amount(dec!("1"), || {
+                    self.dapp_def_account.set_metadata(key, value);
+                });
+        }
+
+        /// Gets the entities claimed by the dapp definition
+        pub fn get_claimed_entities(&self) -> Vec<GlobalAddress> {
+            match self.dapp_def_account.get_metadata("claimed_entities") {
+                Ok(Some(claimed_entities)) => claimed_entities,
+                Ok(None) | Err(_) => vec![],
+            }
+        }
+
+        /// Checks whether a resource is stakable in the Incentives component
+        fn is_stakable(&self, address: ResourceAddress) -> bool {
+            self.incentives
+                .get_stakables()
+                .iter()
+                .any(|(stakable_address, _, _)| *stakable_address == address)
+        }
+
+        /// Checks whether sending an amount of tokens requires approval by approver badges
+        fn requires_approval(&self, address: ResourceAddress, tokens: &ResourceSpecifier) -> bool {
+            self.send_approval_thresholds
+                .get(&address)
+                .map_or(false, |threshold| {
+                    self.unapproved_sent(address) + Self::specified_amount(tokens) > *threshold
+                })
+        }
+
+        /// Adds tokens sent without approval to the amount sent in the current send approval window, starting a new window if the last one has passed
+        fn record_unapproved_send(&mut self, address: ResourceAddress, tokens: &ResourceSpecifier) {
+            if !self.send_approval_thresholds.contains_key(&address) {
+                return;
+            }
+            let sent: Decimal = self.unapproved_sent(address) + Self::specified_amount(tokens);
+            let window_start: Instant = self
+                .unapproved_sends
+                .get(&address)
+                .map(|unapproved| unapproved.1)
+                .filter(|window_start| {
+                    !Clock::current_time_is_at_or_after(
+                        window_start.add_days(SEND_APPROVAL_WINDOW).unwrap(),
+                        TimePrecision::Second,
+                    )
+                })
+                .unwrap_or(Clock::current_time_rounded_to_seconds());
+            self.unapproved_sends.insert(address, (sent, window_start));
+        }
+
+        /// Gets the amount of a token sent without approval in the current send approval window, zero if the last window has passed
+        fn unapproved_sent(&self, address: ResourceAddress) -> Decimal {
+            self.unapproved_sends
+                .get(&address)
+                .map_or(dec!(0), |unapproved| {
+                    if Clock::current_time_is_at_or_after(
+                        unapproved.1.add_days(SEND_APPROVAL_WINDOW).unwrap(),
+                        TimePrecision::Second,
+                    ) {
+                        dec!(0)
+                    } else {
+                        unapproved.0
+                    }
+                })
+        }
+
+        /// Gets the amount of tokens specified, counting every non-fungible as one
+        fn specified_amount(tokens: &ResourceSpecifier) -> Decimal {
+            match tokens {
+                ResourceSpecifier::Fungible(amount) => *amount,
+                ResourceSpecifier::NonFungible(ids) => Decimal::from(ids.len()),
+            }
+        }
+
+        /// Checks whether a token is allowed to be used for salaries and airdrops
+        fn assert_allowed_payment_token(&self, address: ResourceAddress) {
+            if let Some(allowed_payment_tokens) = &self.allowed_payment_tokens {
+                assert!(
+                    allowed_payment_tokens.contains(&address),
+                    "Token is not allowed to be used for payments"
+                );
+            }
+        }
+
+        /// Checks whether an employee is a genuine account, and not the DAO's own dApp definition account
+        fn assert_valid_employee(&self, employee: &Global<Account>) {
+            let blueprint_id: BlueprintId =
+                ScryptoVmV1Api::object_get_blueprint_id(employee.address().as_node_id());
+            assert!(
+                blueprint_id.package_address == ACCOUNT_PACKAGE
+                    && blueprint_id.blueprint_name == ACCOUNT_BLUEPRINT,
+                "Employee must be an account"
+            );
+            assert!(
+                employee.address() != self.dapp_def_account.address(),
+                "Employee can't be the DAO's dApp definition account"
+            );
+        }
+
+        /// Adds a job to the jobs of an employee, checking whether the employee hasn't reached the maximum number of jobs
+        fn add_job_to_employee(&mut self, employee: Global<Account>, job_id: u64) {
+            if self.employees.get(&employee).is_some() {
+                let mut employee_jobs = self.employees.get_mut(&employee).unwrap();
+                if let Some(max_jobs) = self.max_jobs_per_employee {
+                    assert!(
+                        (employee_jobs.len() as u64) < max_jobs,
+                        "Employee has reached the maximum number of jobs"
+                    );
+                }
+                employee_jobs.push(job_id);
+            } else {
+                self.employees.insert(employee, vec![job_id]);
+            }
+        }
+
+        /// Validates stakable parameters and creates a proposal calling the Incentives component with them
+        fn propose_stakable_call(
+            &mut self,
+            title: String,
+            description: String,
+            method: &str,
+            address: ResourceAddress,
+            reward_amount: Decimal,
+            payment: Decimal,
+            max_duration: i64,
+            unlock_payment: Decimal,
+            reward_coefficient: Decimal,
+            bootstrap_bonus: Option<BootstrapBonus>,
+            fee_payment: Bucket,
+            proposer_proof: Option<Proof>,
+            proposer_id_proof: Option<NonFungibleProof>,
+        ) -> (Bucket, Bucket) {
+            assert!(reward_amount >= dec!(0), "Reward amount can't be negative.");
+            assert!(payment >= dec!(1), "Lock payment can't be less than 1.");
+            assert!(max_duration > 0, "Maximum lock duration must be positive.");
+            assert!(
+                unlock_payment >= dec!(1),
+                "Unlock payment can't be less than 1."
+            );
+            assert!(
+                reward_coefficient >= dec!(0),
+                "Reward coefficient can't be negative."
+            );
+            if let Some(bonus) = &bootstrap_bonus {
+                assert!(
+                    bonus.multiplier >= dec!(1),
+                    "Bootstrap bonus multiplier can't be less than 1."
+                );
+                assert!(
+                    bonus.window_days >= 0,
+                    "Bootstrap window can't be negative."
+                );
+            }
+
+            let args: ScryptoValue = if method == "add_stakable" {
+                scrypto_decode(&scrypto_args!(
+                    address,
+                    reward_amount,
+                    payment,
+                    max_duration,
+                    unlock_payment,
+                    reward_coefficient,
+                    bootstrap_bonus
+                ))
+                .unwrap()
+            } else {
+                scrypto_decode(&scrypto_args!(
+                    address,
+                    reward_amount,
+                    payment,
+                    max_duration,
+                    unlock_payment,
+                    reward_coefficient
+                ))
+                .unwrap()
+            };
+
+            self.governance.create_proposal(
+                title,
+                description,
+                None,
+                self.incentives.address(),
+                self.controller_badge_address,
+                method.to_string(),
+                args,
+                false,
+                false,
+                fee_payment,
+                None,
+                proposer_proof,
+                proposer_id_proof,
+            )
+        }
+
+        /// Creates a proposal calling a treasury method on the DAO component, authorized by the controller badge
+        fn propose_treasury_call(
+            &mut self,
+            title: String,
+            description: String,
+            method: &str,
+            args: ScryptoValue,
+            fee_payment: Bucket,
+            proposer_proof: Option<Proof>,
+            proposer_id_proof: Option<NonFungibleProof>,
+        ) -> (Bucket, Bucket) {
+            self.governance.create_proposal(
+                title,
+                description,
+                None,
+                Runtime::global_address(),
+                self.controller_badge_address,
+                method.to_string(),
+                args,
+                false,
+                false,
+                fee_payment,
+                None,
+                proposer_proof,
+                proposer_id_proof,
+            )
+        }
+
+        /// Checks whether tokens can be spent from the treasury: the treasury holds the token, and a positive amount or some IDs are specified
+        fn assert_valid_treasury_spend(
+            &self,
+            address: ResourceAddress,
+            tokens: &ResourceSpecifier,
+        ) {
+            assert!(
+                self.vaults.get(&address).is_some(),
+                "Token is not held by the treasury."
+            );
+            match tokens {
+                ResourceSpecifier::Fungible(amount) => {
+                    assert!(*amount > dec!(0), "Amount must be positive.");
+                    Self::assert_divisibility(address, *amount);
+                }
+                ResourceSpecifier::NonFungible(ids) => {
+                    assert!(!ids.is_empty(), "No non-fungible IDs specified.");
+                }
+            }
+        }
+
+        /// Asserts that an amount can be represented within the divisibility of a fungible token
+        fn assert_divisibility(address: ResourceAddress, amount: Decimal) {
+            if let ResourceType::Fungible { divisibility } =
+                ResourceManager::from(address).resource_type()
+            {
+                assert!(
+                    amount.checked_round(divisibility, RoundingMode::ToZero) == Some(amount),
+                    "Amount {} has more decimal places than the token's divisibility of {} allows.",
+                    amount,
+                    divisibility
+                );
+            }
+        }
+
+        /// Takes tokens from the treasury, checking whether it still holds at least the reserve floor of the token afterwards, and records the outflow
+        fn withdraw_from_treasury(
+            &mut self,
+            address: ResourceAddress,
+            tokens: &ResourceSpecifier,
+        ) -> Bucket {
+            let payment: Bucket = match tokens {
+                ResourceSpecifier::Fungible(amount) => self
+                    .vaults
+                    .get_mut(&address)
+                    .unwrap()
+                    .as_fungible()
+                    .take_advanced(
+                        *amount,
+                        WithdrawStrategy::Rounded(RoundingMode::ToNegativeInfinity),
+                    )
+                    .into(),
+                ResourceSpecifier::NonFungible(ids) => self
+                    .vaults
+                    .get_mut(&address)
+                    .unwrap()
+                    .as_non_fungible()
+                    .take_non_fungibles(ids)
+                    .into(),
+            };
+            self.assert_reserve_floor(address);
+            self.record_flow(address, dec!(0), payment.amount());
+            payment
+        }
+
+        /// Checks whether the treasury still holds at least the reserve floor of a token
+        fn assert_reserve_floor(&self, address: ResourceAddress) {
+            let remaining: Decimal = self.vaults.get(&address).unwrap().amount();
+            assert!(
+                remaining >= self.get_reserve_floor(address),
+                "Treasury can't go below the reserve floor of this token"
+            );
+        }
+
+        /// Adds an inflow and outflow to the cumulative treasury flow of a token
+        fn record_flow(&mut self, address: ResourceAddress, inflow: Decimal, outflow: Decimal) {
+            let (total_inflow, total_outflow) = self.get_treasury_flow(address);
+            self.treasury_flows
+                .insert(address, (total_inflow + inflow, total_outflow + outflow));
+        }
+
+        /// Calculates the whole salary periods worked since the last payment of a job
+        fn whole_periods_worked(job: &Job) -> i64 {
+            let periods_worked: Decimal = ((Clock::current_time_rounded_to_seconds()
+                .seconds_since_unix_epoch
+                - job.last_payment.seconds_since_unix_epoch)
+                / (Decimal::from(job.duration) * dec!(86400)))
+            .checked_floor()
+            .unwrap();
+
+            i64::try_from(periods_worked.0 / Decimal::ONE.0).unwrap()
+        }
+
+        /// Adds up the whole days the given periods of a job have been due, each period falling due at the end of it
+        fn whole_days_overdue(job: &Job, periods: i64) -> i64 {
+            let now: i64 = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+
+            (1..=periods)
+                .map(|period| {
+                    let due: Instant = job.last_payment.add_days(period * job.duration).unwrap();
+                    (now - due.seconds_since_unix_epoch) / 86400
+                })
+                .sum()
+        }
     }
 }
@@ -10,6 +10,7 @@
 
 use crate::reentrancy::reentrancy::*;
 use crate::staking::staking::*;
+use crate::staking::Id;
 use scrypto::prelude::*;
 
 /// File structure, holding all information to lookup a file stored on the Radix Ledger.
@@ -30,15 +31,26 @@ pub struct Proposal {
     pub votes_for: Decimal,
     pub votes_against: Decimal,
     pub votes: KeyValueStore<NonFungibleLocalId, Decimal>,
+    pub raw_votes: KeyValueStore<NonFungibleLocalId, Decimal>,
+    pub total_raw_votes: Decimal,
     pub deadline: Instant,
     pub has_failed_in_last_day: Option<bool>,
+    pub veto_extended: bool,
     pub next_index: i64,
     pub status: ProposalStatus,
     pub reentrancy: bool,
+    pub reached_quorum: bool,
+    pub vote_weights: KeyValueStore<NonFungibleLocalId, Decimal>,
+    pub total_vote_weight: Decimal,
+    pub early_voter_reward: Decimal,
+    pub proposer: Option<ComponentAddress>,
+    pub failure_reason: Option<String>,
+    pub sponsors: IndexSet<NonFungibleLocalId>,
+    pub max_vote_power: Option<Decimal>,
 }
 
 /// Proposal receipt structure, minted when a user wants to propose a new proposal, usable to update the proposal and submit it.
-/// After the proposal is accepted, the receipt is redeemable for the fee paid.
+/// After the proposal is executed, or rejected after reaching quorum, the receipt is redeemable for the fee paid.
 #[derive(ScryptoSbor, NonFungibleData)]
 pub struct ProposalReceipt {
     #[mutable]
@@ -72,18 +84,143 @@ pub enum ProposalStatus {
     Finished,
 }
 
+/// RejectedFeeHandling enum, determining what happens to the fee of a rejected proposal.
+#[derive(ScryptoSbor, PartialEq, Clone, Copy)]
+pub enum RejectedFeeHandling {
+    ReturnToTreasury,
+    Burn,
+}
+
+/// ExecutionPermission enum, determining who can execute the steps of an accepted proposal.
+#[derive(ScryptoSbor, PartialEq, Clone, Copy)]
+pub enum ExecutionPermission {
+    Anyone,
+    ProposerOnly,
+    ExecutorBadge(ResourceAddress),
+}
+
 /// GovernanceParameters structure, holding all parameters of the governance component.
-#[derive(ScryptoSbor)]
+#[derive(ScryptoSbor, Clone)]
 pub struct GovernanceParameters {
     pub fee: Decimal,
     pub proposal_duration: i64,
     pub quorum: Decimal,
     pub approval_threshold: Decimal,
     pub maximum_proposal_submit_delay: i64,
+    pub rejected_fee_handling: RejectedFeeHandling,
+    pub min_title_len: u64,
+    pub min_description_len: u64,
+    pub allow_veto_recovery: bool,
+    pub proposer_discount: Decimal,
+    pub max_proposer_discount: Decimal,
+    pub execution_window: i64,
+    pub min_sponsor_stake: Decimal,
+    pub conviction_multiplier_per_day: Decimal,
+    pub max_extra_lock_days: i64,
+    pub max_steps_per_execution: i64,
+    pub proposer_cooldown_days: i64,
+    pub max_vote_weight_fraction: Decimal,
+    pub execution_permission: ExecutionPermission,
+    pub min_proposer_stake: Decimal,
+    pub min_vote_power: Decimal,
+}
+
+/// GovernanceStats structure, holding counters of the proposals of the governance component over its lifetime.
+#[derive(ScryptoSbor, Clone)]
+pub struct GovernanceStats {
+    pub proposals_created: u64,
+    pub executed: u64,
+    pub rejected: u64,
+    pub vetoed: u64,
+    pub active: u64,
+    pub votes_concluded: u64,
+    pub average_turnout: Decimal,
+}
+
+/// ProposalInfo structure, holding the returnable state of a proposal, leaving out its votes and steps.
+#[derive(ScryptoSbor, Clone)]
+pub struct ProposalInfo {
+    pub title: String,
+    pub description: String,
+    pub votes_for: Decimal,
+    pub votes_against: Decimal,
+    pub deadline: Instant,
+    pub status: ProposalStatus,
+    pub next_index: i64,
+    pub steps: u64,
+    pub reentrancy: bool,
+}
+
+/// Event emitted when a proposal is created.
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct ProposalCreatedEvent {
+    pub proposal_id: u64,
+    pub fee_paid: Decimal,
+}
+
+/// Event emitted when a proposal is submitted, opening it for voting.
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct ProposalSubmittedEvent {
+    pub proposal_id: u64,
+    pub deadline: Instant,
+}
+
+/// Event emitted when a vote is cast on a proposal (or changed), carrying the new vote tally.
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct VoteCastEvent {
+    pub proposal_id: u64,
+    pub for_against: bool,
+    pub vote_power: Decimal,
+    pub votes_for: Decimal,
+    pub votes_against: Decimal,
+}
+
+/// Event emitted when a proposal enters veto mode.
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct VetoModeEnteredEvent {
+    pub proposal_id: u64,
+    pub votes_for: Decimal,
+    pub votes_against: Decimal,
+    pub deadline: Instant,
+}
+
+/// Event emitted when voting on a proposal has finished, carrying the outcome.
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct VotingFinishedEvent {
+    pub proposal_id: u64,
+    pub status: ProposalStatus,
+    pub reached_quorum: bool,
+    pub votes_for: Decimal,
+    pub votes_against: Decimal,
+}
+
+/// Event emitted when a step of an accepted proposal has been executed.
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct ProposalStepExecutedEvent {
+    pub proposal_id: u64,
+    pub step_index: i64,
 }
 
 #[blueprint]
-#[types(ResourceAddress, Vault, u64, Proposal, ProposalStatus, Decimal, Option<Vec<File>>)]
+#[types(
+    ResourceAddress,
+    Vault,
+    u64,
+    Proposal,
+    ProposalStatus,
+    Decimal,
+    Option<Vec<File>>,
+    ComponentAddress,
+    Instant
+)]
+#[events(
+    ProposalCreatedEvent,
+    ProposalSubmittedEvent,
+    VoteCastEvent,
+    VetoModeEnteredEvent,
+    VotingFinishedEvent,
+    ProposalStepExecutedEvent
+)]
 mod governance {
     enable_method_auth! {
         methods {
@@ -91,13 +228,49 @@ mod governance {
             create_proposal => PUBLIC;
             add_proposal_step => PUBLIC;
             submit_proposal => PUBLIC;
+            cosponsor_proposal => PUBLIC;
+            create_and_submit_proposal => PUBLIC;
             vote_on_proposal => PUBLIC;
+            change_vote => PUBLIC;
             finish_voting => PUBLIC;
             execute_proposal_step => PUBLIC;
             retrieve_fee => PUBLIC;
+            fund_early_voter_rewards => PUBLIC;
+            claim_early_voter_reward => PUBLIC;
+            get_approval_threshold_bps => PUBLIC;
+            get_parameters => PUBLIC;
+            get_governance_stats => PUBLIC;
+            get_token_amount => PUBLIC;
+            get_proposal_fee => PUBLIC;
+            is_executable => PUBLIC;
+            get_proposal_info => PUBLIC;
+            get_locked_fee_total => PUBLIC;
+            verify_fee_accounting => PUBLIC;
             finish_reentrancy_step => restrict_to: [OWNER];
+            abort_reentrancy_step => restrict_to: [OWNER];
+            fail_stuck_proposal => restrict_to: [OWNER];
             send_tokens => restrict_to: [OWNER];
             set_parameters => restrict_to: [OWNER];
+            set_fee => restrict_to: [OWNER];
+            set_proposal_duration => restrict_to: [OWNER];
+            set_quorum => restrict_to: [OWNER];
+            set_approval_threshold => restrict_to: [OWNER];
+            set_maximum_proposal_submit_delay => restrict_to: [OWNER];
+            set_min_proposer_stake => restrict_to: [OWNER];
+            set_min_vote_power => restrict_to: [OWNER];
+            set_approval_threshold_bps => restrict_to: [OWNER];
+            set_rejected_fee_handling => restrict_to: [OWNER];
+            set_min_text_lengths => restrict_to: [OWNER];
+            set_early_voter_reward => restrict_to: [OWNER];
+            set_allow_veto_recovery => restrict_to: [OWNER];
+            set_proposer_discount => restrict_to: [OWNER];
+            set_execution_window => restrict_to: [OWNER];
+            set_min_sponsor_stake => restrict_to: [OWNER];
+            set_conviction_voting => restrict_to: [OWNER];
+            set_max_steps_per_execution => restrict_to: [OWNER];
+            set_proposer_cooldown => restrict_to: [OWNER];
+            set_max_vote_weight_fraction => restrict_to: [OWNER];
+            set_execution_permission => restrict_to: [OWNER];
             set_staking_component => restrict_to: [OWNER];
             hurry_proposal => restrict_to: [OWNER];
         }
@@ -114,6 +287,8 @@ mod governance {
         mother_pool_token_address: ResourceAddress,
         /// The vault holding the fee paid for proposals
         proposal_fee_vault: Vault,
+        /// The total of the fees paid for proposals that are still held in the proposal fee vault
+        locked_fee_total: Decimal,
         /// Resource manager for proposal receipts
         proposal_receipt_manager: ResourceManager,
         /// KVS holding all vaults, indexed by their address (these vaults should contain badges used for authorizing proposal steps)
@@ -130,6 +305,18 @@ mod governance {
         controller_badge_address: ResourceAddress,
         /// The address of the component
         component_address: ComponentAddress,
+        /// The vault holding the mother tokens used to reward early voters
+        early_voter_reward_vault: Vault,
+        /// The amount of mother tokens shared by the voters of a proposal, earlier votes earning a larger share
+        early_voter_reward: Decimal,
+        /// The amount of mother tokens in the early voter reward vault reserved for concluded proposals
+        reserved_early_voter_rewards: Decimal,
+        /// KVS holding the number of executed proposals per proposer account, used to discount their proposal fee
+        proposer_records: KeyValueStore<ComponentAddress, u64>,
+        /// KVS holding the time of the last proposal per proposer account, used to enforce the proposer cooldown
+        last_proposals: KeyValueStore<ComponentAddress, Instant>,
+        /// Counters of the proposals over the lifetime of the component, updated at status transitions
+        stats: GovernanceStats,
     }
 
     impl Governance {
@@ -211,6 +398,22 @@ mod governance {
                 quorum: dec!(10000),
                 approval_threshold: dec!("0.5"),
                 maximum_proposal_submit_delay: 7,
+                rejected_fee_handling: RejectedFeeHandling::ReturnToTreasury,
+                min_title_len: 0,
+                min_description_len: 0,
+                allow_veto_recovery: false,
+                proposer_discount: dec!(0),
+                max_proposer_discount: dec!(0),
+                execution_window: 7,
+                min_sponsor_stake: dec!(0),
+                conviction_multiplier_per_day: dec!(0),
+                max_extra_lock_days: 0,
+                max_steps_per_execution: 10,
+                proposer_cooldown_days: 0,
+                max_vote_weight_fraction: dec!(0),
+                execution_permission: ExecutionPermission::Anyone,
+                min_proposer_stake: dec!(0),
+                min_vote_power: dec!(0),
             };
 
             let vaults: KeyValueStore<ResourceAddress, Vault> =
@@ -226,6 +429,7 @@ mod governance {
                 mother_token_address,
                 mother_pool_token_address,
                 proposal_fee_vault: Vault::new(mother_token_address),
+                locked_fee_total: dec!(0),
                 vaults,
                 proposal_receipt_manager,
                 proposals: GovernanceKeyValueStore::new_with_registered_type(),
@@ -235,9 +439,25 @@ mod governance {
                 controller_badge_address,
                 component_address,
                 reentrancy,
+                early_voter_reward_vault: Vault::new(mother_token_address),
+                early_voter_reward: dec!(0),
+                reserved_early_voter_rewards: dec!(0),
+                proposer_records: GovernanceKeyValueStore::new_with_registered_type(),
+                last_proposals: GovernanceKeyValueStore::new_with_registered_type(),
+                stats: GovernanceStats {
+                    proposals_created: 0,
+                    executed: 0,
+                    rejected: 0,
+                    vetoed: 0,
+                    active: 0,
+                    votes_concluded: 0,
+                    average_turnout: dec!(0),
+                },
             }
             .instantiate()
-            .prepare_to_globalize(OwnerRole::Fixed(rule!(require(controller_badge_address))))
+            .prepare_to_globalize(OwnerRole::Updatable(rule!(require(
+                controller_badge_address
+            ))))
             .with_address(address_reservation)
             .metadata(metadata! {
                 init {
@@ -307,18 +527,29 @@ mod governance {
         /// - `args`: Arguments to pass to the method (in the first step)
         /// - `return_bucket`: Whether the method returns a bucket
         /// - `payment`: Payment for the proposal
+        /// - `max_fee`: Optional maximum fee the proposer is willing to pay, protecting against fee changes (no maximum if None)
+        /// - `proposer_proof`: Optional proof of an account owner badge, identifying the proposer to discount the fee for (required if a proposer cooldown is set)
+        /// - `proposer_id_proof`: Optional proof of the proposer's staking ID (required if a minimum proposer stake is set)
         ///
         /// # Output
         /// - A bucket with the leftover payment
         /// - A bucket with the incomplete proposal receipt
         ///
         /// # Logic
+        /// - Checks if the title and description are long enough
+        /// - Gets the proposer account from the proposer proof, if supplied
+        /// - If a proposer cooldown is set, checks whether the proposer's last proposal was long enough ago
+        /// - Records the time of the proposal for the proposer
+        /// - If a minimum proposer stake is set, checks whether the proposer's staking ID has enough tokens staked (co-sponsors can't count towards it, as they can only join after creation, their combined stake being checked against the minimum sponsor stake on submission)
+        /// - Calculates the fee, discounted by the number of executed proposals of the proposer
+        /// - Checks if the fee does not exceed the maximum fee, if supplied
         /// - Checks if the payment is correct and more than the fee
         /// - Puts the fee into the proposal fee vault
         /// - Creates a new ProposalStep with the given parameters
         /// - Creates a new Proposal with this ProposalStep
         /// - Mints a new ProposalReceipt for this proposal
         /// - Inserts the proposal into the proposals KVS
+        /// - Emits a ProposalCreatedEvent
         /// - Increments the proposal counter
         pub fn create_proposal(
             &mut self,
@@ -332,15 +563,74 @@ mod governance {
             return_bucket: bool,
             reentrancy: bool,
             mut payment: Bucket,
+            max_fee: Option<Decimal>,
+            proposer_proof: Option<Proof>,
+            proposer_id_proof: Option<NonFungibleProof>,
         ) -> (Bucket, Bucket) {
             assert!(
-                payment.resource_address() == self.mother_token_address
-                    && payment.amount() >= self.parameters.fee,
+                title.chars().count() as u64 >= self.parameters.min_title_len,
+                "Title too short, must be at least {} characters.",
+                self.parameters.min_title_len
+            );
+            assert!(
+                description.chars().count() as u64 >= self.parameters.min_description_len,
+                "Description too short, must be at least {} characters.",
+                self.parameters.min_description_len
+            );
+
+            let proposer: Option<ComponentAddress> = proposer_proof.map(Self::proposer_account);
+            let now: Instant = Clock::current_time_rounded_to_seconds();
+
+            if self.parameters.proposer_cooldown_days > 0 {
+                assert!(
+                    proposer.is_some(),
+                    "Proposer proof required, as a proposer cooldown is set!"
+                );
+                if let Some(last_proposal) = self.last_proposals.get(&proposer.unwrap()) {
+                    assert!(
+                        Clock::current_time_is_at_or_after(
+                            last_proposal
+                                .add_days(self.parameters.proposer_cooldown_days)
+                                .unwrap(),
+                            TimePrecision::Second
+                        ),
+                        "Proposer cooldown has not passed yet!"
+                    );
+                }
+            }
+            if let Some(proposer) = proposer {
+                self.last_proposals.insert(proposer, now);
+            }
+
+            if self.parameters.min_proposer_stake > dec!(0) {
+                let id_proof = proposer_id_proof
+                    .expect("Staking ID proof required, as a minimum proposer stake is set!")
+                    .check_with_message(self.voting_id_address, "Invalid staking ID supplied!");
+                let id_data: Id = id_proof.non_fungible::<Id>().data();
+                assert!(
+                    self.staking.get_real_amount(id_data.pool_amount_staked)
+                        >= self.parameters.min_proposer_stake,
+                    "Stake of the proposer is below the minimum proposer stake!"
+                );
+            }
+
+            let fee: Decimal = self.get_proposal_fee(proposer);
+
+            if let Some(max_fee) = max_fee {
+                assert!(
+                    fee <= max_fee,
+                    "Proposal fee of {} exceeds the maximum fee!",
+                    fee
+                );
+            }
+
+            assert!(
+                payment.resource_address() == self.mother_token_address && payment.amount() >= fee,
                 "Invalid payment, must be more than the fee and correct token."
             );
 
-            self.proposal_fee_vault
-                .put(payment.take(self.parameters.fee));
+            self.proposal_fee_vault.put(payment.take(fee));
+            self.locked_fee_total += fee;
 
             let first_step = ProposalStep {
                 component,
@@ -359,17 +649,28 @@ mod governance {
                 votes_for: dec!(0),
                 votes_against: dec!(0),
                 votes: KeyValueStore::new(),
+                raw_votes: KeyValueStore::new(),
+                total_raw_votes: dec!(0),
                 deadline: Clock::current_time_rounded_to_seconds()
-                    .add_minutes(self.parameters.maximum_proposal_submit_delay * 24 * 60)
+                    .add_days(self.parameters.maximum_proposal_submit_delay)
                     .unwrap(),
                 next_index: 0,
                 has_failed_in_last_day: None,
+                veto_extended: false,
                 status: ProposalStatus::Building,
                 reentrancy: false,
+                reached_quorum: false,
+                vote_weights: KeyValueStore::new(),
+                total_vote_weight: dec!(0),
+                early_voter_reward: dec!(0),
+                proposer,
+                failure_reason: None,
+                sponsors: IndexSet::new(),
+                max_vote_power: None,
             };
 
             let proposal_receipt = ProposalReceipt {
-                fee_paid: self.parameters.fee,
+                fee_paid: fee,
                 proposal_id: self.proposal_counter,
                 status: ProposalStatus::Building,
             };
@@ -381,7 +682,12 @@ mod governance {
                 );
 
             self.proposals.insert(self.proposal_counter, proposal);
+            Runtime::emit_event(ProposalCreatedEvent {
+                proposal_id: self.proposal_counter,
+                fee_paid: fee,
+            });
             self.proposal_counter += 1;
+            self.stats.proposals_created += 1;
 
             (payment, incomplete_proposal_receipt)
         }
@@ -439,6 +745,47 @@ mod governance {
             proposal.steps.push(step);
         }
 
+        /// Co-sponsors a proposal with a staking ID, adding its stake to the stake backing the proposal.
+        ///
+        /// # Input
+        /// - `proposal_receipt_proof`: Proof of the proposal receipt of the proposal to co-sponsor
+        /// - `sponsor_id_proof`: Proof of the staking ID of the sponsor
+        ///
+        /// # Output
+        /// - None
+        ///
+        /// # Logic
+        /// - Checks if the proposal receipt is valid
+        /// - Checks whether the proposal is in the building phase
+        /// - Checks the staking ID and whether it isn't already sponsoring the proposal
+        /// - Adds the staking ID to the sponsors of the proposal (the proposer can sponsor with their own staking ID)
+        pub fn cosponsor_proposal(
+            &mut self,
+            proposal_receipt_proof: NonFungibleProof,
+            sponsor_id_proof: NonFungibleProof,
+        ) {
+            let receipt_proof = proposal_receipt_proof.check_with_message(
+                self.proposal_receipt_manager.address(),
+                "Invalid proposal receipt supplied!",
+            );
+
+            let receipt = receipt_proof.non_fungible::<ProposalReceipt>().data();
+            assert!(
+                receipt.status == ProposalStatus::Building,
+                "Proposal is not being built!"
+            );
+
+            let id_proof = sponsor_id_proof
+                .check_with_message(self.voting_id_address, "Invalid staking ID supplied!");
+            let id: NonFungibleLocalId = id_proof.non_fungible_local_id();
+
+            let mut proposal = self.proposals.get_mut(&receipt.proposal_id).unwrap();
+            assert!(
+                proposal.sponsors.insert(id),
+                "Staking ID already sponsors this proposal!"
+            );
+        }
+
         /// Submits a proposal.
         ///
         /// # Input
@@ -450,9 +797,12 @@ mod governance {
         /// # Logic
         /// - Checks if the proposal receipt is valid
         /// - Checks whether the proposal is in the building phase
+        /// - Checks whether the combined stake of the sponsors meets the minimum sponsor stake
+        /// - If a vote weight cap is set, snapshots the maximum vote power of a single staking ID from the total amount staked
         /// - Updates the proposal status to ongoing
         /// - Updates the proposal deadline
         /// - Updates the proposal receipt status to ongoing
+        /// - Emits a ProposalSubmittedEvent
         pub fn submit_proposal(&mut self, proposal_receipt_proof: NonFungibleProof) {
             let receipt_proof = proposal_receipt_proof.check_with_message(
                 self.proposal_receipt_manager.address(),
@@ -478,8 +828,10 @@ mod governance {
                     ))
                     .fee_paid;
                 let fee_tokens: Bucket = self.proposal_fee_vault.take(fee_paid);
-                self.put_tokens(fee_tokens);
+                self.locked_fee_total -= fee_paid;
+                self.handle_rejected_fee(fee_tokens);
                 self.proposals.get_mut(&proposal_id).unwrap().status = ProposalStatus::Rejected;
+                self.stats.rejected += 1;
                 self.proposal_receipt_manager.update_non_fungible_data(
                     &NonFungibleLocalId::integer(proposal_id),
                     "status",
@@ -488,7 +840,29 @@ mod governance {
             } else {
                 let mut proposal = self.proposals.get_mut(&proposal_id).unwrap();
 
+                if self.parameters.min_sponsor_stake > dec!(0) {
+                    let id_manager: ResourceManager = ResourceManager::from(self.voting_id_address);
+                    let mut pool_amount_sponsored: Decimal = dec!(0);
+                    for id in proposal.sponsors.iter() {
+                        let id_data: Id = id_manager.get_non_fungible_data(id);
+                        pool_amount_sponsored += id_data.pool_amount_staked;
+                    }
+                    assert!(
+                        self.staking.get_real_amount(pool_amount_sponsored)
+                            >= self.parameters.min_sponsor_stake,
+                        "Combined stake of the sponsors is below the minimum sponsor stake!"
+                    );
+                }
+
+                if self.parameters.max_vote_weight_fraction > dec!(0) {
+                    proposal.max_vote_power = Some(
+                        self.parameters.max_vote_weight_fraction
+                            * self.staking.get_pool_amount_staked(),
+                    );
+                }
+
                 proposal.status = ProposalStatus::Ongoing;
+                self.stats.active += 1;
                 proposal.deadline = Clock::current_time_rounded_to_seconds()
                     .add_minutes(self.parameters.proposal_duration * 24 * 60)
                     .unwrap();
@@ -498,38 +872,169 @@ mod governance {
                     "status",
                     proposal.status,
                 );
+
+                Runtime::emit_event(ProposalSubmittedEvent {
+                    proposal_id,
+                    deadline: proposal.deadline,
+                });
             }
         }
 
+        /// Creates and immediately submits a single-step proposal.
+        ///
+        /// # Input
+        /// - `title`: Title of the proposal
+        /// - `description`: Description of the proposal
+        /// - `component`: Address of the component to call
+        /// - `badge`: Badge to use for authorization
+        /// - `method`: Method to call on the component
+        /// - `args`: Arguments to pass to the method
+        /// - `return_bucket`: Whether the method returns a bucket
+        /// - `payment`: Payment for the proposal
+        /// - `max_fee`: Optional maximum fee the proposer is willing to pay (no maximum if None)
+        /// - `proposer_proof`: Optional proof of an account owner badge, identifying the proposer to discount the fee for
+        /// - `proposer_id_proof`: Optional proof of the proposer's staking ID (required if a minimum proposer stake is set)
+        ///
+        /// # Output
+        /// - A bucket with the leftover payment
+        /// - A bucket with the ongoing proposal receipt
+        ///
+        /// # Logic
+        /// - Creates the proposal using the `create_proposal` method
+        /// - Submits the proposal using the `submit_proposal` method, with a proof of the freshly minted receipt
+        pub fn create_and_submit_proposal(
+            &mut self,
+            title: String,
+            description: String,
+            files: Option<Vec<File>>,
+            component: ComponentAddress,
+            badge: ResourceAddress,
+            method: String,
+            args: ScryptoValue,
+            return_bucket: bool,
+            reentrancy: bool,
+            payment: Bucket,
+            max_fee: Option<Decimal>,
+            proposer_proof: Option<Proof>,
+            proposer_id_proof: Option<NonFungibleProof>,
+        ) -> (Bucket, Bucket) {
+            let (payment, proposal_receipt): (Bucket, Bucket) = self.create_proposal(
+                title,
+                description,
+                files,
+                component,
+                badge,
+                method,
+                args,
+                return_bucket,
+                reentrancy,
+                payment,
+                max_fee,
+                proposer_proof,
+                proposer_id_proof,
+            );
+
+            self.submit_proposal(proposal_receipt.as_non_fungible().create_proof_of_all());
+
+            (payment, proposal_receipt)
+        }
+
         /// Votes on a proposal.
         ///
         /// # Input
         /// - `proposal_id`: ID of the proposal to vote on
         /// - `for_against`: Whether to vote for or against the proposal
         /// - `voting_id_proof`: Proof of the voting ID to use for voting
+        /// - `extra_lock_days`: Optional number of days to keep the staked tokens locked after the vote ends, in exchange for a vote-weight multiplier
         ///
         /// # Output
         /// - None
         ///
         /// # Logic
         /// - Checks whether the proposal is ongoing or in veto mode, so whether it's even votable
-        /// - If voted for, checks whether the proposal is not in veto mode (and whether < 1 day is left), if both are the case, the proposal can't be voted for on!
+        /// - If voted for, checks whether the proposal is not in veto mode (and whether < 1 day is left), if both are the case, the proposal can't be voted for on, unless veto recovery is allowed!
         /// - If the proposal hasn't entered the last day yet, checks whether it is now in the last day, if so, checks whether the proposal has failed, and if so, enters veto mode
         /// - Gets ID from the voting ID proof
         /// - Checks if the voting period has passed
         /// - Checks if the user has already voted on this proposal
-        ///    - if so, checks if the user is changing their vote, which isn't allowed
+        ///    - if so, the vote can only be changed through `change_vote`
         /// - Checks if the proposal is ongoing
-        /// - Calculates vote power
-        /// - Adds the vote to the proposal
-        /// - If in last day, checks if the proposal has failed, and if so, enters veto mode
+        /// - Calculates vote power, locking the staked tokens until a day after the deadline plus any extra lock days
+        /// - Applies the conviction multiplier for the extra lock days to the vote power, for this proposal only
+        /// - Checks if the vote power meets the minimum vote power
+        /// - Caps the vote power at the proposal's maximum vote power, if a vote weight cap was set when it was submitted
+        /// - Adds the vote to the proposal, also recording the vote power before the multiplier and cap to calculate the turnout
+        /// - Records the vote weight for the early voter reward, being the vote power times the days left until the deadline
+        /// - Emits a VoteCastEvent with the vote power and the new vote tally
+        /// - If in last day, checks if the proposal has failed, and if so, enters veto mode (emitting a VetoModeEnteredEvent)
+        /// - If in veto mode and veto recovery is allowed, checks if the proposal passes again, and if so, exits veto mode
+        /// - Entering veto mode extends the deadline by a day only the first time, so recovering and re-entering veto mode can't keep extending the vote
 
         pub fn vote_on_proposal(
             &mut self,
             proposal_id: u64,
             for_against: bool,
             voting_id_proof: NonFungibleProof,
+            extra_lock_days: Option<i64>,
         ) {
+            self.cast_vote(
+                proposal_id,
+                for_against,
+                voting_id_proof,
+                extra_lock_days,
+                false,
+            );
+        }
+
+        /// Changes a vote on a proposal to the other side.
+        ///
+        /// # Input
+        /// - `proposal_id`: ID of the proposal to change the vote on
+        /// - `for_against`: Whether to now vote for or against the proposal
+        /// - `voting_id_proof`: Proof of the voting ID that voted on the proposal
+        /// - `extra_lock_days`: Optional number of days to keep the staked tokens locked after the vote ends, in exchange for a vote-weight multiplier
+        ///
+        /// # Output
+        /// - None
+        ///
+        /// # Logic
+        /// - Goes through the same checks as `vote_on_proposal`, so a vote can't be changed to for in the last day of veto mode
+        /// - Checks if the user has voted on this proposal, on the other side
+        /// - Removes the previous vote, its vote power before the multiplier and cap, and its vote weight for the early voter reward from the proposal
+        /// - Casts the new vote as `vote_on_proposal` does, extending (never shortening) the voting lock of the staked tokens
+        /// - A switch can make the proposal enter veto mode again, but the deadline is only ever extended the first time it enters veto mode
+        pub fn change_vote(
+            &mut self,
+            proposal_id: u64,
+            for_against: bool,
+            voting_id_proof: NonFungibleProof,
+            extra_lock_days: Option<i64>,
+        ) {
+            self.cast_vote(
+                proposal_id,
+                for_against,
+                voting_id_proof,
+                extra_lock_days,
+                true,
+            );
+        }
+
+        /// Casts a vote on a proposal, or changes an existing vote, see `vote_on_proposal` and `change_vote`.
+        fn cast_vote(
+            &mut self,
+            proposal_id: u64,
+            for_against: bool,
+            voting_id_proof: NonFungibleProof,
+            extra_lock_days: Option<i64>,
+            change_vote: bool,
+        ) {
+            let extra_lock_days: i64 = extra_lock_days.unwrap_or(0);
+            assert!(
+                extra_lock_days >= 0 && extra_lock_days <= self.parameters.max_extra_lock_days,
+                "Extra lock days must be between 0 and {}!",
+                self.parameters.max_extra_lock_days
+            );
+
             let mut proposal = self.proposals.get_mut(&proposal_id).unwrap();
 
             assert!(
@@ -539,6 +1044,7 @@ mod governance {
             );
 
             if proposal.status == ProposalStatus::VetoMode
+                && !self.parameters.allow_veto_recovery
                 && Clock::current_time_is_at_or_after(
                     proposal.deadline.add_days(-1).unwrap(),
                     TimePrecision::Second,
@@ -564,7 +1070,16 @@ mod governance {
                 } else {
                     proposal.has_failed_in_last_day = Some(true);
                     proposal.status = ProposalStatus::VetoMode;
-                    proposal.deadline = proposal.deadline.add_days(1).unwrap();
+                    if !proposal.veto_extended {
+                        proposal.veto_extended = true;
+                        proposal.deadline = proposal.deadline.add_days(1).unwrap();
+                    }
+                    Runtime::emit_event(VetoModeEnteredEvent {
+                        proposal_id,
+                        votes_for: proposal.votes_for,
+                        votes_against: proposal.votes_against,
+                        deadline: proposal.deadline,
+                    });
                 }
             }
 
@@ -572,8 +1087,17 @@ mod governance {
                 .check_with_message(self.voting_id_address, "Invalid staking ID supplied!");
             let id: NonFungibleLocalId = id_proof.as_non_fungible().non_fungible_local_id();
 
-            if let Some(vote) = proposal.votes.get(&id) {
-                if *vote >= dec!(0) {
+            let previous_vote: Option<Decimal> = proposal.votes.get(&id).map(|vote| *vote);
+
+            if change_vote {
+                let previous_vote: Decimal =
+                    previous_vote.expect("You haven't voted on this proposal yet!");
+                assert!(
+                    (previous_vote < dec!(0)) == for_against,
+                    "Your vote is already on this side!"
+                );
+            } else if let Some(vote) = previous_vote {
+                if vote >= dec!(0) {
                     panic!("You have already voted for this proposal!");
                 } else {
                     panic!("You have already voted against this proposal!");
@@ -585,15 +1109,49 @@ mod governance {
                 "Voting period has passed!"
             );
 
-            let vote_power: Decimal = self
+            if let Some(previous_vote) = previous_vote {
+                if previous_vote >= dec!(0) {
+                    proposal.votes_for -= previous_vote;
+                } else {
+                    proposal.votes_against += previous_vote;
+                }
+                let previous_weight: Decimal = proposal
+                    .vote_weights
+                    .get(&id)
+                    .map_or(dec!(0), |weight| *weight);
+                proposal.total_vote_weight -= previous_weight;
+                let previous_raw_vote: Decimal = proposal
+                    .raw_votes
+                    .get(&id)
+                    .map_or(dec!(0), |raw_vote| *raw_vote);
+                proposal.total_raw_votes -= previous_raw_vote;
+            }
+
+            let raw_vote_power: Decimal = self
                 .vaults
                 .get_mut(&self.controller_badge_address)
                 .unwrap()
                 .as_fungible()
                 .authorize_with_amount(dec!("0.75"), || {
-                    self.staking
-                        .vote(proposal.deadline.add_days(1).unwrap(), id.clone())
+                    self.staking.vote(
+                        proposal.deadline.add_days(1 + extra_lock_days).unwrap(),
+                        id.clone(),
+                    )
                 });
+            let mut vote_power: Decimal = raw_vote_power
+                * (dec!(1)
+                    + self.parameters.conviction_multiplier_per_day
+                        * Decimal::from(extra_lock_days));
+
+            assert!(
+                vote_power >= self.parameters.min_vote_power,
+                "Vote power is below the minimum vote power of {}!",
+                self.parameters.min_vote_power
+            );
+
+            if let Some(max_vote_power) = proposal.max_vote_power {
+                vote_power = vote_power.min(max_vote_power);
+            }
 
             if for_against {
                 proposal.votes.insert(id.clone(), vote_power);
@@ -602,6 +1160,22 @@ mod governance {
                 proposal.votes.insert(id.clone(), dec!("-1") * vote_power);
                 proposal.votes_against += vote_power;
             }
+            proposal.raw_votes.insert(id.clone(), raw_vote_power);
+            proposal.total_raw_votes += raw_vote_power;
+
+            let seconds_left: i64 = proposal.deadline.seconds_since_unix_epoch
+                - Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+            let vote_weight: Decimal = vote_power * Decimal::from(seconds_left) / dec!(86400);
+            proposal.vote_weights.insert(id.clone(), vote_weight);
+            proposal.total_vote_weight += vote_weight;
+
+            Runtime::emit_event(VoteCastEvent {
+                proposal_id,
+                for_against,
+                vote_power,
+                votes_for: proposal.votes_for,
+                votes_against: proposal.votes_against,
+            });
 
             let proposal_failing: bool = proposal.votes_for
                 <= self.parameters.approval_threshold
@@ -612,8 +1186,23 @@ mod governance {
                 && proposal_failing
             {
                 proposal.has_failed_in_last_day = Some(true);
-                proposal.deadline = proposal.deadline.add_days(1).unwrap();
+                if !proposal.veto_extended {
+                    proposal.veto_extended = true;
+                    proposal.deadline = proposal.deadline.add_days(1).unwrap();
+                }
                 proposal.status = ProposalStatus::VetoMode;
+                Runtime::emit_event(VetoModeEnteredEvent {
+                    proposal_id,
+                    votes_for: proposal.votes_for,
+                    votes_against: proposal.votes_against,
+                    deadline: proposal.deadline,
+                });
+            } else if self.parameters.allow_veto_recovery
+                && proposal.status == ProposalStatus::VetoMode
+                && !proposal_failing
+            {
+                proposal.has_failed_in_last_day = Some(false);
+                proposal.status = ProposalStatus::Ongoing;
             }
         }
 
@@ -630,9 +1219,15 @@ mod governance {
         /// - Checks if the proposal is ongoing
         /// - Checks if the voting period has passed
         /// - Checks if the proposal has enough votes to be accepted
+        /// - Records whether the proposal reached quorum
         /// - Updates the proposal status (to either Accepted or Rejected)
+        /// - Updates the governance stats, counting a rejection in veto mode as vetoed, and adding the turnout (share of the staked tokens that voted, without conviction multipliers or vote caps) to the average turnout
+        /// - If anyone voted, reserves the early voter reward for the proposal, as far as the early voter reward vault allows
+        /// - If rejected without reaching quorum, handles the fee according to the rejected fee handling parameter
+        /// - If rejected after reaching quorum, the fee stays refundable through the proposal receipt
+        /// - Emits a VotingFinishedEvent with the outcome
         pub fn finish_voting(&mut self, proposal_id: u64) {
-            let mut accepted: bool = true;
+            let mut refund_fee: bool = true;
             {
                 let mut proposal = self.proposals.get_mut(&proposal_id).unwrap();
 
@@ -651,14 +1246,40 @@ mod governance {
                 let votes_for: Decimal = proposal.votes_for * pool_unit_multiplier;
                 let votes_against: Decimal = proposal.votes_against * pool_unit_multiplier;
                 let total_votes = votes_against + votes_for;
+                proposal.reached_quorum = total_votes >= self.parameters.quorum;
+
+                let pool_amount_staked: Decimal = self.staking.get_pool_amount_staked();
+                let turnout: Decimal = if pool_amount_staked > dec!(0) {
+                    proposal.total_raw_votes / pool_amount_staked
+                } else {
+                    dec!(0)
+                };
+                self.stats.average_turnout = (self.stats.average_turnout
+                    * Decimal::from(self.stats.votes_concluded)
+                    + turnout)
+                    / Decimal::from(self.stats.votes_concluded + 1);
+                self.stats.votes_concluded += 1;
+                self.stats.active -= 1;
 
                 if (votes_for > self.parameters.approval_threshold * total_votes)
-                    && (total_votes >= self.parameters.quorum)
+                    && proposal.reached_quorum
                 {
                     proposal.status = ProposalStatus::Accepted;
                 } else {
+                    if proposal.status == ProposalStatus::VetoMode {
+                        self.stats.vetoed += 1;
+                    } else {
+                        self.stats.rejected += 1;
+                    }
                     proposal.status = ProposalStatus::Rejected;
-                    accepted = false;
+                    refund_fee = proposal.reached_quorum;
+                }
+
+                if proposal.total_vote_weight > dec!(0) {
+                    proposal.early_voter_reward = self.early_voter_reward.min(
+                        self.early_voter_reward_vault.amount() - self.reserved_early_voter_rewards,
+                    );
+                    self.reserved_early_voter_rewards += proposal.early_voter_reward;
                 }
 
                 self.proposal_receipt_manager.update_non_fungible_data(
@@ -666,8 +1287,16 @@ mod governance {
                     "status",
                     proposal.status,
                 );
+
+                Runtime::emit_event(VotingFinishedEvent {
+                    proposal_id,
+                    status: proposal.status,
+                    reached_quorum: proposal.reached_quorum,
+                    votes_for: proposal.votes_for,
+                    votes_against: proposal.votes_against,
+                });
             }
-            if accepted == false {
+            if refund_fee == false {
                 let fee_paid: Decimal = self
                     .proposal_receipt_manager
                     .get_non_fungible_data::<ProposalReceipt>(&NonFungibleLocalId::integer(
@@ -675,7 +1304,8 @@ mod governance {
                     ))
                     .fee_paid;
                 let fee_tokens: Bucket = self.proposal_fee_vault.take(fee_paid);
-                self.put_tokens(fee_tokens);
+                self.locked_fee_total -= fee_paid;
+                self.handle_rejected_fee(fee_tokens);
             }
         }
 
@@ -683,20 +1313,34 @@ mod governance {
         ///
         /// # Input
         /// - `proposal_id`: ID of the proposal to execute the step for
-        /// - `steps_to_execute`: Number of steps to execute
+        /// - `steps_to_execute`: Number of steps to execute, capped at the maximum steps per execution (callers should loop until the proposal is executed)
+        /// - `executor_proof`: Optional proof of the proposal receipt or executor badge (required if execution is restricted)
         ///
         /// # Output
         /// - None
         ///
         /// # Logic
+        /// - Checks if the caller is permitted to execute the proposal
         /// - Checks if the proposal is accepted
+        /// - Checks if the execution window after the voting deadline hasn't passed yet
         /// - Checks if the previous step required reentrancy (and whether this has been completed yet)
-        /// - Executes the steps
+        /// - Caps the number of steps to execute at the maximum steps per execution
+        /// - Executes the steps, stopping early at a reentrancy step or when all steps have been executed, emitting a ProposalStepExecutedEvent for every executed step
         /// - Updates the proposal status to executed if all steps have been executed
         /// - Handles potentially returned buckets
-        pub fn execute_proposal_step(&mut self, proposal_id: u64, steps_to_execute: i64) {
+        pub fn execute_proposal_step(
+            &mut self,
+            proposal_id: u64,
+            steps_to_execute: i64,
+            executor_proof: Option<Proof>,
+        ) {
+            self.assert_execution_permission(proposal_id, executor_proof);
+
+            let steps_to_execute: i64 =
+                steps_to_execute.min(self.parameters.max_steps_per_execution);
             let mut buckets: Vec<Bucket> = Vec::new();
             let mut reentrancy_happened = false;
+            let mut executed_proposer: Option<ComponentAddress> = None;
             {
                 let mut proposal = self.proposals.get_mut(&proposal_id).unwrap();
                 assert!(
@@ -704,6 +1348,17 @@ mod governance {
                     "Proposal not accepted!"
                 );
 
+                assert!(
+                    !Clock::current_time_is_at_or_after(
+                        proposal
+                            .deadline
+                            .add_days(self.parameters.execution_window)
+                            .unwrap(),
+                        TimePrecision::Second
+                    ),
+                    "Execution window has passed!"
+                );
+
                 assert!(
                     proposal.reentrancy == false,
                     "The previous step required reentrancy! Complete this first by calling the ReentrancyProxy component."
@@ -749,6 +1404,10 @@ mod governance {
                         }
                     }
 
+                    Runtime::emit_event(ProposalStepExecutedEvent {
+                        proposal_id,
+                        step_index: proposal.next_index,
+                    });
                     proposal.next_index += 1;
 
                     if proposal.next_index as usize == proposal.steps.len() {
@@ -759,14 +1418,20 @@ mod governance {
                     proposal.reentrancy = true;
                 } else if proposal.next_index as usize == proposal.steps.len() {
                     proposal.status = ProposalStatus::Executed;
+                    self.stats.executed += 1;
                     self.proposal_receipt_manager.update_non_fungible_data(
                         &NonFungibleLocalId::integer(proposal_id),
                         "status",
                         proposal.status,
                     );
+                    executed_proposer = proposal.proposer;
                 }
             }
 
+            if let Some(proposer) = executed_proposer {
+                self.record_executed_proposal(proposer);
+            }
+
             for bucket in buckets {
                 self.put_tokens(bucket);
             }
@@ -783,22 +1448,137 @@ mod governance {
         /// - None
         ///
         /// # Logic
+        /// - Checks if the proposal is accepted and waiting for a reentrancy step
+        /// - Emits a ProposalStepExecutedEvent for the reentrancy step
         /// - Increments the next index of the proposal
         /// - Updates the proposal status to executed if all steps have been executed
         /// - Updates the proposal receipt status to executed if all steps have been executed
         pub fn finish_reentrancy_step(&mut self, proposal_id: u64) {
-            let mut proposal = self.proposals.get_mut(&proposal_id).unwrap();
-            proposal.reentrancy = false;
-            proposal.next_index += 1;
+            let mut executed_proposer: Option<ComponentAddress> = None;
+            {
+                let mut proposal = self.proposals.get_mut(&proposal_id).unwrap();
+                assert!(
+                    proposal.status == ProposalStatus::Accepted && proposal.reentrancy,
+                    "Proposal is not waiting for a reentrancy step!"
+                );
+                proposal.reentrancy = false;
+                Runtime::emit_event(ProposalStepExecutedEvent {
+                    proposal_id,
+                    step_index: proposal.next_index,
+                });
+                proposal.next_index += 1;
 
-            if proposal.next_index as usize == proposal.steps.len() {
-                proposal.status = ProposalStatus::Executed;
+                if proposal.next_index as usize == proposal.steps.len() {
+                    proposal.status = ProposalStatus::Executed;
+                    self.stats.executed += 1;
+                    self.proposal_receipt_manager.update_non_fungible_data(
+                        &NonFungibleLocalId::integer(proposal_id),
+                        "status",
+                        proposal.status,
+                    );
+                    executed_proposer = proposal.proposer;
+                }
+            }
+
+            if let Some(proposer) = executed_proposer {
+                self.record_executed_proposal(proposer);
+            }
+        }
+
+        /// Aborts a pending reentrancy step in a proposal.
+        ///
+        /// If the ReentrancyProxy dispatches a step that the target component rejects, the proposal stays in reentrancy mode and can't advance.
+        /// This method allows the DAO to recover such a proposal, either by allowing the step to be retried, or by marking the proposal as failed.
+        /// It should only be used when the reentrancy step can't be completed through the ReentrancyProxy anymore.
+        ///
+        /// # Input
+        /// - `proposal_id`: ID of the proposal to abort the reentrancy step for
+        /// - `mark_failed`: Whether to mark the proposal as failed instead of allowing a retry
+        ///
+        /// # Output
+        /// - None
+        ///
+        /// # Logic
+        /// - Checks if the proposal is accepted and waiting for a reentrancy step
+        /// - Resets the reentrancy flag, without incrementing the next index, so the step can be retried by executing it again
+        /// - Cancels the pending step in the ReentrancyProxy, so it can't be completed anymore
+        /// - If the proposal is marked failed, updates the proposal (receipt) status to rejected and records why, the fee stays refundable as the proposal reached quorum
+        pub fn abort_reentrancy_step(&mut self, proposal_id: u64, mark_failed: bool) {
+            {
+                let mut proposal = self.proposals.get_mut(&proposal_id).unwrap();
+                assert!(
+                    proposal.status == ProposalStatus::Accepted,
+                    "Proposal not accepted!"
+                );
+                assert!(
+                    proposal.reentrancy,
+                    "Proposal is not waiting for a reentrancy step!"
+                );
+
+                proposal.reentrancy = false;
+
+                if mark_failed {
+                    proposal.status = ProposalStatus::Rejected;
+                    proposal.failure_reason = Some("Reentrancy step aborted".to_string());
+                    self.stats.rejected += 1;
+                    self.proposal_receipt_manager.update_non_fungible_data(
+                        &NonFungibleLocalId::integer(proposal_id),
+                        "status",
+                        proposal.status,
+                    );
+                }
+            }
+
+            self.cancel_reentrancy_step(proposal_id);
+        }
+
+        /// Fails an accepted proposal that hasn't been fully executed within the execution window.
+        ///
+        /// A step targeting a component or method that no longer exists makes `execute_proposal_step` panic, and as the Radix Engine can't catch panics, the proposal would stay accepted forever.
+        /// This method allows the DAO to conclude such a proposal once the execution window after its voting deadline has passed, as from then on the proposal can't be executed anymore.
+        ///
+        /// # Input
+        /// - `proposal_id`: ID of the proposal to fail
+        ///
+        /// # Output
+        /// - None
+        ///
+        /// # Logic
+        /// - Checks if the proposal is accepted
+        /// - Checks if the execution window after the voting deadline has passed
+        /// - Resets a pending reentrancy step and cancels it in the ReentrancyProxy, so it can't be completed anymore
+        /// - Updates the proposal (receipt) status to rejected and records why, the fee stays refundable as the proposal reached quorum
+        pub fn fail_stuck_proposal(&mut self, proposal_id: u64) {
+            {
+                let mut proposal = self.proposals.get_mut(&proposal_id).unwrap();
+                assert!(
+                    proposal.status == ProposalStatus::Accepted,
+                    "Proposal not accepted!"
+                );
+                assert!(
+                    Clock::current_time_is_at_or_after(
+                        proposal
+                            .deadline
+                            .add_days(self.parameters.execution_window)
+                            .unwrap(),
+                        TimePrecision::Second
+                    ),
+                    "Proposal can still be executed!"
+                );
+
+                proposal.reentrancy = false;
+                proposal.status = ProposalStatus::Rejected;
+                proposal.failure_reason =
+                    Some("Not executed within the execution window".to_string());
+                self.stats.rejected += 1;
                 self.proposal_receipt_manager.update_non_fungible_data(
                     &NonFungibleLocalId::integer(proposal_id),
                     "status",
                     proposal.status,
                 );
             }
+
+            self.cancel_reentrancy_step(proposal_id);
         }
 
         /// Retrieves the fee paid for a proposal.
@@ -811,7 +1591,7 @@ mod governance {
         ///
         /// # Logic
         /// - Checks if the proposal receipt is valid
-        /// - Checks if the proposal is executed
+        /// - Checks if the proposal is executed, or rejected after reaching quorum (an honest loss, instead of ignored spam)
         /// - Updates the proposal receipt status to finished
         /// - Returns the fee paid
         pub fn retrieve_fee(&mut self, proposal_receipt_proof: NonFungibleProof) -> Bucket {
@@ -821,9 +1601,15 @@ mod governance {
             );
             let receipt = receipt_proof.non_fungible::<ProposalReceipt>().data();
 
+            let lost_with_quorum: bool = receipt.status == ProposalStatus::Rejected
+                && self
+                    .proposals
+                    .get(&receipt.proposal_id)
+                    .map_or(false, |proposal| proposal.reached_quorum);
+
             assert!(
-                receipt.status == ProposalStatus::Executed,
-                "Only executed proposals can have their fees refunded!"
+                receipt.status == ProposalStatus::Executed || lost_with_quorum,
+                "Only executed proposals, or rejected proposals that reached quorum, can have their fees refunded!"
             );
 
             self.proposal_receipt_manager.update_non_fungible_data(
@@ -832,9 +1618,68 @@ mod governance {
                 ProposalStatus::Finished,
             );
 
+            self.locked_fee_total -= receipt.fee_paid;
             self.proposal_fee_vault.take(receipt.fee_paid)
         }
 
+        /// Puts mother tokens into the early voter reward vault, funding the rewards of early voters.
+        pub fn fund_early_voter_rewards(&mut self, tokens: Bucket) {
+            assert!(
+                tokens.resource_address() == self.mother_token_address,
+                "Early voter rewards can only be funded with the mother token!"
+            );
+            self.early_voter_reward_vault.put(tokens);
+        }
+
+        /// Claims the early voter reward of a voter for a concluded proposal.
+        ///
+        /// # Input
+        /// - `proposal_id`: ID of the proposal to claim the reward for
+        /// - `voting_id_proof`: Proof of the voting ID that voted on the proposal
+        ///
+        /// # Output
+        /// - The early voter reward
+        ///
+        /// # Logic
+        /// - Gets ID from the voting ID proof
+        /// - Checks whether voting on the proposal has finished
+        /// - Removes the vote weight of the ID, so the reward can only be claimed once
+        /// - Calculates the share of the proposal's early voter reward, being the vote weight divided by the total vote weight
+        /// - Releases the reserved reward and returns it
+        pub fn claim_early_voter_reward(
+            &mut self,
+            proposal_id: u64,
+            voting_id_proof: NonFungibleProof,
+        ) -> Bucket {
+            let id_proof = voting_id_proof
+                .check_with_message(self.voting_id_address, "Invalid staking ID supplied!");
+            let id: NonFungibleLocalId = id_proof.as_non_fungible().non_fungible_local_id();
+
+            let proposal = self.proposals.get(&proposal_id).unwrap();
+            assert!(
+                proposal.status != ProposalStatus::Building
+                    && proposal.status != ProposalStatus::Ongoing
+                    && proposal.status != ProposalStatus::VetoMode,
+                "Voting on this proposal has not finished yet!"
+            );
+
+            let vote_weight: Decimal = proposal
+                .vote_weights
+                .remove(&id)
+                .expect("No early voter reward to claim for this ID!");
+            let reward: Decimal =
+                proposal.early_voter_reward * vote_weight / proposal.total_vote_weight;
+            self.reserved_early_voter_rewards -= reward;
+
+            self.early_voter_reward_vault
+                .as_fungible()
+                .take_advanced(
+                    reward,
+                    WithdrawStrategy::Rounded(RoundingMode::ToNegativeInfinity),
+                )
+                .into()
+        }
+
         pub fn hurry_proposal(&mut self, proposal_id: u64, new_duration: i64) {
             let new_deadline = Clock::current_time_rounded_to_seconds()
                 .add_minutes(new_duration * 24 * 60)
@@ -862,7 +1707,7 @@ mod governance {
             self.voting_id_address = new_voting_id_address;
         }
 
-        /// Sets new parameters for the governance component.
+        /// Sets new parameters for the governance component, with the proposal duration and maximum proposal submit delay in days.
         pub fn set_parameters(
             &mut self,
             fee: Decimal,
@@ -870,23 +1715,413 @@ mod governance {
             quorum: Decimal,
             approval_threshold: Decimal,
             maximum_proposal_submit_delay: i64,
+            min_proposer_stake: Decimal,
         ) {
-            assert!(
-                maximum_proposal_submit_delay > 0,
-                "Maximum proposal submit delay must be positive!"
-            );
+            self.set_fee(fee);
+            self.set_proposal_duration(proposal_duration);
+            self.set_quorum(quorum);
+            self.set_approval_threshold(approval_threshold);
+            self.set_maximum_proposal_submit_delay(maximum_proposal_submit_delay);
+            self.set_min_proposer_stake(min_proposer_stake);
+        }
+
+        /// Sets the fee to create a proposal.
+        pub fn set_fee(&mut self, fee: Decimal) {
+            assert!(fee > dec!(0), "Fee must be positive!");
+            self.parameters.fee = fee;
+        }
+
+        /// Sets the number of days a proposal can be voted on.
+        pub fn set_proposal_duration(&mut self, proposal_duration: i64) {
             assert!(proposal_duration > 0, "Proposal duration must be positive!");
+            self.parameters.proposal_duration = proposal_duration;
+        }
+
+        /// Sets the amount of votes needed for a proposal to be valid.
+        pub fn set_quorum(&mut self, quorum: Decimal) {
             assert!(quorum > dec!(0), "Quorum must be positive!");
+            self.parameters.quorum = quorum;
+        }
+
+        /// Sets the fraction of votes that has to be in favor for a proposal to be accepted.
+        pub fn set_approval_threshold(&mut self, approval_threshold: Decimal) {
             assert!(
                 approval_threshold > dec!(0) && approval_threshold <= dec!(1),
                 "Approval threshold must be between 0 and 1!"
             );
-            assert!(fee > dec!(0), "Fee must be positive!");
-            self.parameters.fee = fee;
-            self.parameters.proposal_duration = proposal_duration;
-            self.parameters.quorum = quorum;
             self.parameters.approval_threshold = approval_threshold;
+        }
+
+        /// Sets the number of days a proposal can be built before it has to be submitted.
+        pub fn set_maximum_proposal_submit_delay(&mut self, maximum_proposal_submit_delay: i64) {
+            assert!(
+                maximum_proposal_submit_delay > 0,
+                "Maximum proposal submit delay must be positive!"
+            );
             self.parameters.maximum_proposal_submit_delay = maximum_proposal_submit_delay;
         }
+
+        /// Sets the minimum stake of the proposer's staking ID to be able to create a proposal (zero to disable), checked separately from the minimum combined stake of the sponsors needed to submit it.
+        pub fn set_min_proposer_stake(&mut self, min_proposer_stake: Decimal) {
+            assert!(
+                min_proposer_stake >= dec!(0),
+                "Minimum proposer stake can't be negative!"
+            );
+            self.parameters.min_proposer_stake = min_proposer_stake;
+        }
+
+        /// Sets the minimum vote power a vote needs to be cast (zero to disable).
+        pub fn set_min_vote_power(&mut self, min_vote_power: Decimal) {
+            assert!(
+                min_vote_power >= dec!(0),
+                "Minimum vote power can't be negative!"
+            );
+            self.parameters.min_vote_power = min_vote_power;
+        }
+
+        /// Sets what happens to the fee of rejected proposals, asserting the mother token is burnable if it is to be burned.
+        pub fn set_rejected_fee_handling(&mut self, rejected_fee_handling: RejectedFeeHandling) {
+            if rejected_fee_handling == RejectedFeeHandling::Burn {
+                let burner_rule: Option<AccessRule> =
+                    ResourceManager::from(self.mother_token_address).get_role("burner");
+                assert!(
+                    burner_rule.is_some() && burner_rule != Some(rule!(deny_all)),
+                    "Mother token has no burn role, so rejected fees can't be burned!"
+                );
+            }
+            self.parameters.rejected_fee_handling = rejected_fee_handling;
+        }
+
+        /// Sets the number of days after the voting deadline within which an accepted proposal has to be executed, before it can be failed.
+        pub fn set_execution_window(&mut self, execution_window: i64) {
+            assert!(execution_window > 0, "Execution window must be positive!");
+            self.parameters.execution_window = execution_window;
+        }
+
+        /// Sets the minimum combined stake of the sponsors of a proposal to be able to submit it, the proposer's stake only counting if they co-sponsor with their staking ID.
+        pub fn set_min_sponsor_stake(&mut self, min_sponsor_stake: Decimal) {
+            assert!(
+                min_sponsor_stake >= dec!(0),
+                "Minimum sponsor stake can't be negative!"
+            );
+            self.parameters.min_sponsor_stake = min_sponsor_stake;
+        }
+
+        /// Sets the vote-weight bonus per extra day a voter locks their tokens after a vote, and the maximum number of extra lock days (bonus at most doubling the vote weight).
+        pub fn set_conviction_voting(
+            &mut self,
+            conviction_multiplier_per_day: Decimal,
+            max_extra_lock_days: i64,
+        ) {
+            assert!(
+                conviction_multiplier_per_day >= dec!(0),
+                "Conviction multiplier per day can't be negative!"
+            );
+            assert!(
+                max_extra_lock_days >= 0 && max_extra_lock_days <= 365,
+                "Maximum extra lock days must be between 0 and 365!"
+            );
+            assert!(
+                conviction_multiplier_per_day * Decimal::from(max_extra_lock_days) <= dec!(1),
+                "Conviction bonus can at most double the vote weight!"
+            );
+            self.parameters.conviction_multiplier_per_day = conviction_multiplier_per_day;
+            self.parameters.max_extra_lock_days = max_extra_lock_days;
+        }
+
+        /// Sets the maximum number of proposal steps executed in a single call to `execute_proposal_step`.
+        pub fn set_max_steps_per_execution(&mut self, max_steps_per_execution: i64) {
+            assert!(
+                max_steps_per_execution > 0,
+                "Maximum steps per execution must be positive!"
+            );
+            self.parameters.max_steps_per_execution = max_steps_per_execution;
+        }
+
+        /// Sets the number of days a proposer has to wait between proposals (0 to disable).
+        pub fn set_proposer_cooldown(&mut self, proposer_cooldown_days: i64) {
+            assert!(
+                proposer_cooldown_days >= 0,
+                "Proposer cooldown can't be negative!"
+            );
+            self.parameters.proposer_cooldown_days = proposer_cooldown_days;
+        }
+
+        /// Sets the maximum fraction of the total amount staked a single staking ID can vote with on a proposal (0 to disable).
+        pub fn set_max_vote_weight_fraction(&mut self, max_vote_weight_fraction: Decimal) {
+            assert!(
+                max_vote_weight_fraction >= dec!(0) && max_vote_weight_fraction <= dec!(1),
+                "Maximum vote weight fraction must be between 0 and 1!"
+            );
+            self.parameters.max_vote_weight_fraction = max_vote_weight_fraction;
+        }
+
+        /// Sets who can execute the steps of accepted proposals.
+        pub fn set_execution_permission(&mut self, execution_permission: ExecutionPermission) {
+            self.parameters.execution_permission = execution_permission;
+        }
+
+        /// Sets whether proposals in veto mode can return to ongoing when they pass again before the deadline.
+        pub fn set_allow_veto_recovery(&mut self, allow_veto_recovery: bool) {
+            self.parameters.allow_veto_recovery = allow_veto_recovery;
+        }
+
+        /// Sets the fee discount per executed proposal of a proposer, and the maximum discount (both fractions of the fee).
+        pub fn set_proposer_discount(
+            &mut self,
+            proposer_discount: Decimal,
+            max_proposer_discount: Decimal,
+        ) {
+            assert!(
+                proposer_discount >= dec!(0),
+                "Proposer discount can't be negative!"
+            );
+            assert!(
+                max_proposer_discount >= dec!(0) && max_proposer_discount <= dec!(1),
+                "Maximum proposer discount must be between 0 and 1!"
+            );
+            self.parameters.proposer_discount = proposer_discount;
+            self.parameters.max_proposer_discount = max_proposer_discount;
+        }
+
+        /// Sets the amount of mother tokens shared by the voters of each proposal, earlier votes earning a larger share.
+        pub fn set_early_voter_reward(&mut self, early_voter_reward: Decimal) {
+            assert!(
+                early_voter_reward >= dec!(0),
+                "Early voter reward can't be negative!"
+            );
+            self.early_voter_reward = early_voter_reward;
+        }
+
+        /// Sets the minimum lengths of proposal titles and descriptions, in characters.
+        pub fn set_min_text_lengths(&mut self, min_title_len: u64, min_description_len: u64) {
+            self.parameters.min_title_len = min_title_len;
+            self.parameters.min_description_len = min_description_len;
+        }
+
+        /// Sets the approval threshold in basis points (1/10000), so it is set exactly, through set_approval_threshold.
+        pub fn set_approval_threshold_bps(&mut self, approval_threshold_bps: u16) {
+            self.set_approval_threshold(Decimal::from(approval_threshold_bps) / dec!(10000));
+        }
+
+        /// Gets the current parameters of the governance component.
+        pub fn get_parameters(&self) -> GovernanceParameters {
+            self.parameters.clone()
+        }
+
+        /// Gets the governance stats: proposals created, executed, rejected and vetoed, currently in voting, and the average turnout of concluded votes.
+        pub fn get_governance_stats(&self) -> GovernanceStats {
+            self.stats.clone()
+        }
+
+        /// Gets the proposal fee for a proposer, discounted by the number of proposals of the proposer that have been executed.
+        pub fn get_proposal_fee(&self, proposer: Option<ComponentAddress>) -> Decimal {
+            let executed: u64 = proposer
+                .and_then(|proposer| self.proposer_records.get(&proposer).map(|count| *count))
+                .unwrap_or(0);
+            let discount: Decimal = (self.parameters.proposer_discount * Decimal::from(executed))
+                .min(self.parameters.max_proposer_discount);
+            self.parameters.fee * (dec!(1) - discount)
+        }
+
+        /// Checks whether the executor proof permits executing a proposal, depending on the execution permission.
+        fn assert_execution_permission(&self, proposal_id: u64, executor_proof: Option<Proof>) {
+            match self.parameters.execution_permission {
+                ExecutionPermission::Anyone => {}
+                ExecutionPermission::ProposerOnly => {
+                    let receipt_proof = executor_proof
+                        .expect("Proposal receipt proof required, as execution is restricted to the proposer!")
+                        .check_with_message(
+                            self.proposal_receipt_manager.address(),
+                            "Invalid proposal receipt supplied!",
+                        );
+                    assert!(
+                        receipt_proof.as_non_fungible().non_fungible_local_id()
+                            == NonFungibleLocalId::integer(proposal_id),
+                        "Proposal receipt does not belong to this proposal!"
+                    );
+                }
+                ExecutionPermission::ExecutorBadge(badge_address) => {
+                    executor_proof
+                        .expect("Executor badge proof required, as execution is restricted!")
+                        .check_with_message(badge_address, "Invalid executor badge supplied!");
+                }
+            }
+        }
+
+        /// Checks whether the next step of a proposal can be executed now, and if not, why not.
+        ///
+        /// # Input
+        /// - `proposal_id`: The ID of the proposal
+        ///
+        /// # Output
+        /// - Whether the next step can be executed
+        /// - A human-readable reason
+        ///
+        /// # Logic
+        /// - Mirrors the guards of `execute_proposal_step` without changing any state
+        /// - Reports the proposal as not executable once the execution window has passed, as the proposal can then only be failed by the DAO
+        /// - Fails if the target component of the next step doesn't exist, as executing the step would fail as well
+        /// - If executable, states who is permitted to execute the proposal in the reason, depending on the execution permission
+        pub fn is_executable(&self, proposal_id: u64) -> (bool, String) {
+            let proposal = match self.proposals.get(&proposal_id) {
+                Some(proposal) => proposal,
+                None => return (false, "Proposal does not exist.".to_string()),
+            };
+
+            match proposal.status {
+                ProposalStatus::Accepted => {}
+                ProposalStatus::Executed | ProposalStatus::Finished => {
+                    return (false, "Proposal already executed.".to_string())
+                }
+                _ => return (false, "Proposal not accepted.".to_string()),
+            }
+
+            if proposal.reentrancy {
+                return (
+                    false,
+                    "Reentrancy step pending, complete it through the ReentrancyProxy component."
+                        .to_string(),
+                );
+            }
+
+            if Clock::current_time_is_at_or_after(
+                proposal
+                    .deadline
+                    .add_days(self.parameters.execution_window)
+                    .unwrap(),
+                TimePrecision::Second,
+            ) {
+                return (
+                    false,
+                    "Execution window passed, proposal can only be failed.".to_string(),
+                );
+            }
+
+            // Fails if the target component of the next step doesn't exist
+            let _target_blueprint: BlueprintId = ScryptoVmV1Api::object_get_blueprint_id(
+                proposal.steps[proposal.next_index as usize]
+                    .component
+                    .as_node_id(),
+            );
+
+            let reason: &str = match self.parameters.execution_permission {
+                ExecutionPermission::Anyone => "Proposal can be executed by anyone.",
+                ExecutionPermission::ProposerOnly => {
+                    "Proposal can be executed with a proof of its proposal receipt."
+                }
+                ExecutionPermission::ExecutorBadge(_) => {
+                    "Proposal can be executed with a proof of the executor badge."
+                }
+            };
+
+            (true, reason.to_string())
+        }
+
+        /// Gets the state of a proposal, to render it without reading the proposals KVS.
+        ///
+        /// # Input
+        /// - `proposal_id`: The ID of the proposal
+        ///
+        /// # Output
+        /// - The proposal info, or None if the proposal does not exist
+        ///
+        /// # Logic
+        /// - Copies the returnable fields of the proposal, leaving out its votes KVS and counting its steps
+        pub fn get_proposal_info(&self, proposal_id: u64) -> Option<ProposalInfo> {
+            self.proposals
+                .get(&proposal_id)
+                .map(|proposal| ProposalInfo {
+                    title: proposal.title.clone(),
+                    description: proposal.description.clone(),
+                    votes_for: proposal.votes_for,
+                    votes_against: proposal.votes_against,
+                    deadline: proposal.deadline,
+                    status: proposal.status,
+                    next_index: proposal.next_index,
+                    steps: proposal.steps.len() as u64,
+                    reentrancy: proposal.reentrancy,
+                })
+        }
+
+        /// Gets the total of the fees paid for proposals that are still held in the proposal fee vault.
+        ///
+        /// # Input
+        /// - None
+        ///
+        /// # Output
+        /// - The sum of the fees still held for all proposals
+        ///
+        /// # Logic
+        /// - Returns the running total of locked fees, which is increased when a proposal fee is paid and decreased when it is refunded or handled as a rejected fee
+        pub fn get_locked_fee_total(&self) -> Decimal {
+            self.locked_fee_total
+        }
+
+        /// Checks whether the proposal fee vault holds exactly the fees still locked for proposals.
+        pub fn verify_fee_accounting(&self) -> bool {
+            self.proposal_fee_vault.amount() == self.get_locked_fee_total()
+        }
+
+        /// Gets the amount of tokens in possession of the governance component.
+        pub fn get_token_amount(&self, address: ResourceAddress) -> Decimal {
+            self.vaults
+                .get(&address)
+                .map_or(dec!(0), |vault| vault.amount())
+        }
+
+        /// Gets the approval threshold in basis points (1/10000), rounded down.
+        pub fn get_approval_threshold_bps(&self) -> u16 {
+            let approval_threshold_bps: Decimal = (self.parameters.approval_threshold
+                * dec!(10000))
+            .checked_floor()
+            .unwrap();
+
+            u16::try_from(approval_threshold_bps.0 / Decimal::ONE.0).unwrap()
+        }
+
+        /// Handles the fee of a rejected proposal, either returning it to the treasury or burning it (authorized by the controller badge).
+        fn handle_rejected_fee(&mut self, fee_tokens: Bucket) {
+            match self.parameters.rejected_fee_handling {
+                RejectedFeeHandling::ReturnToTreasury => self.put_tokens(fee_tokens),
+                RejectedFeeHandling::Burn => self
+                    .vaults
+                    .get_mut(&self.controller_badge_address)
+                    .unwrap()
+                    .as_fungible()
+                    .authorize_with_amount(dec!("0.75"), || fee_tokens.burn()),
+            }
+        }
+
+        /// Cancels the pending step of a proposal in the ReentrancyProxy (authorized by the controller badge).
+        fn cancel_reentrancy_step(&mut self, proposal_id: u64) {
+            self.vaults
+                .get_mut(&self.controller_badge_address)
+                .unwrap()
+                .as_fungible()
+                .authorize_with_amount(dec!("0.75"), || {
+                    self.reentrancy.cancel_step(proposal_id);
+                });
+        }
+
+        /// Records an executed proposal of a proposer, which discounts the proposal fee of the proposer's future proposals.
+        fn record_executed_proposal(&mut self, proposer: ComponentAddress) {
+            let executed: u64 = self
+                .proposer_records
+                .get(&proposer)
+                .map_or(0, |count| *count);
+            self.proposer_records.insert(proposer, executed + 1);
+        }
+
+        /// Gets the account address of a proposer from a proof of its account owner badge.
+        fn proposer_account(proposer_proof: Proof) -> ComponentAddress {
+            let checked_proof = proposer_proof
+                .check_with_message(ACCOUNT_OWNER_BADGE, "Invalid account proof supplied!");
+            match checked_proof.as_non_fungible().non_fungible_local_id() {
+                NonFungibleLocalId::Bytes(bytes) => ComponentAddress::try_from(bytes.value())
+                    .expect("Invalid account proof supplied!"),
+                _ => panic!("Invalid account proof supplied!"),
+            }
+        }
     }
 }
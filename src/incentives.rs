@@ -35,6 +35,9 @@ This NFT staking ID approach has some disadvantages over simple OneResourcePool
 
 use scrypto::prelude::*;
 
+/// Maximum reward multiplier of a bootstrap bonus, bounding the extra rewards early stakers can earn.
+const MAX_BOOTSTRAP_BONUS_MULTIPLIER: Decimal = dec!(3);
+
 /// NFT receipt structure, minted when an unstake is requested, redeemable after a set delay.
 #[derive(ScryptoSbor, NonFungibleData)]
 pub struct UnstakeReceipt {
@@ -46,13 +49,13 @@ pub struct UnstakeReceipt {
     pub redemption_time: Instant,
 }
 
-/// Staking ID structure, holding staked and locked amounts and date until which they are locked. Also stores the next period to claim rewards (updated after a user has claimed them).
+/// Staking ID structure, holding staked and locked amounts and date until which they are locked. Also stores the next period to claim rewards per staked token (updated after a user has claimed them).
 #[derive(ScryptoSbor, NonFungibleData)]
 pub struct IncentivesId {
     #[mutable]
     pub resources: HashMap<ResourceAddress, Resource>,
     #[mutable]
-    pub next_period: i64,
+    pub next_period: HashMap<ResourceAddress, i64>,
 }
 
 /// Lock structure, holding the information about locking options of a token.
@@ -64,15 +67,34 @@ pub struct Lock {
     pub reward_coefficient: Decimal,
 }
 
+/// Bootstrap bonus structure, holding the reward multiplier for early stakers of a newly added stakable.
+/// Stakers qualify by staking at least `min_stake` within `window_days` after it was added, while fewer than `max_stakers` have qualified (0 for no limit).
+/// The bonus is only earned during the window, on the stake held since qualifying, and is paid from the separately funded bonus vault.
+#[derive(ScryptoSbor, Clone)]
+pub struct BootstrapBonus {
+    pub multiplier: Decimal,
+    pub max_stakers: u64,
+    pub window_days: i64,
+    pub min_stake: Decimal,
+}
+
 /// Resource structure, holding information about a staked token within a staking ID.
+/// Tokens still in their warm-up (`warming_amount`) don't earn rewards for periods before `warm_from_period`.
+/// Early stakers of a stakable with a bootstrap bonus earn its multiplier on their `early_stake` (their first stake, lowered by unstaking), from `early_from_period` until the end of the bootstrap window.
 #[derive(ScryptoSbor, Clone)]
 pub struct Resource {
     pub amount_staked: Decimal,
     pub locked_until: Option<Instant>,
     pub voting_until: Option<Instant>,
+    pub warming_amount: Decimal,
+    pub warm_from_period: i64,
+    pub early_stake: Decimal,
+    pub early_from_period: i64,
 }
 
 /// Stakable unit structure, used by the component to data about a stakable token.
+/// Rewards are accounted through `acc_reward_per_share`, the cumulative reward per staked token, of which the value at the start of every period is stored in `acc_rewards`.
+/// Rewards of a staking ID are multiplied by the multiplier of the highest of the `reward_tiers` (minimum stake, multiplier) its stake reaches, or 1 if it reaches none. Rewards above the regular rewards are paid from the bonus vault.
 #[derive(ScryptoSbor)]
 pub struct StakableUnit {
     pub address: ResourceAddress,
@@ -80,7 +102,16 @@ pub struct StakableUnit {
     pub vault: Vault,
     pub reward_amount: Decimal,
     pub lock: Lock,
-    pub rewards: KeyValueStore<i64, Decimal>,
+    pub acc_reward_per_share: Decimal,
+    pub acc_rewards: KeyValueStore<i64, Decimal>,
+    pub paused: bool,
+    pub warmup_days: i64,
+    pub added_at: Instant,
+    pub staker_count: u64,
+    pub bootstrap_bonus: Option<BootstrapBonus>,
+    pub early_staker_count: u64,
+    pub bootstrap_end_period: i64,
+    pub reward_tiers: Vec<(Decimal, Decimal)>,
 }
 
 /// Stake transfer receipt structure, minted when a user wants to transfer their staked tokens, redeemable by other users to add these tokens to their own staking ID.
@@ -88,10 +119,34 @@ pub struct StakableUnit {
 pub struct StakeTransferReceipt {
     pub address: ResourceAddress,
     pub amount: Decimal,
+    pub transferable_after: Instant,
+}
+
+/// Event emitted when the DAO winds down the incentive rewards.
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct RewardsWoundDownEvent {
+    pub returned_amount: Decimal,
+    pub total_distributed: Decimal,
+}
+
+/// Event emitted when the DAO drains and removes a stakable.
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct StakableRemovedEvent {
+    pub address: ResourceAddress,
+    pub recovered_amount: Decimal,
 }
 
 #[blueprint]
-#[types(i64, Decimal, HashMap<ResourceAddress, Resource>, ResourceAddress, Instant)]
+#[types(
+    i64,
+    Decimal,
+    HashMap<ResourceAddress, Resource>,
+    HashMap<ResourceAddress, i64>,
+    ResourceAddress,
+    Instant,
+    StakableUnit
+)]
+#[events(RewardsWoundDownEvent, StakableRemovedEvent)]
 mod incentives {
     enable_method_auth! {
         methods {
@@ -99,20 +154,45 @@ mod incentives {
             stake => PUBLIC;
             start_unstake => PUBLIC;
             finish_unstake => PUBLIC;
+            finish_unstake_batch => PUBLIC;
             update_id => PUBLIC;
+            update_id_for => PUBLIC;
+            claim_and_lock => PUBLIC;
+            claim_and_restake => PUBLIC;
             update_period => PUBLIC;
+            rewarded_update_period => PUBLIC;
             lock_stake => PUBLIC;
             unlock_stake => PUBLIC;
             get_remaining_rewards => PUBLIC;
+            get_total_distributed => PUBLIC;
+            get_unstake_delay => PUBLIC;
+            get_stakables => PUBLIC;
+            get_period_info => PUBLIC;
+            get_unstake_receipt_info => PUBLIC;
+            get_unstake_receipts_info => PUBLIC;
+            get_pending_rewards => PUBLIC;
             put_tokens => PUBLIC;
+            fund_bonuses => PUBLIC;
             vote => restrict_to: [OWNER];
+            migrate_stake => restrict_to: [OWNER];
             set_period_interval => restrict_to: [OWNER];
             set_max_claim_delay => restrict_to: [OWNER];
             remove_tokens => restrict_to: [OWNER];
+            wind_down_rewards => restrict_to: [OWNER];
+            freeze_rewards => restrict_to: [OWNER];
+            unfreeze_rewards => restrict_to: [OWNER];
             add_stakable => restrict_to: [OWNER];
             edit_stakable => restrict_to: [OWNER];
+            drain_and_remove_stakable => restrict_to: [OWNER];
             set_next_period_to_now => restrict_to: [OWNER];
             set_unstake_delay => restrict_to: [OWNER];
+            accelerate_unstake_receipts => restrict_to: [OWNER];
+            set_stakable_paused => restrict_to: [OWNER];
+            set_warmup_days => restrict_to: [OWNER];
+            set_reward_tiers => restrict_to: [OWNER];
+            set_transfer_receipt_delay => restrict_to: [OWNER];
+            set_max_removal_residual => restrict_to: [OWNER];
+            set_caller_reward => restrict_to: [OWNER];
         }
     }
 
@@ -133,16 +213,34 @@ mod incentives {
         pub unstake_receipt_manager: ResourceManager,
         /// counter for the unstake receipts
         pub unstake_receipt_counter: u64,
+        /// accelerations of unstake receipts, each holding the last receipt minted before it and the time from which receipts up to it can be redeemed
+        pub unstake_accelerations: Vec<(u64, Instant)>,
         /// delay after which unstaked tokens can be redeemed in days
         pub unstake_delay: i64,
+        /// delay after which stake transfer receipts can be redeemed in days
+        pub transfer_receipt_delay: i64,
         /// resource manager of the staking IDs
         pub id_manager: ResourceManager,
         /// counter for the staking IDs
         pub id_counter: u64,
         /// vault that stores staking rewards
         pub reward_vault: FungibleVault,
+        /// vault that stores the rewards paid as bonus on top of the regular rewards (bootstrap bonuses and reward tiers above 1), funded separately from the reward vault
+        pub bonus_vault: FungibleVault,
+        /// account locker holding claimed rewards that could not be deposited into the account they were claimed to
+        pub reward_locker: Global<AccountLocker>,
+        /// total amount of rewards taken from the reward vault to date
+        pub total_rewards_distributed: Decimal,
         // keyvaluestore, holding stakable units and their data
         pub stakes: HashMap<ResourceAddress, StakableUnit>,
+        /// removed stakable units, kept so outstanding unstake receipts can still be redeemed
+        pub removed_stakes: KeyValueStore<ResourceAddress, StakableUnit>,
+        /// maximum amount that can still be staked to a stakable when it is drained and removed
+        pub max_removal_residual: Decimal,
+        /// reward paid to callers of rewarded_update_period, at most once a period
+        pub caller_reward: Decimal,
+        /// moment reward accrual was frozen, none if rewards aren't frozen
+        pub rewards_frozen_at: Option<Instant>,
     }
 
     impl Incentives {
@@ -261,6 +359,15 @@ mod incentives {
 
             let stakes: HashMap<ResourceAddress, StakableUnit> = HashMap::new();
 
+            let reward_locker = Blueprint::<AccountLocker>::instantiate(
+                OwnerRole::Fixed(rule!(require(controller))),
+                rule!(require(global_caller(component_address))),
+                rule!(deny_all),
+                rule!(require(controller)),
+                rule!(deny_all),
+                None,
+            );
+
             let component = Self {
                 next_period: Clock::current_time_rounded_to_seconds()
                     .add_days(period_interval)
@@ -269,17 +376,26 @@ mod incentives {
                 current_period: 0,
                 max_claim_delay: 5,
                 unstake_delay: 7,
+                transfer_receipt_delay: 0,
                 id_manager,
                 stake_transfer_receipt_manager,
                 stake_transfer_receipt_counter: 0,
                 unstake_receipt_manager,
                 unstake_receipt_counter: 0,
+                unstake_accelerations: vec![],
                 id_counter: 0,
+                bonus_vault: FungibleVault::new(rewards.resource_address()),
                 reward_vault: FungibleVault::with_bucket(rewards.as_fungible()),
+                reward_locker,
+                total_rewards_distributed: dec!(0),
                 stakes,
+                removed_stakes: IncentivesKeyValueStore::new_with_registered_type(),
+                max_removal_residual: dec!(0),
+                caller_reward: dec!(0),
+                rewards_frozen_at: None,
             }
             .instantiate()
-            .prepare_to_globalize(OwnerRole::Fixed(rule!(require(controller))))
+            .prepare_to_globalize(OwnerRole::Updatable(rule!(require(controller))))
             .with_address(address_reservation)
             .metadata(metadata! {
                 init {
@@ -305,12 +421,18 @@ mod incentives {
         /// - none
         ///
         /// ## LOGIC
+        /// - if rewards are frozen, no period passes and nothing is recorded
         /// - the method calculates the number of extra periods that have passed since the last update, because the method might not be called exactly at the end of a period
         /// - if a period has passed, for each stakable token the rewards are calculated and recorded, reward calculation is relatively simple:
         ///    - every stakable has a total amount of reward per period
         ///    - total reward amount is divided by the total amount staked to get the reward per staked token
+        ///    - the reward per staked token is added to the cumulative reward per staked token, which is recorded for the start of the next period
         /// - the current period is incremented and the next period is set
         pub fn update_period(&mut self) {
+            if self.rewards_frozen_at.is_some() {
+                return;
+            }
+
             let extra_periods_dec: Decimal = ((Clock::current_time_rounded_to_seconds()
                 .seconds_since_unix_epoch
                 - self.next_period.seconds_since_unix_epoch)
@@ -323,13 +445,12 @@ mod incentives {
             if Clock::current_time_is_at_or_after(self.next_period, TimePrecision::Second) {
                 for (_address, stakable_unit) in self.stakes.iter_mut() {
                     if stakable_unit.amount_staked > dec!(0) {
-                        stakable_unit.rewards.insert(
-                            self.current_period,
-                            stakable_unit.reward_amount / stakable_unit.amount_staked,
-                        );
-                    } else {
-                        stakable_unit.rewards.insert(self.current_period, dec!(0));
+                        stakable_unit.acc_reward_per_share +=
+                            stakable_unit.reward_amount / stakable_unit.amount_staked;
                     }
+                    stakable_unit
+                        .acc_rewards
+                        .insert(self.current_period + 1, stakable_unit.acc_reward_per_share);
                 }
 
                 self.current_period += 1;
@@ -340,6 +461,37 @@ mod incentives {
             }
         }
 
+        /// This method updates the component's period and rewards the caller for doing so.
+        ///
+        /// ## INPUT
+        /// - none
+        ///
+        /// ## OUTPUT
+        /// - the caller reward, which is empty if no reward is due
+        ///
+        /// ## LOGIC
+        /// - the method updates the period
+        /// - only if this call started a new period, the caller reward is taken from the reward vault
+        /// - the caller reward is capped at 0.1% of the remaining rewards
+        pub fn rewarded_update_period(&mut self) -> FungibleBucket {
+            let period_before: i64 = self.current_period;
+            self.update_period();
+
+            if self.caller_reward > dec!(0) && self.current_period > period_before {
+                let reward: Decimal = self
+                    .caller_reward
+                    .min(self.reward_vault.amount() * dec!("0.001"));
+                let caller_reward: FungibleBucket = self.reward_vault.take_advanced(
+                    reward,
+                    WithdrawStrategy::Rounded(RoundingMode::ToNegativeInfinity),
+                );
+                self.total_rewards_distributed += caller_reward.amount();
+                caller_reward
+            } else {
+                self.reward_vault.take(dec!(0))
+            }
+        }
+
         /// This method requests an unstake of staked tokens
         ///
         /// ## INPUT
@@ -357,6 +509,7 @@ mod incentives {
         /// - the method checks the staked amount
         /// - the method checks if the staked tokens are locked (then unstaking is not possible)
         /// - if not, tokens are removed from staking ID stake
+        /// - if the stakable has been removed, its residual stake was recovered on removal, so the unstake receipt only covers what the removed stakable still holds (transferring is not possible)
         /// - if the user wants to transfer the tokens, a transfer receipt is minted
         /// - if the user wants to unstake the tokens, an unstake receipt is minted
         pub fn start_unstake(
@@ -404,8 +557,23 @@ mod incentives {
             } else {
                 resource.amount_staked -= amount;
             }
+            resource.warming_amount = resource.warming_amount.min(resource.amount_staked);
+            resource.early_stake = resource.early_stake.min(resource.amount_staked);
 
-            self.stakes.get_mut(&address).unwrap().amount_staked -= unstake_amount;
+            if let Some(stakable_unit) = self.stakes.get_mut(&address) {
+                stakable_unit.amount_staked -= unstake_amount;
+            } else {
+                assert!(
+                    !stake_transfer,
+                    "Stake of a removed stakable can't be transferred."
+                );
+                let mut removed_unit = self
+                    .removed_stakes
+                    .get_mut(&address)
+                    .expect("Stakable not found.");
+                unstake_amount = unstake_amount.min(removed_unit.amount_staked);
+                removed_unit.amount_staked -= unstake_amount;
+            }
 
             resource_map.insert(address, resource);
 
@@ -416,6 +584,9 @@ mod incentives {
                 let stake_transfer_receipt = StakeTransferReceipt {
                     address,
                     amount: unstake_amount,
+                    transferable_after: Clock::current_time_rounded_to_seconds()
+                        .add_days(self.transfer_receipt_delay)
+                        .unwrap(),
                 };
                 self.stake_transfer_receipt_counter += 1;
                 self.stake_transfer_receipt_manager.mint_non_fungible(
@@ -448,7 +619,7 @@ mod incentives {
         ///
         /// ## LOGIC
         /// - the method checks the receipt
-        /// - the method checks the redemption time
+        /// - the method checks the redemption time, which is moved up if the receipt has been accelerated
         /// - the method burns the receipt
         /// - the method returns the unstaked tokens
         pub fn finish_unstake(&mut self, receipt: Bucket) -> Bucket {
@@ -458,25 +629,101 @@ mod incentives {
                 .as_non_fungible()
                 .non_fungible::<UnstakeReceipt>()
                 .data();
+            let redemption_time: Instant = self.redemption_time(
+                &receipt.as_non_fungible().non_fungible_local_id(),
+                receipt_data.redemption_time,
+            );
 
             assert!(
-                Clock::current_time_is_at_or_after(
-                    receipt_data.redemption_time,
-                    TimePrecision::Second
-                ),
+                Clock::current_time_is_at_or_after(redemption_time, TimePrecision::Second),
                 "You cannot unstake tokens before the redemption time."
             );
 
             receipt.burn();
 
-            self.stakes
-                .get_mut(&receipt_data.address)
-                .unwrap()
-                .vault
-                .take_advanced(
+            match self.stakes.get_mut(&receipt_data.address) {
+                Some(stakable_unit) => stakable_unit.vault.take_advanced(
                     receipt_data.amount,
                     WithdrawStrategy::Rounded(RoundingMode::ToNegativeInfinity),
-                )
+                ),
+                None => self
+                    .removed_stakes
+                    .get_mut(&receipt_data.address)
+                    .unwrap()
+                    .vault
+                    .take_advanced(
+                        receipt_data.amount,
+                        WithdrawStrategy::Rounded(RoundingMode::ToNegativeInfinity),
+                    ),
+            }
+        }
+
+        /// This method finishes multiple unstakes of the same stakable token at once, redeeming the unstaked tokens
+        ///
+        /// ## INPUT
+        /// - `receipts`: the unstake receipts
+        ///
+        /// ## OUTPUT
+        /// - the unstaked tokens of all receipts
+        ///
+        /// ## LOGIC
+        /// - the method checks whether any receipts are supplied
+        /// - the method redeems each receipt using the finish_unstake method, so the whole call fails if any receipt isn't redeemable yet
+        /// - the method checks whether all receipts unstake the same token
+        /// - the method returns the combined unstaked tokens
+        pub fn finish_unstake_batch(&mut self, receipts: Vec<Bucket>) -> Bucket {
+            let mut receipts = receipts.into_iter();
+            let mut unstaked_tokens: Bucket =
+                self.finish_unstake(receipts.next().expect("No unstake receipts supplied."));
+
+            for receipt in receipts {
+                let tokens: Bucket = self.finish_unstake(receipt);
+                assert!(
+                    tokens.resource_address() == unstaked_tokens.resource_address(),
+                    "All unstake receipts must be for the same stakable token."
+                );
+                unstaked_tokens.put(tokens);
+            }
+
+            unstaked_tokens
+        }
+
+        /// This method takes staked tokens out of a staking ID without unstake delay, so the DAO can migrate them to the Staking component
+        ///
+        /// ## INPUT
+        /// - `id_proof`: the proof of the staking ID
+        /// - `address`: the address of the stakable token
+        /// - `amount`: the amount of tokens to take out
+        ///
+        /// ## OUTPUT
+        /// - the unstaked tokens
+        ///
+        /// ## LOGIC
+        /// - the method checks whether the stakable is live, as the residual stake of a removed stakable has already been recovered
+        /// - the method requests an unstake using the start_unstake method, so the staked tokens can't be locked or voting
+        /// - the method burns the unstake receipt right away, skipping the unstake delay
+        /// - the method returns the unstaked tokens
+        pub fn migrate_stake(
+            &mut self,
+            id_proof: NonFungibleProof,
+            address: ResourceAddress,
+            amount: Decimal,
+        ) -> Bucket {
+            assert!(
+                self.stakes.get(&address).is_some(),
+                "Stakable has been removed, unstake its stake instead."
+            );
+            let receipt: Bucket = self.start_unstake(id_proof, address, amount, false);
+            let receipt_data = receipt
+                .as_non_fungible()
+                .non_fungible::<UnstakeReceipt>()
+                .data();
+
+            receipt.burn();
+            self.stakes.get_mut(&address).unwrap().vault.take_advanced(
+                receipt_data.amount,
+                WithdrawStrategy::Rounded(RoundingMode::ToNegativeInfinity),
+            )
         }
 
         /// This method creates a new staking ID
@@ -496,7 +743,7 @@ mod incentives {
 
             let id_data = IncentivesId {
                 resources: HashMap::new(),
-                next_period: self.current_period + 1,
+                next_period: HashMap::new(),
             };
 
             let id: Bucket = self
@@ -511,26 +758,27 @@ mod incentives {
         /// ## INPUT
         /// - `stake_bucket`: bucket containing either the tokens to stake or a stake transfer receipt
         /// - `id_proof`: the proof of the staking ID
+        /// - `auto_claim`: whether to claim unclaimed rewards on the staking ID before staking, instead of failing
         ///
         /// ## OUTPUT
         /// - an optional staking ID (if none was provided)
+        /// - a potential lock reward
+        /// - the claimed rewards, if any were claimed
         ///
         /// ## LOGIC
         /// - the method checks whether a staking ID is supplied, if not, it creates one
         /// - the method checks the staking ID
-        /// - the method checks if latest rewards have been claimed, if not, the method fails
-        /// - the method checks whether it received tokens or a transfer receipt
-        /// - the method adds tokens to an internal vault, or burns the transfer receipt
-        /// - if the staked tokens are locked, the method calculates the lock reward and returns it
-        /// - the method updates the staking ID
+        /// - if auto_claim is true and the staking ID has unclaimed rewards, the method updates the component period and claims them
+        /// - the method passes the id and stake_bucket to the stake_to_id method, which stakes the tokens and returns a potential lock reward
         pub fn stake(
             &mut self,
             stake_bucket: Bucket,
             id_proof: Option<Proof>,
-        ) -> (Option<Bucket>, Option<Bucket>) {
+            auto_claim: bool,
+        ) -> (Option<Bucket>, Option<Bucket>, Option<FungibleBucket>) {
             let id: NonFungibleLocalId;
             let mut id_bucket: Option<Bucket> = None;
-            let mut lock_reward_bucket: Option<Bucket> = None;
+            let mut claimed_rewards: Option<FungibleBucket> = None;
 
             if let Some(id_proof) = id_proof {
                 let id_proof = id_proof.check_with_message(
@@ -542,6 +790,24 @@ mod incentives {
                     .non_fungible::<IncentivesId>()
                     .local_id()
                     .clone();
+
+                if auto_claim {
+                    self.update_period();
+                    let id_data: IncentivesId = self.id_manager.get_non_fungible_data(&id);
+                    let has_unclaimed: bool =
+                        id_data.resources.iter().any(|(address, resource)| {
+                            resource.amount_staked > dec!(0)
+                                && id_data
+                                    .next_period
+                                    .get(address)
+                                    .map_or(false, |next_period| {
+                                        *next_period <= self.current_period
+                                    })
+                        });
+                    if has_unclaimed {
+                        claimed_rewards = Some(self.claim_rewards(&id, None));
+                    }
+                }
             } else {
                 let new_id: Bucket = self.create_id();
                 id = new_id
@@ -552,133 +818,146 @@ mod incentives {
                 id_bucket = Some(new_id);
             }
 
-            let id_data: IncentivesId = self.id_manager.get_non_fungible_data(&id);
-            assert!(
-                id_data.next_period > self.current_period,
-                "Please claim unclaimed rewards on your ID before staking."
-            );
-
-            let stake_amount: Decimal;
-            let address: ResourceAddress;
-
-            if stake_bucket.resource_address() == self.stake_transfer_receipt_manager.address() {
-                (stake_amount, address) =
-                    self.stake_transfer_receipt(stake_bucket.as_non_fungible());
-            } else {
-                (stake_amount, address) = self.stake_tokens(stake_bucket);
-            }
-
-            let mut resource_map = id_data.resources.clone();
-            resource_map
-                .entry(address)
-                .and_modify(|resource| {
-                    resource.amount_staked += stake_amount;
-                })
-                .or_insert(Resource {
-                    amount_staked: stake_amount,
-                    locked_until: None,
-                    voting_until: None,
-                });
+            let lock_reward_bucket: Option<Bucket> = self.stake_to_id(stake_bucket, &id);
 
-            if let Some(locked_until) = resource_map
-                .get(&address)
-                .expect("Stakable not found in staking ID.")
-                .locked_until
-            {
-                if locked_until.compare(
-                    Clock::current_time_rounded_to_seconds(),
-                    TimeComparisonOperator::Gt,
-                ) {
-                    let stakable = self.stakes.get(&address).unwrap();
-                    let seconds_to_unlock = locked_until.seconds_since_unix_epoch
-                        - Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
-                    let seconds_to_unlock_dec = Decimal::from(seconds_to_unlock);
-                    let full_days_to_unlock = (seconds_to_unlock_dec / dec!(86400))
-                        .checked_floor()
-                        .unwrap();
-                    let whole_days_to_unlock: i64 =
-                        i64::try_from(full_days_to_unlock.0 / Decimal::ONE.0).unwrap();
-                    lock_reward_bucket = Some(
-                        self.reward_vault
-                            .take(
-                                stakable.lock.reward_coefficient * ((stakable
-                                    .lock
-                                    .payment
-                                    .checked_powi(whole_days_to_unlock)
-                                    .unwrap()
-                                    * stake_amount)
-                                    - stake_amount),
-                            )
-                            .into(),
-                    );
-                }
-            }
+            (id_bucket, lock_reward_bucket, claimed_rewards)
+        }
 
-            self.id_manager
-                .update_non_fungible_data(&id, "resources", resource_map);
+        /// This method claims rewards from a staking ID
+        ///
+        /// ## INPUT
+        /// - `id_proof`: the proof of the staking ID
+        /// - `claim_to`: optional account to deposit the claimed rewards into, instead of returning them
+        ///
+        /// ## OUTPUT
+        /// - the claimed rewards, empty if they were deposited into an account
+        ///
+        /// ## LOGIC
+        /// - the method updates the component period if necessary
+        /// - the method checks the staking ID
+        /// - the method checks amount of unclaimed periods per staked token
+        /// - the method iterates over all staked tokens and calculates the rewards
+        /// - the method updates the staking ID to the next period for all staked tokens
+        /// - the method deposits the claimed rewards into the account if one is supplied, or stores them in the reward locker if the account doesn't accept them, otherwise returns them
+        pub fn update_id(
+            &mut self,
+            id_proof: NonFungibleProof,
+            claim_to: Option<Global<Account>>,
+        ) -> FungibleBucket {
+            self.update_period();
+            let id_proof = id_proof
+                .check_with_message(self.id_manager.address(), "Invalid IncentivesId supplied!");
+            let id = id_proof.non_fungible::<IncentivesId>().local_id().clone();
 
-            self.stakes.get_mut(&address).unwrap().amount_staked += stake_amount;
+            let rewards: FungibleBucket = self.claim_rewards(&id, None);
+            self.deliver_rewards(rewards, claim_to)
+        }
 
-            self.id_manager
-                .update_non_fungible_data(&id, "next_period", self.current_period + 1);
+        /// This method claims rewards from a staking ID for a single staked token
+        ///
+        /// ## INPUT
+        /// - `id_proof`: the proof of the staking ID
+        /// - `address`: the address of the staked token to claim rewards for
+        /// - `claim_to`: optional account to deposit the claimed rewards into, instead of returning them
+        ///
+        /// ## OUTPUT
+        /// - the claimed rewards, empty if they were deposited into an account
+        ///
+        /// ## LOGIC
+        /// - the method updates the component period if necessary
+        /// - the method checks the staking ID
+        /// - the method checks whether the token is staked to the staking ID
+        /// - the method checks amount of unclaimed periods for the token
+        /// - the method calculates the rewards for the token
+        /// - the method updates the staking ID to the next period for the token, leaving other tokens accruing
+        /// - the method deposits the claimed rewards into the account if one is supplied, or stores them in the reward locker if the account doesn't accept them, otherwise returns them
+        pub fn update_id_for(
+            &mut self,
+            id_proof: NonFungibleProof,
+            address: ResourceAddress,
+            claim_to: Option<Global<Account>>,
+        ) -> FungibleBucket {
+            self.update_period();
+            let id_proof = id_proof
+                .check_with_message(self.id_manager.address(), "Invalid IncentivesId supplied!");
+            let id = id_proof.non_fungible::<IncentivesId>().local_id().clone();
 
-            (id_bucket, lock_reward_bucket)
+            let rewards: FungibleBucket = self.claim_rewards(&id, Some(address));
+            self.deliver_rewards(rewards, claim_to)
         }
 
-        /// This method claims rewards from a staking ID
+        /// This method claims rewards from a staking ID and immediately stakes and locks them to the same ID
         ///
         /// ## INPUT
         /// - `id_proof`: the proof of the staking ID
+        /// - `days_to_lock`: the duration for which the staked rewards are locked in days
         ///
         /// ## OUTPUT
-        /// - the claimed rewards
+        /// - rewards for locking the tokens
         ///
         /// ## LOGIC
         /// - the method updates the component period if necessary
         /// - the method checks the staking ID
-        /// - the method checks amount of unclaimed periods
-        /// - the method iterates over all staked tokens and calculates the rewards
-        /// - the method updates the staking ID to the next period
-        /// - the method returns the claimed rewards
-        pub fn update_id(&mut self, id_proof: NonFungibleProof) -> FungibleBucket {
+        /// - the method checks whether the reward token is stakable
+        /// - the method claims the rewards of the staking ID
+        /// - the method stakes the claimed rewards to the staking ID
+        /// - the method locks the staked reward token position of the staking ID
+        /// - the method returns the rewards for locking the tokens (including lock rewards for staking into an already locked position)
+        pub fn claim_and_lock(
+            &mut self,
+            id_proof: NonFungibleProof,
+            days_to_lock: i64,
+        ) -> FungibleBucket {
             self.update_period();
             let id_proof = id_proof
                 .check_with_message(self.id_manager.address(), "Invalid IncentivesId supplied!");
             let id = id_proof.non_fungible::<IncentivesId>().local_id().clone();
-            let id_data: IncentivesId = self.id_manager.get_non_fungible_data(&id);
+            let address: ResourceAddress = self.reward_vault.resource_address();
+
+            assert!(
+                self.stakes.get(&address).is_some(),
+                "Reward token is not stakable, so rewards cannot be locked."
+            );
 
-            let mut claimed_weeks: i64 = self.current_period - id_data.next_period + 1;
-            if claimed_weeks > self.max_claim_delay {
-                claimed_weeks = self.max_claim_delay;
+            let rewards: FungibleBucket = self.claim_rewards(&id, None);
+            let staking_lock_reward: Option<Bucket> = self.stake_to_id(rewards.into(), &id);
+            let mut lock_reward: FungibleBucket = self.lock_id(address, &id, days_to_lock);
+
+            if let Some(staking_lock_reward) = staking_lock_reward {
+                lock_reward.put(staking_lock_reward.as_fungible());
             }
 
-            assert!(claimed_weeks > 0, "Wait longer to claim your rewards.");
+            lock_reward
+        }
 
-            let mut staking_reward: Decimal = dec!(0);
+        /// This method claims rewards from a staking ID and immediately stakes them to the same ID
+        ///
+        /// ## INPUT
+        /// - `id_proof`: the proof of the staking ID
+        ///
+        /// ## OUTPUT
+        /// - a potential lock reward, if the reward token position of the staking ID is locked
+        ///
+        /// ## LOGIC
+        /// - the method updates the component period if necessary
+        /// - the method checks the staking ID
+        /// - the method checks whether the reward token is stakable
+        /// - the method claims the rewards of the staking ID, which moves it to the next period so rewards can't be claimed twice
+        /// - the method stakes the claimed rewards to the staking ID and returns a potential lock reward
+        pub fn claim_and_restake(&mut self, id_proof: NonFungibleProof) -> Option<Bucket> {
+            self.update_period();
+            let id_proof = id_proof
+                .check_with_message(self.id_manager.address(), "Invalid IncentivesId supplied!");
+            let id = id_proof.non_fungible::<IncentivesId>().local_id().clone();
+            let address: ResourceAddress = self.reward_vault.resource_address();
 
-            self.id_manager
-                .update_non_fungible_data(&id, "next_period", self.current_period + 1);
-
-            for (address, stakable_unit) in self.stakes.iter() {
-                for week in 1..(claimed_weeks + 1) {
-                    if stakable_unit
-                        .rewards
-                        .get(&(self.current_period - week))
-                        .is_some()
-                    {
-                        staking_reward += *stakable_unit
-                            .rewards
-                            .get(&(self.current_period - week))
-                            .unwrap()
-                            * id_data
-                                .resources
-                                .get(address)
-                                .map_or(dec!(0), |resource| resource.amount_staked);
-                    }
-                }
-            }
+            assert!(
+                self.stakes.get(&address).is_some(),
+                "Reward token is not stakable, so rewards cannot be restaked."
+            );
 
-            self.reward_vault.take(staking_reward)
+            let rewards: FungibleBucket = self.claim_rewards(&id, None);
+            self.stake_to_id(rewards.into(), &id)
         }
 
         /// This method locks staked tokens for a certain duration and gives rewards for locking them
@@ -707,83 +986,38 @@ mod incentives {
             let id_proof = id_proof
                 .check_with_message(self.id_manager.address(), "Invalid IncentivesId supplied!");
             let id = id_proof.non_fungible::<IncentivesId>().local_id().clone();
-            let stakable = self.stakes.get(&address).unwrap();
-
-            let id_data: IncentivesId = self.id_manager.get_non_fungible_data(&id);
-            let mut resource_map = id_data.resources.clone();
-            let mut resource = resource_map
-                .get(&address)
-                .expect("Stakable not found in staking ID.")
-                .clone();
-
-            let amount_staked = resource.amount_staked;
-            let new_lock: Instant;
-            let max_lock: Instant = Clock::current_time_rounded_to_seconds()
-                .add_days(stakable.lock.max_duration)
-                .unwrap();
-
-            if let Some(locked_until) = resource.locked_until {
-                if locked_until.compare(
-                    Clock::current_time_rounded_to_seconds(),
-                    TimeComparisonOperator::Gt,
-                ) {
-                    new_lock = locked_until.add_days(days_to_lock).unwrap();
-                } else {
-                    new_lock = Clock::current_time_rounded_to_seconds()
-                        .add_days(days_to_lock)
-                        .unwrap();
-                }
-            } else {
-                new_lock = Clock::current_time_rounded_to_seconds()
-                    .add_days(days_to_lock)
-                    .unwrap();
-            }
-
-            assert!(
-                new_lock.compare(max_lock, TimeComparisonOperator::Lte),
-                "New lock duration exceeds maximum lock duration."
-            );
-
-            resource.locked_until = Some(new_lock);
-            resource_map.insert(address, resource);
-
-            self.id_manager
-                .update_non_fungible_data(&id, "resources", resource_map);
-
-            self.reward_vault.take(
-                stakable.lock.reward_coefficient * ((stakable.lock.payment.checked_powi(days_to_lock).unwrap() * amount_staked)
-                    - amount_staked),
-            )
-        }
-
-        /// This method unlocks locked (and, naturally, staked) tokens for a certain duration against payment that's (probably) worth more than the locking reward
-        ///
-        /// ## INPUT
-        /// - `address`: the address of the stakable token
-        /// - `id_proof`: the proof of the staking ID
-        /// - `payment`: the payment for unlocking the tokens
-        /// - `days_to_unlock`: the duration that the lock is shortened by in days
-        ///
-        /// ## OUTPUT
-        /// - leftover payment
-        ///
-        /// ## LOGIC
-        /// - the method checks the staking ID
-        /// - the method calculates the unlock fee
-        /// - the method checks whether the payment is enough, takes it, and puts it into the reward vault
-        /// - the method updates the locking time of the tokens
-        /// - the method returns leftover unlock fee
-
-        pub fn unlock_stake(
-            &mut self,
-            address: ResourceAddress,
-            id_proof: NonFungibleProof,
-            mut payment: Bucket,
-            days_to_unlock: i64,
-        ) -> Bucket {
-            let id_proof = id_proof
-                .check_with_message(self.id_manager.address(), "Invalid IncentivesId supplied!");
-            let id = id_proof.non_fungible::<IncentivesId>().local_id().clone();
+
+            self.lock_id(address, &id, days_to_lock)
+        }
+
+        /// This method unlocks locked (and, naturally, staked) tokens for a certain duration against payment that's (probably) worth more than the locking reward
+        ///
+        /// ## INPUT
+        /// - `address`: the address of the stakable token
+        /// - `id_proof`: the proof of the staking ID
+        /// - `payment`: the payment for unlocking the tokens
+        /// - `days_to_unlock`: the duration that the lock is shortened by in days
+        ///
+        /// ## OUTPUT
+        /// - leftover payment
+        ///
+        /// ## LOGIC
+        /// - the method checks the staking ID
+        /// - the method calculates the unlock fee
+        /// - the method checks whether the payment is enough, takes it, and puts it into the reward vault
+        /// - the method updates the locking time of the tokens
+        /// - the method returns leftover unlock fee
+
+        pub fn unlock_stake(
+            &mut self,
+            address: ResourceAddress,
+            id_proof: NonFungibleProof,
+            mut payment: Bucket,
+            days_to_unlock: i64,
+        ) -> Bucket {
+            let id_proof = id_proof
+                .check_with_message(self.id_manager.address(), "Invalid IncentivesId supplied!");
+            let id = id_proof.non_fungible::<IncentivesId>().local_id().clone();
             let stakable = self.stakes.get(&address).unwrap();
 
             let id_data: IncentivesId = self.id_manager.get_non_fungible_data(&id);
@@ -843,11 +1077,107 @@ mod incentives {
             self.reward_vault.put(bucket.as_fungible());
         }
 
+        /// Method puts tokens into the bonus vault, from which bootstrap bonuses and rewards of reward tiers above 1 are paid
+        pub fn fund_bonuses(&mut self, bucket: Bucket) {
+            self.bonus_vault.put(bucket.as_fungible());
+        }
+
+        /// This method freezes reward accrual, for instance while migrating to a new incentives component
+        ///
+        /// ## INPUT
+        /// - none
+        ///
+        /// ## OUTPUT
+        /// - none
+        ///
+        /// ## LOGIC
+        /// - the method updates the period, recording the rewards of any period that has passed
+        /// - the method records the moment of freezing, after which no new period starts
+        pub fn freeze_rewards(&mut self) {
+            assert!(
+                self.rewards_frozen_at.is_none(),
+                "Rewards are already frozen."
+            );
+            self.update_period();
+            self.rewards_frozen_at = Some(Clock::current_time_rounded_to_seconds());
+        }
+
+        /// This method unfreezes reward accrual
+        ///
+        /// ## INPUT
+        /// - none
+        ///
+        /// ## OUTPUT
+        /// - none
+        ///
+        /// ## LOGIC
+        /// - the method moves the start of the next period forward by the time rewards were frozen, so the frozen window doesn't count towards a period
+        /// - the method clears the moment of freezing, after which periods pass again
+        pub fn unfreeze_rewards(&mut self) {
+            let frozen_at: Instant = self
+                .rewards_frozen_at
+                .take()
+                .expect("Rewards are not frozen.");
+            let seconds_frozen: i64 = Clock::current_time_rounded_to_seconds()
+                .seconds_since_unix_epoch
+                - frozen_at.seconds_since_unix_epoch;
+            self.next_period = self.next_period.add_seconds(seconds_frozen).unwrap();
+        }
+
         /// Method removes tokens from the reward vault
         pub fn remove_tokens(&mut self, amount: Decimal) -> Bucket {
             self.reward_vault.take(amount).into()
         }
 
+        /// This method winds down the incentive rewards, ending all emissions
+        ///
+        /// ## INPUT
+        /// - none
+        ///
+        /// ## OUTPUT
+        /// - the remaining rewards, not claimable by stakers
+        ///
+        /// ## LOGIC
+        /// - the method updates the period, recording the rewards of a passed period
+        /// - the method sets the reward amount and lock reward coefficient of all stakables, and the caller reward, to zero, so no more rewards are taken from the reward vault
+        /// - the method keeps the rewards stakers can still claim in the reward vault, being the rewards of all claimable periods for the currently staked tokens (bonuses are paid from their own vault)
+        /// - the method takes all other rewards from the reward vault and returns them
+        /// - the method emits a RewardsWoundDownEvent
+        pub fn wind_down_rewards(&mut self) -> Bucket {
+            self.update_period();
+
+            let first_period: i64 = self.current_period - self.max_claim_delay;
+            let mut claimable_rewards: Decimal = dec!(0);
+
+            self.caller_reward = dec!(0);
+
+            for (_address, stakable_unit) in self.stakes.iter_mut() {
+                stakable_unit.reward_amount = dec!(0);
+                stakable_unit.lock.reward_coefficient = dec!(0);
+
+                claimable_rewards += (stakable_unit.acc_reward_per_share
+                    - Self::acc_reward_at(stakable_unit, first_period))
+                    * stakable_unit.amount_staked;
+            }
+
+            let remaining_amount: Decimal =
+                (self.reward_vault.amount() - claimable_rewards).max(dec!(0));
+            let remaining_rewards: Bucket = self
+                .reward_vault
+                .take_advanced(
+                    remaining_amount,
+                    WithdrawStrategy::Rounded(RoundingMode::ToNegativeInfinity),
+                )
+                .into();
+
+            Runtime::emit_event(RewardsWoundDownEvent {
+                returned_amount: remaining_rewards.amount(),
+                total_distributed: self.total_rewards_distributed,
+            });
+
+            remaining_rewards
+        }
+
         /// Method sets the max claim delay, the maximum amount of periods a user can wait before claiming rewards
         pub fn set_max_claim_delay(&mut self, new_delay: i64) {
             self.max_claim_delay = new_delay;
@@ -863,7 +1193,34 @@ mod incentives {
             self.unstake_delay = new_delay;
         }
 
-        /// Method adds a stakable resource
+        /// Method accelerates the unstake receipts minted before this call, making them redeemable from the new redemption time at the latest
+        pub fn accelerate_unstake_receipts(&mut self, new_redemption_time: Instant) {
+            self.unstake_accelerations
+                .push((self.unstake_receipt_counter, new_redemption_time));
+        }
+
+        /// Method sets the reward paid to callers of rewarded_update_period
+        pub fn set_caller_reward(&mut self, caller_reward: Decimal) {
+            assert!(caller_reward >= dec!(0), "Caller reward can't be negative.");
+            self.caller_reward = caller_reward;
+        }
+
+        /// Method sets the transfer receipt delay, the amount of days before a stake transfer receipt can be redeemed
+        pub fn set_transfer_receipt_delay(&mut self, new_delay: i64) {
+            assert!(new_delay >= 0, "Transfer receipt delay can't be negative.");
+            self.transfer_receipt_delay = new_delay;
+        }
+
+        /// Method sets the maximum amount that can still be staked to a stakable when it is drained and removed
+        pub fn set_max_removal_residual(&mut self, max_residual: Decimal) {
+            assert!(
+                max_residual >= dec!(0),
+                "Maximum removal residual can't be negative."
+            );
+            self.max_removal_residual = max_residual;
+        }
+
+        /// Method adds a stakable resource, optionally with a bootstrap bonus rewarding its early stakers
         pub fn add_stakable(
             &mut self,
             address: ResourceAddress,
@@ -872,7 +1229,33 @@ mod incentives {
             max_duration: i64,
             unlock_payment: Decimal,
             reward_coefficient: Decimal,
+            bootstrap_bonus: Option<BootstrapBonus>,
         ) {
+            assert!(
+                self.stakes.get(&address).is_none(),
+                "Stakable already exists, use edit_stakable instead."
+            );
+            assert!(
+                self.removed_stakes.get(&address).is_none(),
+                "Stakable has been removed and can't be added again."
+            );
+            let mut bootstrap_end_period: i64 = self.current_period;
+            if let Some(bonus) = &bootstrap_bonus {
+                assert!(
+                    bonus.multiplier >= dec!(1)
+                        && bonus.multiplier <= MAX_BOOTSTRAP_BONUS_MULTIPLIER,
+                    "Bootstrap bonus multiplier must be between 1 and {}.",
+                    MAX_BOOTSTRAP_BONUS_MULTIPLIER
+                );
+                assert!(bonus.window_days > 0, "Bootstrap window must be positive.");
+                assert!(
+                    bonus.min_stake >= dec!(0),
+                    "Bootstrap bonus minimum stake can't be negative."
+                );
+                bootstrap_end_period +=
+                    (bonus.window_days + self.period_interval - 1) / self.period_interval;
+            }
+
             let lock: Lock = Lock {
                 payment,
                 max_duration,
@@ -880,6 +1263,10 @@ mod incentives {
                 reward_coefficient,
             };
 
+            let acc_rewards: KeyValueStore<i64, Decimal> =
+                IncentivesKeyValueStore::new_with_registered_type();
+            acc_rewards.insert(self.current_period, dec!(0));
+
             self.stakes.insert(
                 address,
                 StakableUnit {
@@ -888,7 +1275,16 @@ mod incentives {
                     vault: Vault::new(address),
                     reward_amount,
                     lock,
-                    rewards: IncentivesKeyValueStore::new_with_registered_type(),
+                    acc_reward_per_share: dec!(0),
+                    acc_rewards,
+                    paused: false,
+                    warmup_days: 0,
+                    added_at: Clock::current_time_rounded_to_seconds(),
+                    staker_count: 0,
+                    bootstrap_bonus,
+                    early_staker_count: 0,
+                    bootstrap_end_period,
+                    reward_tiers: vec![],
                 },
             );
         }
@@ -914,6 +1310,95 @@ mod incentives {
             self.stakes.get_mut(&address).unwrap().lock = lock;
         }
 
+        /// This method removes a stakable resource that only has a residual amount staked left, such as rounding dust
+        ///
+        /// ## INPUT
+        /// - `address`: the address of the stakable token
+        ///
+        /// ## OUTPUT
+        /// - the recovered residual stake
+        ///
+        /// ## LOGIC
+        /// - the method checks whether the amount staked doesn't exceed the maximum removal residual, so live stake can't be swept
+        /// - the method updates the period, recording the rewards of a passed period
+        /// - the method removes the stakable unit, so it can't be staked to or earn rewards anymore
+        /// - the method takes the residual amount staked from the stakable's vault, leaving the tokens of outstanding unstake receipts
+        /// - the method stores the stakable unit with its zeroed accounting, so outstanding unstake receipts can still be redeemed, and positions holding residual stake can still be unstaked
+        /// - the method emits a StakableRemovedEvent
+        pub fn drain_and_remove_stakable(&mut self, address: ResourceAddress) -> Bucket {
+            assert!(
+                self.stakes
+                    .get(&address)
+                    .expect("Stakable not found.")
+                    .amount_staked
+                    <= self.max_removal_residual,
+                "Stakable has more than the maximum removal residual staked."
+            );
+            self.update_period();
+
+            let mut stakable_unit: StakableUnit = self.stakes.remove(&address).unwrap();
+
+            let residual_amount: Decimal = stakable_unit
+                .amount_staked
+                .min(stakable_unit.vault.amount());
+            let recovered_tokens: Bucket = stakable_unit.vault.take_advanced(
+                residual_amount,
+                WithdrawStrategy::Rounded(RoundingMode::ToNegativeInfinity),
+            );
+            stakable_unit.amount_staked = dec!(0);
+            stakable_unit.reward_amount = dec!(0);
+
+            self.removed_stakes.insert(address, stakable_unit);
+
+            Runtime::emit_event(StakableRemovedEvent {
+                address,
+                recovered_amount: recovered_tokens.amount(),
+            });
+
+            recovered_tokens
+        }
+
+        /// Method pauses or unpauses a stakable resource, a paused resource can't be staked or locked, but can be unstaked
+        pub fn set_stakable_paused(&mut self, address: ResourceAddress, paused: bool) {
+            self.stakes
+                .get_mut(&address)
+                .expect("Stakable not found.")
+                .paused = paused;
+        }
+
+        /// Method sets the warm-up of a stakable resource, the amount of days newly staked tokens don't earn rewards
+        pub fn set_warmup_days(&mut self, address: ResourceAddress, warmup_days: i64) {
+            assert!(warmup_days >= 0, "Warm-up can't be negative.");
+            self.stakes
+                .get_mut(&address)
+                .expect("Stakable not found.")
+                .warmup_days = warmup_days;
+        }
+
+        /// Method sets the reward tiers of a stakable resource, (minimum stake, multiplier) pairs in ascending order of minimum stake, rewards above the regular rewards being paid from the bonus vault
+        pub fn set_reward_tiers(
+            &mut self,
+            address: ResourceAddress,
+            reward_tiers: Vec<(Decimal, Decimal)>,
+        ) {
+            for (index, (minimum_stake, multiplier)) in reward_tiers.iter().enumerate() {
+                assert!(
+                    *multiplier >= dec!(0),
+                    "Reward tier multiplier can't be negative."
+                );
+                if index > 0 {
+                    assert!(
+                        *minimum_stake > reward_tiers[index - 1].0,
+                        "Reward tiers must be in ascending order of minimum stake."
+                    );
+                }
+            }
+            self.stakes
+                .get_mut(&address)
+                .expect("Stakable not found.")
+                .reward_tiers = reward_tiers;
+        }
+
         /// Method sets next period to now, making rewards come instantly
         pub fn set_next_period_to_now(&mut self) {
             self.next_period = Clock::current_time_rounded_to_seconds();
@@ -921,16 +1406,19 @@ mod incentives {
 
         /// This method locks staked tokens for voting
         ///
+        /// Incentives IDs are not used for Governance voting, so this method only places a voting lock on a staked resource.
+        /// It is used by the DAO to vote-lock staked tokens it airdrops, and is only callable by the owner (the DAO).
+        ///
         /// ## INPUT
         /// - `address`: the address of the stakable token
-        /// - `lock_until`: the date until which the tokens are locked
+        /// - `voting_until`: the date until which the tokens are locked
         /// - `id`: the staking ID
         ///
         /// ## OUTPUT
-        /// - none
+        /// - the amount of tokens staked of the stakable token
         ///
         /// ## LOGIC
-        /// - the method checks whether a DAO is controlling the staking
+        /// - the method checks whether the voting lock ends in the future
         /// - the method updates the voting_until field of the staking ID appropriately
 
         pub fn vote(
@@ -939,6 +1427,14 @@ mod incentives {
             voting_until: Instant,
             id: NonFungibleLocalId,
         ) -> Decimal {
+            assert!(
+                voting_until.compare(
+                    Clock::current_time_rounded_to_seconds(),
+                    TimeComparisonOperator::Gt
+                ),
+                "Voting lock must end in the future."
+            );
+
             let id_data: IncentivesId = self.id_manager.get_non_fungible_data(&id);
 
             let mut resource_map = id_data.resources.clone();
@@ -976,6 +1472,129 @@ mod incentives {
             self.reward_vault.amount()
         }
 
+        /// This method gets the total amount of rewards distributed to date
+        ///
+        /// ## INPUT
+        /// - none
+        ///
+        /// ## OUTPUT
+        /// - total amount of tokens taken from the reward_vault as rewards
+        ///
+        /// ## LOGIC
+        /// - the method returns the counter of distributed rewards, which is incremented whenever rewards leave the reward_vault
+        pub fn get_total_distributed(&self) -> Decimal {
+            self.total_rewards_distributed
+        }
+
+        /// This method gets the unstake delay, the amount of days a user has to wait before claiming unstaked tokens
+        pub fn get_unstake_delay(&self) -> i64 {
+            self.unstake_delay
+        }
+
+        /// This method gets all stakable resources and their parameters
+        ///
+        /// ## INPUT
+        /// - none
+        ///
+        /// ## OUTPUT
+        /// - a vector of (address, reward amount per period, amount staked) for every stakable resource
+        ///
+        /// ## LOGIC
+        /// - the method iterates over all stakable units and collects their data
+        pub fn get_stakables(&self) -> Vec<(ResourceAddress, Decimal, Decimal)> {
+            self.stakes
+                .iter()
+                .map(|(address, stakable_unit)| {
+                    (
+                        *address,
+                        stakable_unit.reward_amount,
+                        stakable_unit.amount_staked,
+                    )
+                })
+                .collect()
+        }
+
+        /// This method gets the timing of the reward periods
+        ///
+        /// ## INPUT
+        /// - none
+        ///
+        /// ## OUTPUT
+        /// - the current period
+        /// - the time from which the next period can start
+        /// - the period interval in days
+        ///
+        /// ## LOGIC
+        /// - the method returns the period data of the component
+        pub fn get_period_info(&self) -> (i64, Instant, i64) {
+            (self.current_period, self.next_period, self.period_interval)
+        }
+
+        /// This method gets the amount and redemption time of an unstake receipt
+        ///
+        /// ## INPUT
+        /// - `local_id`: the local ID of the unstake receipt
+        ///
+        /// ## OUTPUT
+        /// - the amount of tokens redeemable with the receipt
+        /// - the time from which the receipt is redeemable
+        ///
+        /// ## LOGIC
+        /// - the method gets the receipt data from the unstake receipt manager
+        /// - the method returns the amount and the redemption time, which is moved up if the receipt has been accelerated
+        pub fn get_unstake_receipt_info(&self, local_id: NonFungibleLocalId) -> (Decimal, Instant) {
+            let receipt_data: UnstakeReceipt = self
+                .unstake_receipt_manager
+                .get_non_fungible_data(&local_id);
+
+            (
+                receipt_data.amount,
+                self.redemption_time(&local_id, receipt_data.redemption_time),
+            )
+        }
+
+        /// This method gets the amounts and redemption times of multiple unstake receipts
+        ///
+        /// ## INPUT
+        /// - `local_ids`: the local IDs of the unstake receipts
+        ///
+        /// ## OUTPUT
+        /// - the amount and redemption time of each receipt, in the order of the supplied IDs
+        ///
+        /// ## LOGIC
+        /// - the method gets the info of each receipt using the `get_unstake_receipt_info` method
+        pub fn get_unstake_receipts_info(
+            &self,
+            local_ids: Vec<NonFungibleLocalId>,
+        ) -> Vec<(Decimal, Instant)> {
+            local_ids
+                .into_iter()
+                .map(|local_id| self.get_unstake_receipt_info(local_id))
+                .collect()
+        }
+
+        /// This method gets the rewards a staking ID could currently claim, without claiming them
+        ///
+        /// ## INPUT
+        /// - `id_proof`: the proof of the staking ID
+        ///
+        /// ## OUTPUT
+        /// - the amount of claimable rewards
+        ///
+        /// ## LOGIC
+        /// - the method checks the staking ID
+        /// - the method calculates the rewards using the same logic as claiming them, capped at the maximum claim delay
+        /// - the method does not update the component period, so rewards of a period that has yet to be started are not included
+        pub fn get_pending_rewards(&self, id_proof: NonFungibleProof) -> Decimal {
+            let id_proof = id_proof
+                .check_with_message(self.id_manager.address(), "Invalid IncentivesId supplied!");
+            let id = id_proof.non_fungible::<IncentivesId>().local_id().clone();
+            let id_data: IncentivesId = self.id_manager.get_non_fungible_data(&id);
+
+            let (staking_reward, bonus, _) = self.pending_rewards(&id_data, None);
+            staking_reward + bonus.min(self.bonus_vault.amount())
+        }
+
         //===================================================================
         //                          HELPER METHODS
         //===================================================================
@@ -990,7 +1609,7 @@ mod incentives {
         /// - the address of the stakable token
         ///
         /// ## LOGIC
-        /// - the method checks whether the staked token is a stakable token
+        /// - the method checks whether the staked token is a stakable token that is not paused
         /// - the method puts the staked tokens in the staking component's vault
         /// - the method returns the amount of staked tokens and the address of the stakable token
 
@@ -1000,6 +1619,10 @@ mod incentives {
                 self.stakes.get(&address).is_some(),
                 "Token supplied does not match requested stakable token."
             );
+            assert!(
+                !self.stakes.get(&address).unwrap().paused,
+                "Staking this resource is paused."
+            );
             let stake_amount: Decimal = stake_bucket.amount();
             self.stakes
                 .get_mut(&address)
@@ -1010,6 +1633,27 @@ mod incentives {
             (stake_amount, address)
         }
 
+        /// This method calculates the redemption time of an unstake receipt, moving it up to the earliest acceleration made after the receipt was minted
+        fn redemption_time(
+            &self,
+            receipt_id: &NonFungibleLocalId,
+            redemption_time: Instant,
+        ) -> Instant {
+            let mut earliest_time: Instant = redemption_time;
+
+            if let NonFungibleLocalId::Integer(receipt_number) = receipt_id {
+                for (last_receipt, accelerated_time) in self.unstake_accelerations.iter() {
+                    if receipt_number.value() <= *last_receipt
+                        && accelerated_time.compare(earliest_time, TimeComparisonOperator::Lt)
+                    {
+                        earliest_time = *accelerated_time;
+                    }
+                }
+            }
+
+            earliest_time
+        }
+
         /// This method counts the staked tokens from a transfer receipt and burns it.
         ///
         /// ## INPUT
@@ -1021,6 +1665,7 @@ mod incentives {
         ///
         /// ## LOGIC
         /// - the method extracts the data from the receipt
+        /// - the method checks whether the receipt can be redeemed yet
         /// - the method burns the receipt
         /// - the method returns the amount of staked tokens and the address of the stakable token
 
@@ -1029,11 +1674,426 @@ mod incentives {
             receipt: NonFungibleBucket,
         ) -> (Decimal, ResourceAddress) {
             let receipt_data = receipt.non_fungible::<StakeTransferReceipt>().data();
+            assert!(
+                Clock::current_time_is_at_or_after(
+                    receipt_data.transferable_after,
+                    TimePrecision::Second
+                ),
+                "You cannot redeem this transfer receipt yet."
+            );
             let address: ResourceAddress = receipt_data.address;
             let stake_amount: Decimal = receipt_data.amount;
             receipt.burn();
 
             (stake_amount, address)
         }
+
+        /// This method stakes tokens to a staking ID
+        ///
+        /// ## INPUT
+        /// - `stake_bucket`: bucket containing either the tokens to stake or a stake transfer receipt
+        /// - `id`: the staking ID
+        ///
+        /// ## OUTPUT
+        /// - the lock reward, if the staked tokens are locked
+        ///
+        /// ## LOGIC
+        /// - the method checks whether it received tokens or a transfer receipt
+        /// - if the ID still has tokens of this stakable staked, the method checks if latest rewards of the staked token have been claimed, if not, the method fails (an ID without stake has nothing to claim)
+        /// - the method adds tokens to an internal vault, or burns the transfer receipt
+        /// - if the stakable has a warm-up, newly staked tokens (not transferred stake) don't earn rewards until the warm-up has passed, their share of the rewards stays in the reward vault
+        /// - if the ID stakes the stakable for the first time (not through a transfer), it is registered as a staker, which records the stake as early stake if it qualifies for the stakable's bootstrap bonus
+        /// - if the staked tokens are locked, the method calculates the lock reward and returns it
+        /// - the method updates the staking ID
+        fn stake_to_id(&mut self, stake_bucket: Bucket, id: &NonFungibleLocalId) -> Option<Bucket> {
+            let mut lock_reward_bucket: Option<Bucket> = None;
+
+            let id_data: IncentivesId = self.id_manager.get_non_fungible_data(id);
+
+            let stake_amount: Decimal;
+            let address: ResourceAddress;
+            let is_transfer: bool =
+                stake_bucket.resource_address() == self.stake_transfer_receipt_manager.address();
+
+            if is_transfer {
+                (stake_amount, address) =
+                    self.stake_transfer_receipt(stake_bucket.as_non_fungible());
+            } else {
+                (stake_amount, address) = self.stake_tokens(stake_bucket);
+            }
+
+            let has_stake: bool = id_data
+                .resources
+                .get(&address)
+                .map_or(false, |resource| resource.amount_staked > dec!(0));
+            assert!(
+                !has_stake
+                    || id_data
+                        .next_period
+                        .get(&address)
+                        .map_or(true, |next_period| *next_period > self.current_period),
+                "Please claim unclaimed rewards on your ID before staking."
+            );
+
+            let (warming_amount, warm_from_period): (Decimal, i64) =
+                if is_transfer || self.stakes.get(&address).unwrap().warmup_days == 0 {
+                    (dec!(0), self.current_period)
+                } else {
+                    (stake_amount, self.warm_from_period(address))
+                };
+
+            let early_stake: Decimal = if !is_transfer
+                && !id_data.resources.contains_key(&address)
+                && self.register_staker(address, stake_amount)
+            {
+                stake_amount
+            } else {
+                dec!(0)
+            };
+
+            let current_period: i64 = self.current_period;
+            let mut resource_map = id_data.resources.clone();
+            resource_map
+                .entry(address)
+                .and_modify(|resource| {
+                    resource.amount_staked += stake_amount;
+                    if warming_amount > dec!(0) {
+                        if resource.warm_from_period <= current_period {
+                            resource.warming_amount = dec!(0);
+                        }
+                        resource.warming_amount += warming_amount;
+                        resource.warm_from_period = warm_from_period;
+                    }
+                })
+                .or_insert(Resource {
+                    amount_staked: stake_amount,
+                    locked_until: None,
+                    voting_until: None,
+                    warming_amount,
+                    warm_from_period,
+                    early_stake,
+                    early_from_period: warm_from_period,
+                });
+
+            if let Some(locked_until) = resource_map
+                .get(&address)
+                .expect("Stakable not found in staking ID.")
+                .locked_until
+            {
+                if locked_until.compare(
+                    Clock::current_time_rounded_to_seconds(),
+                    TimeComparisonOperator::Gt,
+                ) {
+                    let stakable = self.stakes.get(&address).unwrap();
+                    let seconds_to_unlock = locked_until.seconds_since_unix_epoch
+                        - Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+                    let seconds_to_unlock_dec = Decimal::from(seconds_to_unlock);
+                    let full_days_to_unlock = (seconds_to_unlock_dec / dec!(86400))
+                        .checked_floor()
+                        .unwrap();
+                    let whole_days_to_unlock: i64 =
+                        i64::try_from(full_days_to_unlock.0 / Decimal::ONE.0).unwrap();
+                    let lock_reward: Bucket = self
+                        .reward_vault
+                        .take(
+                            stakable.lock.reward_coefficient
+                                * ((stakable
+                                    .lock
+                                    .payment
+                                    .checked_powi(whole_days_to_unlock)
+                                    .unwrap()
+                                    * stake_amount)
+                                    - stake_amount),
+                        )
+                        .into();
+                    self.total_rewards_distributed += lock_reward.amount();
+                    lock_reward_bucket = Some(lock_reward);
+                }
+            }
+
+            self.id_manager
+                .update_non_fungible_data(id, "resources", resource_map);
+
+            self.stakes.get_mut(&address).unwrap().amount_staked += stake_amount;
+
+            let mut next_period_map = id_data.next_period.clone();
+            next_period_map.insert(address, self.current_period + 1);
+            self.id_manager
+                .update_non_fungible_data(id, "next_period", next_period_map);
+
+            lock_reward_bucket
+        }
+
+        /// This method claims rewards from a staking ID
+        ///
+        /// ## INPUT
+        /// - `id`: the staking ID
+        /// - `address`: the address of the staked token to claim rewards for, or none to claim for all staked tokens
+        ///
+        /// ## OUTPUT
+        /// - the claimed rewards
+        ///
+        /// ## LOGIC
+        /// - the method calculates the rewards of the (requested) staked tokens using the `pending_rewards` method
+        /// - the method checks whether any staked token had unclaimed periods
+        /// - the method updates the staking ID to the next period for the claimed tokens
+        /// - the method returns the claimed rewards, together with the bonus as far as the bonus vault can pay it
+        fn claim_rewards(
+            &mut self,
+            id: &NonFungibleLocalId,
+            address: Option<ResourceAddress>,
+        ) -> FungibleBucket {
+            let id_data: IncentivesId = self.id_manager.get_non_fungible_data(id);
+
+            if let Some(address) = address {
+                assert!(
+                    id_data.resources.get(&address).is_some(),
+                    "Stakable not found in staking ID."
+                );
+            }
+
+            let (staking_reward, bonus, claimed_addresses): (
+                Decimal,
+                Decimal,
+                Vec<ResourceAddress>,
+            ) = self.pending_rewards(&id_data, address);
+
+            assert!(
+                !claimed_addresses.is_empty(),
+                "Wait longer to claim your rewards."
+            );
+
+            let mut next_period_map = id_data.next_period.clone();
+            for claimed_address in claimed_addresses {
+                next_period_map.insert(claimed_address, self.current_period + 1);
+            }
+
+            self.id_manager
+                .update_non_fungible_data(id, "next_period", next_period_map);
+
+            let bonus: Decimal = bonus.min(self.bonus_vault.amount());
+            self.total_rewards_distributed += staking_reward + bonus;
+            let mut rewards: FungibleBucket = self.reward_vault.take(staking_reward);
+            rewards.put(self.bonus_vault.take(bonus));
+            rewards
+        }
+
+        /// This method calculates the unclaimed rewards of a staking ID
+        ///
+        /// ## INPUT
+        /// - `id_data`: the data of the staking ID
+        /// - `address`: the address of the staked token to calculate rewards for, or none to calculate for all staked tokens
+        ///
+        /// ## OUTPUT
+        /// - the unclaimed rewards
+        /// - the unclaimed bonus, being the rewards of reward tiers above 1 and the bootstrap bonus
+        /// - the addresses of the staked tokens that have unclaimed periods
+        ///
+        /// ## LOGIC
+        /// - the method iterates over the (requested) staked tokens
+        /// - the method checks amount of unclaimed periods per staked token, skipping tokens without any
+        /// - the method calculates the rewards for these periods, being the growth of the cumulative reward per staked token over them times the amount staked
+        /// - warming tokens are excluded from the growth before their warm-up has passed
+        /// - rewards are multiplied by the multiplier of the reward tier reached by the amount staked, the part above the regular rewards counting as bonus
+        /// - early stakers earn the extra rewards of the stakable's bootstrap bonus on their early stake, for the unclaimed periods within the bootstrap window
+        fn pending_rewards(
+            &self,
+            id_data: &IncentivesId,
+            address: Option<ResourceAddress>,
+        ) -> (Decimal, Decimal, Vec<ResourceAddress>) {
+            let mut staking_reward: Decimal = dec!(0);
+            let mut bonus_reward: Decimal = dec!(0);
+            let mut claimed_addresses: Vec<ResourceAddress> = vec![];
+
+            for (resource_address, resource) in id_data.resources.iter() {
+                if address.map_or(false, |address| address != *resource_address) {
+                    continue;
+                }
+
+                let next_period: i64 = *id_data
+                    .next_period
+                    .get(resource_address)
+                    .unwrap_or(&(self.current_period + 1));
+                let mut claimed_weeks: i64 = self.current_period - next_period + 1;
+                if claimed_weeks > self.max_claim_delay {
+                    claimed_weeks = self.max_claim_delay;
+                }
+
+                if claimed_weeks <= 0 {
+                    continue;
+                }
+
+                claimed_addresses.push(*resource_address);
+
+                if let Some(stakable_unit) = self.stakes.get(resource_address) {
+                    let first_period: i64 = self.current_period - claimed_weeks;
+                    let acc_start: Decimal = Self::acc_reward_at(stakable_unit, first_period);
+                    let acc_end: Decimal = Self::acc_reward_at(stakable_unit, self.current_period);
+                    let mut resource_reward: Decimal =
+                        (acc_end - acc_start) * resource.amount_staked;
+
+                    if resource.warm_from_period > first_period {
+                        let acc_warm: Decimal = Self::acc_reward_at(
+                            stakable_unit,
+                            resource.warm_from_period.min(self.current_period),
+                        );
+                        resource_reward -= (acc_warm - acc_start) * resource.warming_amount;
+                    }
+
+                    let multiplier: Decimal =
+                        Self::reward_multiplier(stakable_unit, resource.amount_staked);
+
+                    staking_reward += resource_reward * multiplier.min(dec!(1));
+                    bonus_reward += resource_reward * (multiplier - dec!(1)).max(dec!(0));
+
+                    if let Some(bonus) = &stakable_unit.bootstrap_bonus {
+                        let bonus_from: i64 = first_period.max(resource.early_from_period);
+                        let bonus_until: i64 =
+                            self.current_period.min(stakable_unit.bootstrap_end_period);
+                        if resource.early_stake > dec!(0) && bonus_until > bonus_from {
+                            bonus_reward += (Self::acc_reward_at(stakable_unit, bonus_until)
+                                - Self::acc_reward_at(stakable_unit, bonus_from))
+                                * resource.early_stake
+                                * (bonus.multiplier - dec!(1));
+                        }
+                    }
+                }
+            }
+
+            (staking_reward, bonus_reward, claimed_addresses)
+        }
+
+        /// This method deposits claimed rewards into an account if one is supplied, falling back to the reward locker if the account doesn't accept them, otherwise it returns them to the caller
+        fn deliver_rewards(
+            &mut self,
+            rewards: FungibleBucket,
+            claim_to: Option<Global<Account>>,
+        ) -> FungibleBucket {
+            match claim_to {
+                Some(account) => {
+                    self.reward_locker.store(account, rewards.into(), true);
+                    self.reward_vault.take(dec!(0))
+                }
+                None => rewards,
+            }
+        }
+
+        /// This method gets the reward multiplier of the highest reward tier reached by a staked amount, which is 1 if none is reached
+        fn reward_multiplier(stakable_unit: &StakableUnit, amount_staked: Decimal) -> Decimal {
+            stakable_unit
+                .reward_tiers
+                .iter()
+                .rev()
+                .find(|(minimum_stake, _)| amount_staked >= *minimum_stake)
+                .map_or(dec!(1), |(_, multiplier)| *multiplier)
+        }
+
+        /// This method gets the cumulative reward per staked token at the start of a period, which is 0 for periods before the stakable was added
+        fn acc_reward_at(stakable_unit: &StakableUnit, period: i64) -> Decimal {
+            stakable_unit
+                .acc_rewards
+                .get(&period)
+                .map_or(dec!(0), |acc_reward| *acc_reward)
+        }
+
+        /// This method registers a new staker of a stakable and checks whether it qualifies for the stakable's bootstrap bonus, by staking at least its minimum stake within its bootstrap window while places are left
+        fn register_staker(&mut self, address: ResourceAddress, stake_amount: Decimal) -> bool {
+            let stakable = self.stakes.get_mut(&address).unwrap();
+            stakable.staker_count += 1;
+
+            let qualifies: bool = stakable.bootstrap_bonus.as_ref().map_or(false, |bonus| {
+                stake_amount >= bonus.min_stake
+                    && (bonus.max_stakers == 0 || stakable.early_staker_count < bonus.max_stakers)
+                    && !Clock::current_time_is_at_or_after(
+                        stakable.added_at.add_days(bonus.window_days).unwrap(),
+                        TimePrecision::Second,
+                    )
+            });
+            if qualifies {
+                stakable.early_staker_count += 1;
+            }
+
+            qualifies
+        }
+
+        /// This method calculates the first period newly staked tokens of a stakable earn rewards for, after their warm-up
+        fn warm_from_period(&self, address: ResourceAddress) -> i64 {
+            let warmup_days: i64 = self.stakes.get(&address).unwrap().warmup_days;
+            self.current_period + (warmup_days + self.period_interval - 1) / self.period_interval
+        }
+
+        /// This method locks staked tokens of a staking ID for a certain duration and gives rewards for locking them
+        ///
+        /// ## INPUT
+        /// - `address`: the address of the stakable token
+        /// - `id`: the staking ID
+        /// - `days_to_lock`: the duration for which the tokens are locked in days
+        ///
+        /// ## OUTPUT
+        /// - rewards for locking the tokens
+        ///
+        /// ## LOGIC
+        /// - the method checks whether this resource address is lockable
+        /// - the method checks whether the staking ID tokens are already locked, if so the lock is extended from the existing lock
+        /// - regardless of earlier relocks, the new lock can never end later than the maximum lock duration from now
+        /// - the method locks the tokens by updating the staking ID
+        /// - the method calculates and returns the rewards for locking the tokens
+        fn lock_id(
+            &mut self,
+            address: ResourceAddress,
+            id: &NonFungibleLocalId,
+            days_to_lock: i64,
+        ) -> FungibleBucket {
+            assert!(days_to_lock > 0, "Days to lock must be positive.");
+            let stakable = self.stakes.get(&address).unwrap();
+            assert!(!stakable.paused, "Locking this resource is paused.");
+
+            let id_data: IncentivesId = self.id_manager.get_non_fungible_data(id);
+            let mut resource_map = id_data.resources.clone();
+            let mut resource = resource_map
+                .get(&address)
+                .expect("Stakable not found in staking ID.")
+                .clone();
+
+            let amount_staked = resource.amount_staked;
+            let new_lock: Instant;
+            let max_lock: Instant = Clock::current_time_rounded_to_seconds()
+                .add_days(stakable.lock.max_duration)
+                .unwrap();
+
+            if let Some(locked_until) = resource.locked_until {
+                if locked_until.compare(
+                    Clock::current_time_rounded_to_seconds(),
+                    TimeComparisonOperator::Gt,
+                ) {
+                    new_lock = locked_until.add_days(days_to_lock).unwrap();
+                } else {
+                    new_lock = Clock::current_time_rounded_to_seconds()
+                        .add_days(days_to_lock)
+                        .unwrap();
+                }
+            } else {
+                new_lock = Clock::current_time_rounded_to_seconds()
+                    .add_days(days_to_lock)
+                    .unwrap();
+            }
+
+            assert!(
+                new_lock.compare(max_lock, TimeComparisonOperator::Lte),
+                "New lock duration exceeds maximum lock duration."
+            );
+
+            resource.locked_until = Some(new_lock);
+            resource_map.insert(address, resource);
+
+            self.id_manager
+                .update_non_fungible_data(id, "resources", resource_map);
+
+            let lock_reward: Decimal = stakable.lock.reward_coefficient
+                * ((stakable.lock.payment.checked_powi(days_to_lock).unwrap() * amount_staked)
+                    - amount_staked);
+            self.total_rewards_distributed += lock_reward;
+            self.reward_vault.take(lock_reward)
+        }
     }
 }
@@ -16,7 +16,9 @@ mod reentrancy {
     enable_method_auth! {
         methods {
             call => PUBLIC;
+            get_pending_step => PUBLIC;
             send_step => restrict_to: [OWNER];
+            cancel_step => restrict_to: [OWNER];
         }
     }
 
@@ -87,6 +89,21 @@ mod reentrancy {
                 .insert(proposal_id, (args, component, method));
         }
 
+        /// Cancels a ProposalStep stored in the ReentrancyProxy, so it can't be executed anymore.
+        ///
+        /// # Input
+        /// - `proposal_id`: ID of the proposal to cancel the step for
+        ///
+        /// # Output
+        /// - None
+        ///
+        /// # Logic
+        /// - Removes the ProposalStep from the reentrancies KVS, if any
+        ///     - This method is called by the Governance component when a pending reentrancy step is aborted
+        pub fn cancel_step(&mut self, proposal_id: u64) {
+            self.reentrancies.remove(&proposal_id);
+        }
+
         /// Executes a ProposalStep stored in the ReentrancyProxy.
         ///
         /// # Input
@@ -116,5 +133,21 @@ mod reentrancy {
                     component.call_raw::<()>("finish_reentrancy_step", scrypto_args!(proposal_id))
                 });
         }
+
+        /// Gets the ProposalStep of a proposal that is waiting to be executed through the ReentrancyProxy.
+        ///
+        /// # Input
+        /// - `proposal_id`: ID of the proposal to get the pending step for
+        ///
+        /// # Output
+        /// - The address of the component and the method to call, or None if no step is pending
+        ///
+        /// # Logic
+        /// - Retrieves the ProposalStep from the reentrancies KVS, if any
+        pub fn get_pending_step(&self, proposal_id: u64) -> Option<(ComponentAddress, String)> {
+            self.reentrancies
+                .get(&proposal_id)
+                .map(|step| (step.1, step.2.clone()))
+        }
     }
 }
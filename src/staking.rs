@@ -5,6 +5,7 @@ This blueprint enables advanced staking of a resource, with itself as a reward.
 To understand some design choices, it might be useful to check that one out first.
 The modifications made to the incentives blueprint to get to this version are made to allow for the staking rewards are distributed continuously.
 This is only possible when the staking rewards are distributed in the same token as the staked tokens, as the rewards are distributed to the staking pool, and the staking pool is the only one that can claim these rewards.
+A secondary reward token can be streamed to stakers as well. As it can't be compounded into the pool, it is tracked per staking ID and has to be claimed.
 Still, we are using a Staking ID system, where users stake tokens to a staking ID, which is an NFT, to allow for locking of / voting with staked tokens and introduce unstaking delays.
 
 The following description is a copy of the Incentives blueprint description with minimal changes to reflect the changes made in this blueprint:
@@ -40,6 +41,9 @@ This NFT staking ID approach has some disadvantages over simple OneResourcePool
 
 use scrypto::prelude::*;
 
+/// Maximum amount of share price snapshots kept, older snapshots are overwritten.
+const SHARE_PRICE_HISTORY_SIZE: u64 = 365;
+
 /// NFT receipt structure, minted when an unstake is requested, redeemable after a set delay.
 #[derive(ScryptoSbor, NonFungibleData)]
 pub struct UnstakeReceipt {
@@ -64,6 +68,21 @@ pub struct Id {
     pub voting_until: Option<Instant>,
     #[mutable]
     pub undelegating_until: Option<Instant>,
+    #[mutable]
+    pub warmup_tranches: Vec<WarmupTranche>,
+    #[mutable]
+    pub last_voted_at: Option<Instant>,
+    #[mutable]
+    pub lock_reward_granted: Decimal,
+    pub created_at: Instant,
+}
+
+/// Warm-up tranche structure, holding the tokens staked to a staking ID at one moment that may still be in their warm-up.
+#[derive(ScryptoSbor, Clone)]
+pub struct WarmupTranche {
+    pub staked_at: Instant,
+    pub pool_amount: Decimal,
+    pub real_amount: Decimal,
 }
 
 /// Lock structure, holding the information about locking options of a token.
@@ -82,35 +101,122 @@ pub struct StakableUnit {
     pub vault: Vault,
     pub reward_amount: Decimal,
     pub lock: Lock,
+    pub warmup_days: i64,
+    pub transfer_receipt_delay: i64,
+}
+
+/// Vote decay structure, holding the parameters used to scale down the vote power of inactive staking IDs.
+#[derive(ScryptoSbor)]
+pub struct VoteDecay {
+    pub period_days: i64,
+    pub factor: Decimal,
+    pub floor: Decimal,
+}
+
+/// Secondary reward structure, holding a second reward token that is streamed to stakers next to the mother token.
+#[derive(ScryptoSbor)]
+pub struct SecondaryReward {
+    pub reward_vault: FungibleVault,
+    pub claimable_vault: FungibleVault,
+    pub reward_amount: Decimal,
+    pub reward_per_pool_token: Decimal,
+    pub last_update: Instant,
+}
+
+/// Secondary reward snapshot structure, holding the secondary rewards a staking ID earned up to its last snapshot.
+#[derive(ScryptoSbor, Clone)]
+pub struct SecondaryRewardSnapshot {
+    pub reward_per_pool_token: Decimal,
+    pub unclaimed: Decimal,
 }
 
 /// Stake transfer receipt structure, minted when a user wants to transfer their staked tokens, redeemable by other users to add these tokens to their own staking ID.
 #[derive(ScryptoSbor, NonFungibleData)]
 pub struct StakeTransferReceipt {
     pub pool_amount: Decimal,
+    pub transferable_after: Instant,
+}
+
+/// Event emitted when the DAO forcefully shortens the lock of a staking ID.
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct ForceUnlockEvent {
+    pub id: NonFungibleLocalId,
+    pub days_unlocked: i64,
+    pub locked_until: Instant,
+}
+
+/// Event emitted when the DAO winds down the staking rewards.
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct RewardsWoundDownEvent {
+    pub returned_amount: Decimal,
+    pub total_distributed: Decimal,
 }
 
 #[blueprint]
-#[types(Decimal, Option<NonFungibleLocalId>, Option<Instant>, Instant)]
+#[types(
+    Decimal,
+    Option<NonFungibleLocalId>,
+    Option<Instant>,
+    Instant,
+    u64,
+    (Instant, Decimal),
+    NonFungibleLocalId,
+    SecondaryRewardSnapshot,
+    Vec<WarmupTranche>
+)]
+#[events(ForceUnlockEvent, RewardsWoundDownEvent)]
 mod staking {
     enable_method_auth! {
         methods {
             create_id => PUBLIC;
             stake => PUBLIC;
+            stake_and_lock => PUBLIC;
             start_unstake => PUBLIC;
+            settle_warmup => PUBLIC;
+            transfer_full_position => PUBLIC;
             finish_unstake => PUBLIC;
+            finish_unstake_batch => PUBLIC;
+            finish_unstake_and_restake => PUBLIC;
+            emergency_unstake => PUBLIC;
+            claim_secondary_rewards => PUBLIC;
             update_period => PUBLIC;
+            rewarded_update_period => PUBLIC;
             lock_stake => PUBLIC;
             unlock_stake => PUBLIC;
             get_remaining_rewards => PUBLIC;
+            get_total_distributed => PUBLIC;
+            get_unstake_delay => PUBLIC;
             delegate_vote => PUBLIC;
             undelegate_vote => PUBLIC;
             put_tokens => PUBLIC;
+            put_secondary_tokens => PUBLIC;
             get_real_amount => PUBLIC;
+            get_share_price_history => PUBLIC;
+            get_unstake_receipt_info => PUBLIC;
+            get_unstake_receipts_info => PUBLIC;
+            get_effective_vote_power => PUBLIC;
+            get_reward_halvings => PUBLIC;
+            get_total_delegated => PUBLIC;
+            get_pool_amount_staked => PUBLIC;
             vote => restrict_to: [OWNER];
+            migrate_stake => restrict_to: [OWNER];
             remove_tokens => restrict_to: [OWNER];
+            remove_secondary_tokens => restrict_to: [OWNER];
+            set_secondary_reward => restrict_to: [OWNER];
+            wind_down_rewards => restrict_to: [OWNER];
+            freeze_rewards => restrict_to: [OWNER];
+            unfreeze_rewards => restrict_to: [OWNER];
             edit_stakable => restrict_to: [OWNER];
             set_unstake_delay => restrict_to: [OWNER];
+            accelerate_unstake_receipts => restrict_to: [OWNER];
+            force_unlock => restrict_to: [OWNER];
+            set_caller_reward => restrict_to: [OWNER];
+            set_warmup_days => restrict_to: [OWNER];
+            set_lock_clawback => restrict_to: [OWNER];
+            set_emergency_penalty => restrict_to: [OWNER];
+            set_transfer_receipt_delay => restrict_to: [OWNER];
+            set_vote_decay => restrict_to: [OWNER];
+            set_reward_halvings => restrict_to: [OWNER];
         }
     }
 
@@ -123,12 +229,16 @@ mod staking {
         pub unstake_receipt_manager: ResourceManager,
         /// counter for the unstake receipts
         pub unstake_receipt_counter: u64,
+        /// accelerations of unstake receipts, each holding the last receipt minted before it and the time from which receipts up to it can be redeemed
+        pub unstake_accelerations: Vec<(u64, Instant)>,
         /// resource manager of the staking IDs
         pub id_manager: ResourceManager,
         /// counter for the staking IDs
         pub id_counter: u64,
         /// vault that stores staking rewards
         pub reward_vault: FungibleVault,
+        /// total amount of rewards taken from the reward vault to date
+        pub total_rewards_distributed: Decimal,
         // parameters for staking the token
         pub stakable_unit: StakableUnit,
         ///lsu pool for reward token
@@ -141,6 +251,30 @@ mod staking {
         pub pool_token_address: ResourceAddress,
         ///address of mother token
         pub mother_token_address: ResourceAddress,
+        ///ring buffer of share price snapshots (time, value of a single pool token)
+        pub share_price_history: KeyValueStore<u64, (Instant, Decimal)>,
+        ///counter for the share price snapshots
+        pub share_price_counter: u64,
+        ///reward paid to callers of rewarded_update_period, at most once a day
+        pub caller_reward: Decimal,
+        ///last time a caller reward was paid out
+        pub last_caller_reward: Instant,
+        ///optional decay of the vote power of staking IDs that haven't voted for a while
+        pub vote_decay: Option<VoteDecay>,
+        ///upcoming emission milestones (total rewards distributed threshold, new daily reward amount), in ascending order
+        pub reward_halvings: Vec<(Decimal, Decimal)>,
+        ///total amount of pool tokens of which the voting power is delegated to other staking IDs
+        pub total_pool_amount_delegated: Decimal,
+        ///moment reward accrual was frozen, none if rewards aren't frozen
+        pub rewards_frozen_at: Option<Instant>,
+        ///penalty on top of the lock rewards clawed back when unlocking early, no clawback if none
+        pub lock_clawback_penalty: Option<Decimal>,
+        ///share of the unstaked tokens left in the pool when unstaking without delay, emergency unstaking disabled if zero
+        pub emergency_penalty: Decimal,
+        ///optional second reward token streamed to stakers, which can't be compounded into the pool and is claimed per staking ID instead
+        pub secondary_reward: Option<SecondaryReward>,
+        ///secondary rewards earned by each staking ID up to its last snapshot
+        pub secondary_reward_snapshots: KeyValueStore<NonFungibleLocalId, SecondaryRewardSnapshot>,
     }
 
     impl Staking {
@@ -287,6 +421,8 @@ mod staking {
                 vault: Vault::new(pool_token_address),
                 reward_amount: dec!(10000),
                 lock: mother_lock,
+                warmup_days: 0,
+                transfer_receipt_delay: 0,
             };
 
             let component = Self {
@@ -295,17 +431,31 @@ mod staking {
                 stake_transfer_receipt_counter: 0,
                 unstake_receipt_manager,
                 unstake_receipt_counter: 0,
+                unstake_accelerations: vec![],
                 id_counter: 0,
                 reward_vault: FungibleVault::with_bucket(rewards.as_fungible()),
+                total_rewards_distributed: dec!(0),
                 stakable_unit,
                 mother_pool,
                 unstaked_mother_tokens: Vault::new(mother_token_address),
                 last_update: Clock::current_time_rounded_to_seconds(),
                 pool_token_address,
                 mother_token_address,
+                share_price_history: StakingKeyValueStore::new_with_registered_type(),
+                share_price_counter: 0,
+                caller_reward: dec!(0),
+                last_caller_reward: Clock::current_time_rounded_to_seconds(),
+                vote_decay: None,
+                reward_halvings: vec![],
+                total_pool_amount_delegated: dec!(0),
+                rewards_frozen_at: None,
+                lock_clawback_penalty: None,
+                emergency_penalty: dec!(0),
+                secondary_reward: None,
+                secondary_reward_snapshots: StakingKeyValueStore::new_with_registered_type(),
             }
             .instantiate()
-            .prepare_to_globalize(OwnerRole::Fixed(rule!(require(controller))))
+            .prepare_to_globalize(OwnerRole::Updatable(rule!(require(controller))))
             .with_address(address_reservation)
             .metadata(metadata! {
                 init {
@@ -331,23 +481,106 @@ mod staking {
         /// - none
         ///
         /// ## LOGIC
+        /// - if rewards are frozen, nothing is distributed
+        /// - if nothing is staked, nothing is distributed and the last update is kept, so rewards accrue for the first staker instead of being lost
         /// - the mother token staking rewards are distributed every time the method is called, depending on how many minutes have passed since the last update
+        /// - if the total rewards distributed cross an emission milestone, the time after crossing it is rewarded at the new reward amount, which is then stored
         /// - a new value for the last update is set
+        /// - a snapshot of the share price is recorded
+        /// - the secondary rewards are distributed as well, using the accrue_secondary_rewards method
         pub fn update_period(&mut self) {
+            self.accrue_secondary_rewards();
+
+            if self.rewards_frozen_at.is_some() || self.stakable_unit.pool_amount_staked == dec!(0)
+            {
+                return;
+            }
+
             if Clock::current_time_is_strictly_after(self.last_update, TimePrecision::Second) {
                 let seconds_since_last_update: i64 = Clock::current_time_rounded_to_seconds()
                     .seconds_since_unix_epoch
                     - self.last_update.seconds_since_unix_epoch;
                 let seconds_per_period: i64 = 86400; //one day of seconds
-                let reward_fraction: Decimal = self.stakable_unit.reward_amount
-                    * Decimal::from(seconds_since_last_update)
-                    / Decimal::from(seconds_per_period);
+
+                let mut reward_amount: Decimal = self.stakable_unit.reward_amount;
+                let mut halvings_passed: usize = 0;
+                let mut seconds_left: Decimal = Decimal::from(seconds_since_last_update);
+                let mut reward_fraction: Decimal = dec!(0);
+
+                while seconds_left > dec!(0) {
+                    let period_reward: Decimal =
+                        reward_amount * seconds_left / Decimal::from(seconds_per_period);
+                    match self.reward_halvings.get(halvings_passed) {
+                        Some((threshold, new_reward_amount))
+                            if self.total_rewards_distributed + reward_fraction + period_reward
+                                >= *threshold =>
+                        {
+                            let reward_to_threshold: Decimal =
+                                (*threshold - self.total_rewards_distributed - reward_fraction)
+                                    .max(dec!(0));
+                            if reward_amount > dec!(0) {
+                                seconds_left -= reward_to_threshold
+                                    * Decimal::from(seconds_per_period)
+                                    / reward_amount;
+                            }
+                            reward_fraction += reward_to_threshold;
+                            reward_amount = *new_reward_amount;
+                            halvings_passed += 1;
+                        }
+                        _ => {
+                            reward_fraction += period_reward;
+                            seconds_left = dec!(0);
+                        }
+                    }
+                }
 
                 if self.reward_vault.amount() > reward_fraction {
+                    self.stakable_unit.reward_amount = reward_amount;
+                    self.reward_halvings.drain(..halvings_passed);
+                    self.total_rewards_distributed += reward_fraction;
                     self.mother_pool
                         .protected_deposit(self.reward_vault.take(reward_fraction).into());
                 }
                 self.last_update = Clock::current_time_rounded_to_seconds();
+                self.record_share_price();
+            }
+        }
+
+        /// This method updates the component's period and rewards the caller for doing so.
+        ///
+        /// ## INPUT
+        /// - none
+        ///
+        /// ## OUTPUT
+        /// - the caller reward, which is empty if no reward is due
+        ///
+        /// ## LOGIC
+        /// - the method updates the period
+        /// - if rewards aren't frozen and a day has passed since the last paid out caller reward, the caller reward is taken from the reward vault
+        /// - the caller reward is capped at 0.1% of the remaining rewards
+        pub fn rewarded_update_period(&mut self) -> FungibleBucket {
+            self.update_period();
+
+            let now: Instant = Clock::current_time_rounded_to_seconds();
+            if self.caller_reward > dec!(0)
+                && self.rewards_frozen_at.is_none()
+                && Clock::current_time_is_at_or_after(
+                    self.last_caller_reward.add_days(1).unwrap(),
+                    TimePrecision::Second,
+                )
+            {
+                self.last_caller_reward = now;
+                let reward: Decimal = self
+                    .caller_reward
+                    .min(self.reward_vault.amount() * dec!("0.001"));
+                let caller_reward: FungibleBucket = self.reward_vault.take_advanced(
+                    reward,
+                    WithdrawStrategy::Rounded(RoundingMode::ToNegativeInfinity),
+                );
+                self.total_rewards_distributed += caller_reward.amount();
+                caller_reward
+            } else {
+                self.reward_vault.take(dec!(0))
             }
         }
 
@@ -365,7 +598,9 @@ mod staking {
         /// - the method checks the staking ID
         /// - the method checks the staked amount
         /// - the method checks if the staked tokens are locked or voting (then unstaking is not possible)
+        /// - if a warm-up of the staking ID has passed without being settled, it is settled first, using the settle_passed_warmup method
         /// - if not, tokens are removed from staking ID stake
+        /// - if tokens are unstaked during their warm-up, the rewards they earned are forfeited to the reward vault
         /// - if the user wants to transfer the tokens, a transfer receipt is minted
         /// - if the user wants to unstake the tokens, an unstake receipt is minted and pool tokens are converted to normal mother tokens again.
         pub fn start_unstake(
@@ -412,6 +647,9 @@ mod staking {
                 "Undelegate voting power before unstaking"
             );
 
+            self.snapshot_secondary_rewards(&id, id_data.pool_amount_staked);
+            self.settle_passed_warmup(&id, &mut id_data);
+
             if amount >= id_data.pool_amount_staked {
                 unstake_amount = id_data.pool_amount_staked;
                 id_data.pool_amount_staked = dec!(0);
@@ -427,9 +665,16 @@ mod staking {
                 id_data.pool_amount_staked,
             );
 
+            if self.is_warming_up(&id_data) {
+                unstake_amount -= self.forfeit_warmup_rewards(&id, &mut id_data, unstake_amount);
+            }
+
             if stake_transfer {
                 let stake_transfer_receipt = StakeTransferReceipt {
                     pool_amount: unstake_amount,
+                    transferable_after: Clock::current_time_rounded_to_seconds()
+                        .add_days(self.stakable_unit.transfer_receipt_delay)
+                        .unwrap(),
                 };
                 self.stake_transfer_receipt_counter += 1;
                 self.stake_transfer_receipt_manager.mint_non_fungible(
@@ -452,6 +697,56 @@ mod staking {
             }
         }
 
+        /// This method settles the passed warm-ups of a staking ID, so its tokens don't keep the rewards earned while warming up
+        ///
+        /// ## INPUT
+        /// - `id`: the local ID of the staking ID
+        ///
+        /// ## OUTPUT
+        /// - none
+        ///
+        /// ## LOGIC
+        /// - the method checks whether the staking ID has tokens in a warm-up that has passed
+        /// - the method takes a snapshot of the secondary rewards earned by the staking ID, using the snapshot_secondary_rewards method
+        /// - the method settles the passed warm-ups, using the settle_passed_warmup method
+        /// - anyone can call this method, as settling a warm-up only forfeits the rewards earned during it
+        pub fn settle_warmup(&mut self, id: NonFungibleLocalId) {
+            let mut id_data: Id = self.id_manager.get_non_fungible_data(&id);
+
+            assert!(
+                id_data
+                    .warmup_tranches
+                    .iter()
+                    .any(|tranche| self.warmup_has_passed(tranche)),
+                "No passed warm-up to settle."
+            );
+
+            self.snapshot_secondary_rewards(&id, id_data.pool_amount_staked);
+            self.settle_passed_warmup(&id, &mut id_data);
+        }
+
+        /// This method transfers a whole staking position to another account in one go
+        ///
+        /// ## INPUT
+        /// - `id_proof`: the proof of the staking ID
+        /// - `recipient`: the account to transfer the position to
+        ///
+        /// ## OUTPUT
+        /// - none
+        ///
+        /// ## LOGIC
+        /// - the method requests a transfer of the full stake using the start_unstake method, so the staked tokens can't be locked, voting or delegating
+        /// - the method deposits the minted transfer receipt into the recipient's account, aborting if the account doesn't accept it
+        /// - partial transfers can still be made by using the start_unstake method directly
+        pub fn transfer_full_position(
+            &mut self,
+            id_proof: NonFungibleProof,
+            recipient: Global<Account>,
+        ) {
+            let transfer_receipt: Bucket = self.start_unstake(id_proof, Decimal::MAX, true);
+            recipient.try_deposit_or_abort(transfer_receipt, None);
+        }
+
         /// This method finishes an unstake, redeeming the unstaked tokens
         ///
         /// ## INPUT
@@ -462,7 +757,7 @@ mod staking {
         ///
         /// ## LOGIC
         /// - the method checks the receipt
-        /// - the method checks the redemption time
+        /// - the method checks the redemption time, which is moved up if the receipt has been accelerated
         /// - the method burns the receipt
         /// - the method returns the unstaked tokens
         pub fn finish_unstake(&mut self, receipt: Bucket) -> Bucket {
@@ -472,12 +767,13 @@ mod staking {
                 .as_non_fungible()
                 .non_fungible::<UnstakeReceipt>()
                 .data();
+            let redemption_time: Instant = self.redemption_time(
+                &receipt.as_non_fungible().non_fungible_local_id(),
+                receipt_data.redemption_time,
+            );
 
             assert!(
-                Clock::current_time_is_at_or_after(
-                    receipt_data.redemption_time,
-                    TimePrecision::Second
-                ),
+                Clock::current_time_is_at_or_after(redemption_time, TimePrecision::Second),
                 "You cannot unstake tokens before the redemption time."
             );
 
@@ -485,6 +781,166 @@ mod staking {
             self.unstaked_mother_tokens.take(receipt_data.amount)
         }
 
+        /// This method finishes multiple unstakes at once, redeeming the unstaked tokens
+        ///
+        /// ## INPUT
+        /// - `receipts`: the unstake receipts
+        ///
+        /// ## OUTPUT
+        /// - the unstaked tokens of all receipts
+        ///
+        /// ## LOGIC
+        /// - the method checks whether any receipts are supplied
+        /// - the method redeems each receipt using the finish_unstake method, so the whole call fails if any receipt isn't redeemable yet
+        /// - the method returns the combined unstaked tokens
+        pub fn finish_unstake_batch(&mut self, receipts: Vec<Bucket>) -> Bucket {
+            assert!(!receipts.is_empty(), "No unstake receipts supplied.");
+
+            let mut unstaked_tokens: Bucket = self.unstaked_mother_tokens.take(dec!(0));
+            for receipt in receipts {
+                unstaked_tokens.put(self.finish_unstake(receipt));
+            }
+
+            unstaked_tokens
+        }
+
+        /// This method finishes an unstake and immediately stakes the unstaked tokens again
+        ///
+        /// ## INPUT
+        /// - `receipt`: the unstake receipt
+        /// - `id_proof`: the proof of the staking ID to stake to
+        ///
+        /// ## OUTPUT
+        /// - an optional staking ID (if none was provided)
+        ///
+        /// ## LOGIC
+        /// - the method redeems the receipt using the finish_unstake method, so the redemption time is checked
+        /// - the method stakes the unstaked tokens using the stake method, treating them as a new stake
+        pub fn finish_unstake_and_restake(
+            &mut self,
+            receipt: Bucket,
+            id_proof: Option<Proof>,
+        ) -> (Option<Bucket>, Option<Bucket>) {
+            let unstaked_tokens: Bucket = self.finish_unstake(receipt);
+            self.stake(unstaked_tokens, id_proof)
+        }
+
+        /// This method unstakes tokens without unstake delay, against a penalty that benefits the remaining stakers
+        ///
+        /// ## INPUT
+        /// - `id_proof`: the proof of the staking ID
+        /// - `amount`: the amount of pool tokens to unstake
+        /// - `penalty_bps`: the highest penalty the caller accepts, in basis points (1/10000) of the unstaked tokens
+        ///
+        /// ## OUTPUT
+        /// - the unstaked tokens minus the penalty
+        ///
+        /// ## LOGIC
+        /// - the method checks whether emergency unstaking is enabled and the penalty doesn't exceed the accepted maximum
+        /// - the method requests an unstake using the start_unstake method, so the staked tokens can't be locked, voting or delegating
+        /// - the method burns the unstake receipt right away, skipping the unstake delay
+        /// - the method deposits the penalty into the mother token pool, raising the value of the remaining pool tokens
+        /// - if the caller was the last staker, no pool tokens remain to benefit, so the penalty is put into the reward vault instead
+        /// - the method returns the rest of the unstaked tokens
+        pub fn emergency_unstake(
+            &mut self,
+            id_proof: NonFungibleProof,
+            amount: Decimal,
+            penalty_bps: u16,
+        ) -> Bucket {
+            let penalty: Decimal = self.emergency_penalty;
+            assert!(penalty > dec!(0), "Emergency unstaking is disabled.");
+            assert!(
+                penalty <= Decimal::from(penalty_bps) / dec!(10000),
+                "Emergency unstake penalty exceeds the maximum accepted penalty."
+            );
+
+            let receipt: Bucket = self.start_unstake(id_proof, amount, false);
+            let receipt_data = receipt
+                .as_non_fungible()
+                .non_fungible::<UnstakeReceipt>()
+                .data();
+            receipt.burn();
+
+            let mut unstaked_tokens: Bucket = self.unstaked_mother_tokens.take(receipt_data.amount);
+            let penalty_tokens: Bucket = unstaked_tokens.take_advanced(
+                receipt_data.amount * penalty,
+                WithdrawStrategy::Rounded(RoundingMode::ToPositiveInfinity),
+            );
+            if self.stakable_unit.vault.amount() > dec!(0) {
+                self.mother_pool.protected_deposit(penalty_tokens);
+            } else {
+                self.reward_vault.put(penalty_tokens.as_fungible());
+            }
+
+            unstaked_tokens
+        }
+
+        /// This method claims the secondary rewards earned by a staking ID
+        ///
+        /// ## INPUT
+        /// - `id_proof`: the proof of the staking ID
+        ///
+        /// ## OUTPUT
+        /// - the claimed secondary rewards
+        ///
+        /// ## LOGIC
+        /// - the method checks the staking ID
+        /// - the method checks whether a secondary reward is set
+        /// - the method takes a snapshot of the secondary rewards earned by the staking ID, using the snapshot_secondary_rewards method
+        /// - the method resets the unclaimed secondary rewards of the staking ID and returns them
+        pub fn claim_secondary_rewards(&mut self, id_proof: NonFungibleProof) -> FungibleBucket {
+            let id_proof =
+                id_proof.check_with_message(self.id_manager.address(), "Invalid Id supplied!");
+            let id = id_proof.non_fungible::<Id>().local_id().clone();
+            let id_data: Id = self.id_manager.get_non_fungible_data(&id);
+
+            assert!(
+                self.secondary_reward.is_some(),
+                "No secondary reward is set."
+            );
+
+            self.snapshot_secondary_rewards(&id, id_data.pool_amount_staked);
+            let mut snapshot: SecondaryRewardSnapshot =
+                self.secondary_reward_snapshots.get(&id).unwrap().clone();
+            let unclaimed: Decimal = snapshot.unclaimed;
+            snapshot.unclaimed = dec!(0);
+            self.secondary_reward_snapshots.insert(id, snapshot);
+
+            self.secondary_reward
+                .as_mut()
+                .unwrap()
+                .claimable_vault
+                .take_advanced(
+                    unclaimed,
+                    WithdrawStrategy::Rounded(RoundingMode::ToNegativeInfinity),
+                )
+        }
+
+        /// This method takes staked tokens out of a staking ID without unstake delay, so the DAO can migrate them to the Incentives component
+        ///
+        /// ## INPUT
+        /// - `id_proof`: the proof of the staking ID
+        /// - `amount`: the amount of pool tokens to take out
+        ///
+        /// ## OUTPUT
+        /// - the unstaked mother tokens
+        ///
+        /// ## LOGIC
+        /// - the method requests an unstake using the start_unstake method, so the staked tokens can't be locked, voting or delegating
+        /// - the method burns the unstake receipt right away, skipping the unstake delay
+        /// - the method returns the unstaked tokens
+        pub fn migrate_stake(&mut self, id_proof: NonFungibleProof, amount: Decimal) -> Bucket {
+            let receipt: Bucket = self.start_unstake(id_proof, amount, false);
+            let receipt_data = receipt
+                .as_non_fungible()
+                .non_fungible::<UnstakeReceipt>()
+                .data();
+
+            receipt.burn();
+            self.unstaked_mother_tokens.take(receipt_data.amount)
+        }
+
         /// This method creates a new staking ID
         ///
         /// ## INPUT
@@ -507,6 +963,10 @@ mod staking {
                 locked_until: None,
                 voting_until: None,
                 undelegating_until: None,
+                warmup_tranches: vec![],
+                last_voted_at: None,
+                lock_reward_granted: dec!(0),
+                created_at: Clock::current_time_rounded_to_seconds(),
             };
 
             let id: Bucket = self
@@ -519,7 +979,7 @@ mod staking {
         /// This method stakes tokens to a staking ID
         ///
         /// ## INPUT
-        /// - `stake_bucket`: bucket containing either the tokens to stake or a stake transfer receipt
+        /// - `stake_bucket`: bucket containing either the tokens to stake (mother tokens or mother pool tokens) or a stake transfer receipt
         /// - `id_proof`: the proof of the staking ID
         ///
         /// ## OUTPUT
@@ -527,42 +987,48 @@ mod staking {
         ///
         /// ## LOGIC
         /// - the method checks whether a staking ID is supplied, if not, it creates one
-        /// - the method passes the id and stake_bucket to the stake_advanced method
-        /// - if the stake_advanced method returns a lock_rewards bucket, the method passes this bucket and the id to the stake_advanced method again, this time with the with_lock_rewards parameter set to false
+        /// - the method stakes the tokens to the ID using the stake_to_id method
+        /// - staking to an ID that is currently voting is allowed, but vote weight is recorded at the time of voting, so the new stake does not count towards votes already cast
+        /// - the new stake is subject to the voting lock of the ID, so it can't be unstaked until the voting lock ends
         pub fn stake(
             &mut self,
             stake_bucket: Bucket,
             id_proof: Option<Proof>,
         ) -> (Option<Bucket>, Option<Bucket>) {
-            let id: NonFungibleLocalId;
-            let mut id_bucket: Option<Bucket> = None;
+            let (id, id_bucket): (NonFungibleLocalId, Option<Bucket>) = self.resolve_id(id_proof);
+            let lock_rewards_empty: Option<Bucket> = self.stake_to_id(stake_bucket, &id);
 
-            if let Some(id_proof) = id_proof {
-                let id_proof =
-                    id_proof.check_with_message(self.id_manager.address(), "Invalid Id supplied!");
-                id = id_proof
-                    .as_non_fungible()
-                    .non_fungible::<Id>()
-                    .local_id()
-                    .clone();
-            } else {
-                let new_id: Bucket = self.create_id();
-                id = new_id
-                    .as_non_fungible()
-                    .non_fungible::<Id>()
-                    .local_id()
-                    .clone();
-                id_bucket = Some(new_id);
-            }
+            (id_bucket, lock_rewards_empty)
+        }
 
-            let lock_rewards: Option<Bucket> = self.stake_advanced(stake_bucket, &id, true);
-            if let Some(lock_rewards) = lock_rewards {
-                let lock_rewards_empty: Option<Bucket> =
-                    self.stake_advanced(lock_rewards, &id, false);
-                (id_bucket, lock_rewards_empty)
-            } else {
-                (id_bucket, None)
-            }
+        /// This method stakes tokens to a staking ID and locks the full position in one go
+        ///
+        /// ## INPUT
+        /// - `stake_bucket`: bucket containing either the tokens to stake (mother tokens or mother pool tokens) or a stake transfer receipt
+        /// - `id_proof`: the proof of the staking ID
+        /// - `days_to_lock`: the duration for which the tokens are locked in days
+        /// - `for_reward`: whether the lock is rewarded
+        ///
+        /// ## OUTPUT
+        /// - an optional staking ID (if none was provided)
+        /// - an optional empty bucket, left over after the lock rewards for staking to an already locked ID were restaked to it
+        ///
+        /// ## LOGIC
+        /// - the method checks whether a staking ID is supplied, if not, it creates one
+        /// - the method stakes the tokens to the ID using the stake_to_id method
+        /// - the method locks all tokens staked by the ID using the lock_id method, restaking the lock rewards
+        pub fn stake_and_lock(
+            &mut self,
+            stake_bucket: Bucket,
+            id_proof: Option<Proof>,
+            days_to_lock: i64,
+            for_reward: bool,
+        ) -> (Option<Bucket>, Option<Bucket>) {
+            let (id, id_bucket): (NonFungibleLocalId, Option<Bucket>) = self.resolve_id(id_proof);
+            let lock_rewards_empty: Option<Bucket> = self.stake_to_id(stake_bucket, &id);
+            self.lock_id(&id, days_to_lock, for_reward);
+
+            (id_bucket, lock_rewards_empty)
         }
 
         /// This method delegates voting power to another staking ID, making the other ID able to vote with your stake, without getting staking rewards
@@ -619,6 +1085,7 @@ mod staking {
 
             id_data.delegating_voting_power_to = Some(delegate_id.clone());
             delegate_id_data.pool_amount_delegated_to_me += id_data.pool_amount_staked;
+            self.total_pool_amount_delegated += id_data.pool_amount_staked;
 
             self.id_manager.update_non_fungible_data(
                 &id,
@@ -657,6 +1124,7 @@ mod staking {
                 let mut delegate_id_data: Id = self.id_manager.get_non_fungible_data(&delegate_id);
 
                 delegate_id_data.pool_amount_delegated_to_me -= id_data.pool_amount_staked;
+                self.total_pool_amount_delegated -= id_data.pool_amount_staked;
                 id_data.delegating_voting_power_to = None;
                 id_data.undelegating_until = delegate_id_data.voting_until;
 
@@ -691,9 +1159,7 @@ mod staking {
         ///
         /// ## LOGIC
         /// - the method checks the staking ID
-        /// - the method checks whether the staking ID tokens are already locked
-        /// - the method locks the tokens by updating the staking ID
-        /// - the method calculates and returns the rewards for locking the tokens
+        /// - the method locks the tokens using the lock_id method
         pub fn lock_stake(
             &mut self,
             id_proof: NonFungibleProof,
@@ -703,7 +1169,29 @@ mod staking {
             let id_proof =
                 id_proof.check_with_message(self.id_manager.address(), "Invalid Id supplied!");
             let id = id_proof.non_fungible::<Id>().local_id().clone();
-            let mut id_data: Id = self.id_manager.get_non_fungible_data(&id);
+
+            self.lock_id(&id, days_to_lock, for_reward);
+        }
+
+        /// This method locks the tokens staked by a staking ID for a certain duration and gives rewards for locking them
+        ///
+        /// ## INPUT
+        /// - `id`: the staking ID
+        /// - `days_to_lock`: the duration for which the tokens are locked in days
+        /// - `for_reward`: whether the lock is rewarded
+        ///
+        /// ## OUTPUT
+        /// - none
+        ///
+        /// ## LOGIC
+        /// - the method checks whether the staking ID tokens are already locked, if so the lock is extended from the existing lock
+        /// - regardless of earlier relocks, the new lock can never end later than the maximum lock duration from now
+        /// - the method locks the tokens by updating the staking ID
+        /// - the method calculates the rewards for locking the tokens and stakes them to the ID
+        /// - the method records the lock rewards granted for the current lock, starting from zero if the earlier lock has expired
+        fn lock_id(&mut self, id: &NonFungibleLocalId, days_to_lock: i64, for_reward: bool) {
+            let mut id_data: Id = self.id_manager.get_non_fungible_data(id);
+            assert!(days_to_lock > 0, "Days to lock must be positive.");
 
             let real_amount_staked = self.get_real_amount(id_data.pool_amount_staked);
             let new_lock: Instant;
@@ -711,6 +1199,17 @@ mod staking {
             let max_lock: Instant = Clock::current_time_rounded_to_seconds()
                 .add_days(stakable.lock.max_duration)
                 .unwrap();
+            let lock_active: bool = id_data.locked_until.map_or(false, |locked_until| {
+                locked_until.compare(
+                    Clock::current_time_rounded_to_seconds(),
+                    TimeComparisonOperator::Gt,
+                )
+            });
+            let mut lock_reward_granted: Decimal = if lock_active {
+                id_data.lock_reward_granted
+            } else {
+                dec!(0)
+            };
 
             if let Some(locked_until) = id_data.locked_until {
                 if locked_until.compare(
@@ -737,7 +1236,7 @@ mod staking {
             id_data.locked_until = Some(new_lock);
 
             self.id_manager
-                .update_non_fungible_data(&id, "locked_until", id_data.locked_until);
+                .update_non_fungible_data(id, "locked_until", id_data.locked_until);
 
             if for_reward {
                 let lock_reward: Bucket = self
@@ -748,8 +1247,16 @@ mod staking {
                             - real_amount_staked,
                     )
                     .into();
-                self.stake_advanced(lock_reward, &id, false);
+                self.total_rewards_distributed += lock_reward.amount();
+                lock_reward_granted += lock_reward.amount();
+                self.stake_advanced(lock_reward, id, false);
             }
+
+            self.id_manager.update_non_fungible_data(
+                id,
+                "lock_reward_granted",
+                lock_reward_granted,
+            );
         }
 
         /// This method unlocks locked (and, naturally, staked) tokens for a certain duration against payment that's (probably) worth more than the locking reward
@@ -765,8 +1272,10 @@ mod staking {
         /// ## LOGIC
         /// - the method checks the staking ID
         /// - the method calculates the unlock fee
+        /// - if a clawback penalty is set, the fee is at least the lock rewards granted for the unlocked days plus the penalty, so unlocking early is never profitable
+        ///    - the lock rewards granted for the unlocked days are the lock rewards granted for the current lock, pro rata to the share of the remaining lock being unlocked
         /// - the method checks whether the payment is enough, takes it, and stores it in the reward vault
-        /// - the method updates the locking time of the tokens
+        /// - the method updates the locking time of the tokens and the lock rewards granted for the remaining lock
         /// - the method returns leftover unlock fee
 
         pub fn unlock_stake(
@@ -782,9 +1291,26 @@ mod staking {
             let mut id_data: Id = self.id_manager.get_non_fungible_data(&id);
 
             let real_amount_staked = self.get_real_amount(id_data.pool_amount_staked);
-            let necessary_payment = (stakable.lock.unlock_payment.checked_powi(days_to_unlock).unwrap()
+            let mut necessary_payment = (stakable.lock.unlock_payment.checked_powi(days_to_unlock).unwrap()
                     * real_amount_staked)
                     - real_amount_staked;
+
+            let mut clawback: Decimal = dec!(0);
+            if let Some(penalty) = self.lock_clawback_penalty {
+                let seconds_locked: i64 = id_data
+                    .locked_until
+                    .expect("Tokens not locked.")
+                    .seconds_since_unix_epoch
+                    - Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+                if seconds_locked > 0 {
+                    clawback = (id_data.lock_reward_granted
+                        * Decimal::from(days_to_unlock * 86400)
+                        / Decimal::from(seconds_locked))
+                    .min(id_data.lock_reward_granted);
+                }
+                necessary_payment = necessary_payment.max(clawback * (dec!(1) + penalty));
+            }
+
             assert!(
                 payment.amount() >= necessary_payment,
                 "Payment is not enough to unlock the tokens."
@@ -812,10 +1338,37 @@ mod staking {
 
             self.id_manager
                 .update_non_fungible_data(&id, "locked_until", id_data.locked_until);
+            self.id_manager.update_non_fungible_data(
+                &id,
+                "lock_reward_granted",
+                id_data.lock_reward_granted - clawback,
+            );
 
             payment
         }
 
+        /// This method gets the most recent share price snapshots
+        ///
+        /// ## INPUT
+        /// - `limit`: the maximum amount of snapshots to return
+        ///
+        /// ## OUTPUT
+        /// - the snapshots as (time, value of a single pool token), most recent first
+        ///
+        /// ## LOGIC
+        /// - the method walks back through the ring buffer, starting at the latest snapshot
+        pub fn get_share_price_history(&self, limit: u64) -> Vec<(Instant, Decimal)> {
+            let available: u64 = self.share_price_counter.min(SHARE_PRICE_HISTORY_SIZE);
+            let mut history: Vec<(Instant, Decimal)> = Vec::new();
+
+            for i in 1..(limit.min(available) + 1) {
+                let index: u64 = (self.share_price_counter - i) % SHARE_PRICE_HISTORY_SIZE;
+                history.push(*self.share_price_history.get(&index).unwrap());
+            }
+
+            history
+        }
+
         //===================================================================
         //                          ADMIN METHODS
         //===================================================================
@@ -825,21 +1378,227 @@ mod staking {
             self.reward_vault.put(bucket.as_fungible());
         }
 
-        /// Method removes tokens from the reward vault
-        pub fn remove_tokens(&mut self, amount: Decimal) -> Bucket {
-            self.reward_vault.take(amount).into()
+        /// This method freezes reward accrual, for instance while migrating to a new staking component
+        ///
+        /// ## INPUT
+        /// - none
+        ///
+        /// ## OUTPUT
+        /// - none
+        ///
+        /// ## LOGIC
+        /// - the method updates the period, distributing the rewards accrued up to now
+        /// - the method records the moment of freezing, after which updating the period distributes nothing
+        pub fn freeze_rewards(&mut self) {
+            assert!(
+                self.rewards_frozen_at.is_none(),
+                "Rewards are already frozen."
+            );
+            self.update_period();
+            self.rewards_frozen_at = Some(Clock::current_time_rounded_to_seconds());
         }
 
-        /// Method sets the unstake delay, the amount of days a user has to wait before claiming unstaked tokens
-        pub fn set_unstake_delay(&mut self, new_delay: i64) {
-            assert!(new_delay > 0, "Unstake delay must be positive.");
-            assert!(
+        /// This method unfreezes reward accrual
+        ///
+        /// ## INPUT
+        /// - none
+        ///
+        /// ## OUTPUT
+        /// - none
+        ///
+        /// ## LOGIC
+        /// - the method moves the last update forward by the time rewards were frozen, so no rewards accrue over the frozen window
+        /// - the method moves the last update of the secondary rewards to now as well, without distributing them
+        /// - the method clears the moment of freezing, after which updating the period distributes rewards again
+        pub fn unfreeze_rewards(&mut self) {
+            self.accrue_secondary_rewards();
+            let frozen_at: Instant = self
+                .rewards_frozen_at
+                .take()
+                .expect("Rewards are not frozen.");
+            let seconds_frozen: i64 = Clock::current_time_rounded_to_seconds()
+                .seconds_since_unix_epoch
+                - frozen_at.seconds_since_unix_epoch;
+            self.last_update = self.last_update.add_seconds(seconds_frozen).unwrap();
+        }
+
+        /// Method removes tokens from the reward vault
+        pub fn remove_tokens(&mut self, amount: Decimal) -> Bucket {
+            self.reward_vault.take(amount).into()
+        }
+
+        /// This method sets the secondary reward token and the amount of it streamed to stakers per day
+        ///
+        /// ## INPUT
+        /// - `address`: the address of the secondary reward token
+        /// - `reward_amount`: the amount of secondary reward tokens streamed per day
+        ///
+        /// ## OUTPUT
+        /// - none
+        ///
+        /// ## LOGIC
+        /// - the method distributes the secondary rewards accrued up to now at the old reward amount
+        /// - if no secondary reward is set yet, the method creates it for the supplied token
+        /// - if a secondary reward is set, the method checks the token is the same and updates the reward amount
+        pub fn set_secondary_reward(&mut self, address: ResourceAddress, reward_amount: Decimal) {
+            assert!(reward_amount >= dec!(0), "Reward amount can't be negative.");
+            self.accrue_secondary_rewards();
+
+            match self.secondary_reward.as_mut() {
+                Some(secondary_reward) => {
+                    assert!(
+                        secondary_reward.reward_vault.resource_address() == address,
+                        "Secondary reward token can't be changed."
+                    );
+                    secondary_reward.reward_amount = reward_amount;
+                }
+                None => {
+                    self.secondary_reward = Some(SecondaryReward {
+                        reward_vault: FungibleVault::new(address),
+                        claimable_vault: FungibleVault::new(address),
+                        reward_amount,
+                        reward_per_pool_token: dec!(0),
+                        last_update: Clock::current_time_rounded_to_seconds(),
+                    });
+                }
+            }
+        }
+
+        /// Method puts tokens into the secondary reward vault
+        pub fn put_secondary_tokens(&mut self, bucket: Bucket) {
+            self.secondary_reward
+                .as_mut()
+                .expect("No secondary reward is set.")
+                .reward_vault
+                .put(bucket.as_fungible());
+        }
+
+        /// Method removes tokens from the secondary reward vault, after distributing the secondary rewards accrued up to now
+        pub fn remove_secondary_tokens(&mut self, amount: Decimal) -> Bucket {
+            self.accrue_secondary_rewards();
+            self.secondary_reward
+                .as_mut()
+                .expect("No secondary reward is set.")
+                .reward_vault
+                .take(amount)
+                .into()
+        }
+
+        /// This method winds down the staking rewards, ending all emissions
+        ///
+        /// ## INPUT
+        /// - none
+        ///
+        /// ## OUTPUT
+        /// - the remaining rewards
+        ///
+        /// ## LOGIC
+        /// - the method updates the period, streaming the rewards accrued up to now to the stakers
+        /// - the method sets the reward amount to zero and clears all emission milestones, so emissions can't restart
+        /// - the method sets the lock payment to 1, so locking and staking to a locked ID don't take lock rewards from the emptied reward vault
+        /// - the method takes all remaining rewards from the reward vault and returns them
+        /// - the method emits a RewardsWoundDownEvent
+        pub fn wind_down_rewards(&mut self) -> Bucket {
+            self.update_period();
+
+            self.stakable_unit.reward_amount = dec!(0);
+            self.reward_halvings.clear();
+            self.stakable_unit.lock.payment = dec!(1);
+
+            let remaining_rewards: Bucket = self.reward_vault.take_all().into();
+
+            Runtime::emit_event(RewardsWoundDownEvent {
+                returned_amount: remaining_rewards.amount(),
+                total_distributed: self.total_rewards_distributed,
+            });
+
+            remaining_rewards
+        }
+
+        /// Method sets the unstake delay, the amount of days a user has to wait before claiming unstaked tokens
+        pub fn set_unstake_delay(&mut self, new_delay: i64) {
+            assert!(new_delay > 0, "Unstake delay must be positive.");
+            assert!(
                 new_delay <= self.stakable_unit.unstake_delay * 2 + 1,
                 "Unstake delay cannot be more than twice + 1 the current delay."
             );
             self.stakable_unit.unstake_delay = new_delay;
         }
 
+        /// Method accelerates the unstake receipts minted before this call, making them redeemable from the new redemption time at the latest
+        pub fn accelerate_unstake_receipts(&mut self, new_redemption_time: Instant) {
+            self.unstake_accelerations
+                .push((self.unstake_receipt_counter, new_redemption_time));
+        }
+
+        /// Method sets the reward paid to callers of rewarded_update_period
+        pub fn set_caller_reward(&mut self, caller_reward: Decimal) {
+            assert!(caller_reward >= dec!(0), "Caller reward can't be negative.");
+            self.caller_reward = caller_reward;
+        }
+
+        /// Method sets the warm-up, the amount of days newly staked tokens don't earn rewards
+        pub fn set_warmup_days(&mut self, warmup_days: i64) {
+            assert!(warmup_days >= 0, "Warm-up can't be negative.");
+            self.stakable_unit.warmup_days = warmup_days;
+        }
+
+        /// Method sets the penalty on top of the lock rewards clawed back when unlocking early, disabling the clawback if none
+        pub fn set_lock_clawback(&mut self, penalty: Option<Decimal>) {
+            if let Some(penalty) = penalty {
+                assert!(penalty >= dec!(0), "Clawback penalty can't be negative.");
+            }
+            self.lock_clawback_penalty = penalty;
+        }
+
+        /// Method sets the penalty for unstaking without unstake delay, disabling emergency unstaking if zero
+        pub fn set_emergency_penalty(&mut self, penalty: Decimal) {
+            assert!(
+                penalty >= dec!(0) && penalty <= dec!(1),
+                "Emergency unstake penalty must be between 0 and 1."
+            );
+            self.emergency_penalty = penalty;
+        }
+
+        /// Method sets the transfer receipt delay, the amount of days before a stake transfer receipt can be redeemed
+        pub fn set_transfer_receipt_delay(&mut self, new_delay: i64) {
+            assert!(new_delay >= 0, "Transfer receipt delay can't be negative.");
+            self.stakable_unit.transfer_receipt_delay = new_delay;
+        }
+
+        /// Method sets the vote decay, scaling down the vote power of staking IDs that haven't voted for a while (None to disable)
+        pub fn set_vote_decay(&mut self, vote_decay: Option<VoteDecay>) {
+            if let Some(vote_decay) = &vote_decay {
+                assert!(vote_decay.period_days > 0, "Decay period must be positive.");
+                assert!(
+                    vote_decay.factor > dec!(0) && vote_decay.factor <= dec!(1),
+                    "Decay factor must be between 0 and 1."
+                );
+                assert!(
+                    vote_decay.floor > dec!(0) && vote_decay.floor <= dec!(1),
+                    "Decay floor must be between 0 and 1."
+                );
+            }
+            self.vote_decay = vote_decay;
+        }
+
+        /// Method sets the emission milestones, at which the reward amount changes once the total rewards distributed cross the threshold
+        pub fn set_reward_halvings(&mut self, reward_halvings: Vec<(Decimal, Decimal)>) {
+            let mut last_threshold: Decimal = self.total_rewards_distributed;
+            for (threshold, reward_amount) in reward_halvings.iter() {
+                assert!(
+                    *threshold > last_threshold,
+                    "Thresholds must be ascending and above the rewards distributed to date."
+                );
+                assert!(
+                    *reward_amount >= dec!(0),
+                    "Reward amount can't be negative."
+                );
+                last_threshold = *threshold;
+            }
+            self.reward_halvings = reward_halvings;
+        }
+
         /// Method edits a stakable resource
         pub fn edit_stakable(
             &mut self,
@@ -858,6 +1617,49 @@ mod staking {
             self.stakable_unit.lock = lock;
         }
 
+        /// This method shortens the lock of a staking ID without payment, for emergencies approved by governance
+        ///
+        /// ## INPUT
+        /// - `id`: the staking ID
+        /// - `days_to_unlock`: the duration that the lock is shortened by in days
+        ///
+        /// ## OUTPUT
+        /// - none
+        ///
+        /// ## LOGIC
+        /// - the method checks whether the staking ID is locked
+        /// - the method checks whether the staking ID is not vote-locked, as active votes can't be bypassed
+        /// - the method shortens the lock, but not further than the current time
+        /// - the method updates the staking ID and emits a ForceUnlockEvent
+        pub fn force_unlock(&mut self, id: NonFungibleLocalId, days_to_unlock: i64) {
+            assert!(days_to_unlock > 0, "Days to unlock must be positive.");
+            let id_data: Id = self.id_manager.get_non_fungible_data(&id);
+
+            let locked_until: Instant = id_data.locked_until.expect("Tokens not locked.");
+
+            if let Some(voting_until) = id_data.voting_until {
+                assert!(
+                    Clock::current_time_is_at_or_after(voting_until, TimePrecision::Second),
+                    "Cannot force unlock tokens that are locked by an active vote."
+                );
+            }
+
+            let now: Instant = Clock::current_time_rounded_to_seconds();
+            let mut new_lock: Instant = locked_until.add_days(-days_to_unlock).unwrap();
+            if new_lock.compare(now, TimeComparisonOperator::Lt) {
+                new_lock = now;
+            }
+
+            self.id_manager
+                .update_non_fungible_data(&id, "locked_until", Some(new_lock));
+
+            Runtime::emit_event(ForceUnlockEvent {
+                id,
+                days_unlocked: days_to_unlock,
+                locked_until: new_lock,
+            });
+        }
+
         /// This method locks staked tokens for voting
         ///
         /// ## INPUT
@@ -871,7 +1673,8 @@ mod staking {
         /// ## LOGIC
         /// - the method checks the staking ID
         /// - the method checks whether the staking ID tokens are vote-locked by (un)delegating
-        /// - the method updates the voting_until field of the staking ID appropriately
+        /// - the method calculates the vote power of the staking ID, scaled down by the vote decay if enabled
+        /// - the method updates the voting_until and last_voted_at fields of the staking ID appropriately
 
         pub fn vote(&mut self, voting_until: Instant, id: NonFungibleLocalId) -> Decimal {
             let id_data: Id = self.id_manager.get_non_fungible_data(&id);
@@ -887,8 +1690,13 @@ mod staking {
                 );
             }
 
-            let vote_power: Decimal =
-                id_data.pool_amount_staked + id_data.pool_amount_delegated_to_me;
+            let vote_power: Decimal = self.vote_power(&id_data);
+
+            self.id_manager.update_non_fungible_data(
+                &id,
+                "last_voted_at",
+                Some(Clock::current_time_rounded_to_seconds()),
+            );
 
             if id_data.voting_until.map_or(true, |voting_until_id| {
                 voting_until_id.compare(voting_until, TimeComparisonOperator::Lt)
@@ -915,21 +1723,163 @@ mod staking {
             self.reward_vault.amount()
         }
 
+        /// This method gets the upcoming emission milestones (total rewards distributed threshold, new daily reward amount)
+        pub fn get_reward_halvings(&self) -> Vec<(Decimal, Decimal)> {
+            self.reward_halvings.clone()
+        }
+
+        /// This method gets the total amount of pool units staked, the total voting power of all staking IDs
+        pub fn get_pool_amount_staked(&self) -> Decimal {
+            self.stakable_unit.pool_amount_staked
+        }
+
+        /// This method gets the total amount of rewards distributed to date
+        ///
+        /// ## INPUT
+        /// - none
+        ///
+        /// ## OUTPUT
+        /// - total amount of tokens taken from the reward_vault as rewards
+        ///
+        /// ## LOGIC
+        /// - the method returns the counter of distributed rewards, which is incremented whenever rewards leave the reward_vault
+        pub fn get_total_distributed(&self) -> Decimal {
+            self.total_rewards_distributed
+        }
+
+        /// This method gets the unstake delay, the amount of days a user has to wait before claiming unstaked tokens
+        pub fn get_unstake_delay(&self) -> i64 {
+            self.stakable_unit.unstake_delay
+        }
+
+        /// This method gets the amount and redemption time of an unstake receipt
+        ///
+        /// ## INPUT
+        /// - `local_id`: the local ID of the unstake receipt
+        ///
+        /// ## OUTPUT
+        /// - the amount of tokens redeemable with the receipt
+        /// - the time from which the receipt is redeemable
+        ///
+        /// ## LOGIC
+        /// - the method gets the receipt data from the unstake receipt manager
+        /// - the method returns the amount and the redemption time, which is moved up if the receipt has been accelerated
+        pub fn get_unstake_receipt_info(&self, local_id: NonFungibleLocalId) -> (Decimal, Instant) {
+            let receipt_data: UnstakeReceipt = self
+                .unstake_receipt_manager
+                .get_non_fungible_data(&local_id);
+
+            (
+                receipt_data.amount,
+                self.redemption_time(&local_id, receipt_data.redemption_time),
+            )
+        }
+
+        /// This method gets the amounts and redemption times of multiple unstake receipts
+        ///
+        /// ## INPUT
+        /// - `local_ids`: the local IDs of the unstake receipts
+        ///
+        /// ## OUTPUT
+        /// - the amount and redemption time of each receipt, in the order of the supplied IDs
+        ///
+        /// ## LOGIC
+        /// - the method gets the info of each receipt using the `get_unstake_receipt_info` method
+        pub fn get_unstake_receipts_info(
+            &self,
+            local_ids: Vec<NonFungibleLocalId>,
+        ) -> Vec<(Decimal, Instant)> {
+            local_ids
+                .into_iter()
+                .map(|local_id| self.get_unstake_receipt_info(local_id))
+                .collect()
+        }
+
+        /// This method gets the effective vote power of a staking ID, denominated in mother tokens
+        ///
+        /// ## INPUT
+        /// - `id`: the staking ID
+        ///
+        /// ## OUTPUT
+        /// - the vote power of the staking ID, in mother tokens
+        ///
+        /// ## LOGIC
+        /// - the method calculates the vote power of the staking ID in pool tokens, including the vote decay, like the `vote` method does
+        /// - the method converts this amount of pool tokens to mother tokens, like the governance component does when tallying votes
+        pub fn get_effective_vote_power(&self, id: NonFungibleLocalId) -> Decimal {
+            let id_data: Id = self.id_manager.get_non_fungible_data(&id);
+
+            self.get_real_amount(self.vote_power(&id_data))
+        }
+
+        /// This method gets the total voting power delegated to other staking IDs, denominated in mother tokens
+        ///
+        /// ## INPUT
+        /// - none
+        ///
+        /// ## OUTPUT
+        /// - the total delegated voting power, in mother tokens
+        ///
+        /// ## LOGIC
+        /// - the method converts the running total of delegated pool tokens, which is updated whenever voting power is (un)delegated or staked to a delegating ID, to mother tokens
+        pub fn get_total_delegated(&self) -> Decimal {
+            self.get_real_amount(self.total_pool_amount_delegated)
+        }
+
         //===================================================================
         //                          HELPER METHODS
         //===================================================================
 
+        /// This method gets the staking ID from a proof, or creates a new one if none is supplied
+        fn resolve_id(&mut self, id_proof: Option<Proof>) -> (NonFungibleLocalId, Option<Bucket>) {
+            if let Some(id_proof) = id_proof {
+                let id_proof =
+                    id_proof.check_with_message(self.id_manager.address(), "Invalid Id supplied!");
+                let id: NonFungibleLocalId = id_proof
+                    .as_non_fungible()
+                    .non_fungible::<Id>()
+                    .local_id()
+                    .clone();
+                (id, None)
+            } else {
+                let new_id: Bucket = self.create_id();
+                let id: NonFungibleLocalId = new_id
+                    .as_non_fungible()
+                    .non_fungible::<Id>()
+                    .local_id()
+                    .clone();
+                (id, Some(new_id))
+            }
+        }
+
+        /// This method stakes tokens to a staking ID.
+        ///
+        /// ## INPUT
+        /// - `stake_bucket`: bucket containing either the tokens to stake (mother tokens or mother pool tokens) or a stake transfer receipt
+        /// - `id`: the staking ID
+        ///
+        /// ## OUTPUT
+        /// - an empty bucket if lock rewards were staked
+        ///
+        /// ## LOGIC
+        /// - the method passes the id and stake_bucket to the stake_advanced method
+        /// - if the stake_advanced method returns a lock_rewards bucket, the method passes this bucket and the id to the stake_advanced method again, this time with the with_lock_rewards parameter set to false
+        fn stake_to_id(&mut self, stake_bucket: Bucket, id: &NonFungibleLocalId) -> Option<Bucket> {
+            let lock_rewards: Option<Bucket> = self.stake_advanced(stake_bucket, id, true);
+            lock_rewards.and_then(|lock_rewards| self.stake_advanced(lock_rewards, id, false))
+        }
+
         /// This method counts the staked tokens and puts them away in the staking component's vault.
         ///
         /// ## INPUT
-        /// - `stake_bucket`: the bucket of staked tokens
+        /// - `stake_bucket`: the bucket of staked mother pool tokens
         ///
         /// ## OUTPUT
         /// - the amount of staked tokens
         /// - the address of the stakable token
         ///
         /// ## LOGIC
-        /// - the method checks whether the staked token is a stakable token
+        /// - the method checks whether the staked token is the mother pool token, mother tokens have been converted to it already
         /// - the method puts the staked tokens in the staking component's vault
         /// - the method returns the amount of staked tokens and the address of the stakable token
 
@@ -937,7 +1887,7 @@ mod staking {
             let address: ResourceAddress = stake_bucket.resource_address();
             assert!(
                 address == self.pool_token_address,
-                "Token supplied does not match requested stakable token."
+                "Only mother tokens, mother pool tokens or stake transfer receipts can be staked."
             );
             let stake_amount: Decimal = stake_bucket.amount();
             self.stakable_unit.vault.put(stake_bucket);
@@ -945,6 +1895,27 @@ mod staking {
             stake_amount
         }
 
+        /// This method calculates the redemption time of an unstake receipt, moving it up to the earliest acceleration made after the receipt was minted
+        fn redemption_time(
+            &self,
+            receipt_id: &NonFungibleLocalId,
+            redemption_time: Instant,
+        ) -> Instant {
+            let mut earliest_time: Instant = redemption_time;
+
+            if let NonFungibleLocalId::Integer(receipt_number) = receipt_id {
+                for (last_receipt, accelerated_time) in self.unstake_accelerations.iter() {
+                    if receipt_number.value() <= *last_receipt
+                        && accelerated_time.compare(earliest_time, TimeComparisonOperator::Lt)
+                    {
+                        earliest_time = *accelerated_time;
+                    }
+                }
+            }
+
+            earliest_time
+        }
+
         /// This method counts the staked tokens from a transfer receipt and burns it.
         ///
         /// ## INPUT
@@ -956,11 +1927,19 @@ mod staking {
         ///
         /// ## LOGIC
         /// - the method extracts the data from the receipt
+        /// - the method checks whether the receipt can be redeemed yet
         /// - the method burns the receipt
         /// - the method returns the amount of staked tokens and the address of the stakable token
 
         fn stake_transfer_receipt(&mut self, receipt: NonFungibleBucket) -> Decimal {
             let receipt_data = receipt.non_fungible::<StakeTransferReceipt>().data();
+            assert!(
+                Clock::current_time_is_at_or_after(
+                    receipt_data.transferable_after,
+                    TimePrecision::Second
+                ),
+                "You cannot redeem this transfer receipt yet."
+            );
             let stake_amount: Decimal = receipt_data.pool_amount;
             receipt.burn();
 
@@ -970,7 +1949,7 @@ mod staking {
         /// This method stakes tokens to a staking ID
         ///
         /// ## INPUT
-        /// - `stake_bucket`: bucket containing either the tokens to stake or a stake transfer receipt
+        /// - `stake_bucket`: bucket containing either the tokens to stake (mother tokens or mother pool tokens) or a stake transfer receipt
         /// - `id_proof`: the proof of the staking ID
         /// - `with_lock_rewards`: whether to calculate lock rewards or not (lock rewards are also staked, when received through staking, and no more lock rewards should be given for that)
         ///
@@ -981,9 +1960,11 @@ mod staking {
         /// - the method checks the staking ID
         /// - the method checks if latest rewards have been claimed, if not, the method fails
         /// - the method checks whether it received tokens or a transfer receipt
-        /// - the received mother tokens are converted to mother pool tokens
+        /// - received mother tokens are converted to mother pool tokens, received mother pool tokens are staked as they are, so both count for the same value
         /// - the method adds the tokens to the internal vault, or burns the transfer receipt
-        /// - if the staked tokens are already locked, the method calculates the lock reward and returns it (if with_lock_rewards is true)
+        /// - tokens of which the warm-up has passed are settled first, using the settle_passed_warmup method
+        /// - if a warm-up is set, newly staked tokens (not transferred stake) enter the warm-up as a new tranche, leaving the warm-up of earlier tranches as it is
+        /// - if the staked tokens are already locked, the method calculates the lock reward and returns it (if with_lock_rewards is true), adding it to the lock reward granted to the staking ID so it can be clawed back on an unlock
         /// - the method updates the staking ID
         fn stake_advanced(
             &mut self,
@@ -994,14 +1975,19 @@ mod staking {
             let mut lock_reward_bucket: Option<Bucket> = None;
 
             let mut id_data: Id = self.id_manager.get_non_fungible_data(id);
+            self.snapshot_secondary_rewards(id, id_data.pool_amount_staked);
+            self.settle_passed_warmup(id, &mut id_data);
 
+            // the staked amount is recorded in pool tokens, so mother tokens are converted first
             if stake_bucket.resource_address() == self.reward_vault.resource_address() {
                 stake_bucket = self.make_mother_lsu(stake_bucket);
             }
 
             let stake_amount: Decimal;
+            let is_transfer: bool =
+                stake_bucket.resource_address() == self.stake_transfer_receipt_manager.address();
 
-            if stake_bucket.resource_address() == self.stake_transfer_receipt_manager.address() {
+            if is_transfer {
                 stake_amount = self.stake_transfer_receipt(stake_bucket.as_non_fungible());
             } else {
                 stake_amount = self.stake_tokens(stake_bucket);
@@ -1009,6 +1995,20 @@ mod staking {
 
             id_data.pool_amount_staked += stake_amount;
 
+            if !is_transfer && self.stakable_unit.warmup_days > 0 {
+                let real_amount: Decimal = self.get_real_amount(stake_amount);
+                id_data.warmup_tranches.push(WarmupTranche {
+                    staked_at: Clock::current_time_rounded_to_seconds(),
+                    pool_amount: stake_amount,
+                    real_amount,
+                });
+                self.id_manager.update_non_fungible_data(
+                    id,
+                    "warmup_tranches",
+                    id_data.warmup_tranches.clone(),
+                );
+            }
+
             if let Some(locked_until) = id_data.locked_until {
                 let lock_is_active = locked_until.compare(
                     Clock::current_time_rounded_to_seconds(),
@@ -1024,26 +2024,34 @@ mod staking {
                     let whole_days_to_unlock: i64 =
                         i64::try_from(full_days_to_unlock.0 / Decimal::ONE.0).unwrap();
                     let real_stake_amount = self.get_real_amount(stake_amount);
-                    lock_reward_bucket = Some(
-                        self.reward_vault
-                            .take(
-                                (self
-                                    .stakable_unit
-                                    .lock
-                                    .payment
-                                    .checked_powi(whole_days_to_unlock)
-                                    .unwrap()
-                                    * real_stake_amount)
-                                    - real_stake_amount,
-                            )
-                            .into(),
+                    let lock_reward: Bucket = self
+                        .reward_vault
+                        .take(
+                            (self
+                                .stakable_unit
+                                .lock
+                                .payment
+                                .checked_powi(whole_days_to_unlock)
+                                .unwrap()
+                                * real_stake_amount)
+                                - real_stake_amount,
+                        )
+                        .into();
+                    self.total_rewards_distributed += lock_reward.amount();
+                    id_data.lock_reward_granted += lock_reward.amount();
+                    self.id_manager.update_non_fungible_data(
+                        id,
+                        "lock_reward_granted",
+                        id_data.lock_reward_granted,
                     );
+                    lock_reward_bucket = Some(lock_reward);
                 }
             }
 
             if let Some(delegate_id) = id_data.delegating_voting_power_to {
                 let mut delegate_id_data: Id = self.id_manager.get_non_fungible_data(&delegate_id);
                 delegate_id_data.pool_amount_delegated_to_me += stake_amount;
+                self.total_pool_amount_delegated += stake_amount;
                 self.id_manager.update_non_fungible_data(
                     &delegate_id,
                     "pool_amount_delegated_to_me",
@@ -1062,11 +2070,200 @@ mod staking {
             lock_reward_bucket
         }
 
+        /// This method calculates the vote power of a staking ID in pool tokens
+        ///
+        /// ## INPUT
+        /// - `id_data`: the data of the staking ID
+        ///
+        /// ## OUTPUT
+        /// - the vote power of the staking ID, in pool tokens
+        ///
+        /// ## LOGIC
+        /// - the method adds the pool tokens staked by the ID to the pool tokens delegated to it
+        /// - if vote decay is enabled, the vote power is scaled down for every full period passed since the ID last voted, or since it was created if it never voted, never below the floor
+        fn vote_power(&self, id_data: &Id) -> Decimal {
+            let mut vote_power: Decimal =
+                id_data.pool_amount_staked + id_data.pool_amount_delegated_to_me;
+
+            if let Some(vote_decay) = &self.vote_decay {
+                let active_at: Instant = id_data.last_voted_at.unwrap_or(id_data.created_at);
+                let inactive_periods: i64 = (Clock::current_time_rounded_to_seconds()
+                    .seconds_since_unix_epoch
+                    - active_at.seconds_since_unix_epoch)
+                    / (vote_decay.period_days * 86400);
+                if inactive_periods > 0 {
+                    let multiplier: Decimal = vote_decay
+                        .factor
+                        .checked_powi(inactive_periods)
+                        .unwrap()
+                        .max(vote_decay.floor);
+                    vote_power *= multiplier;
+                }
+            }
+
+            vote_power
+        }
+
         /// This method converts the reward token to an LSU so you don't have to claim rewards manually
         fn make_mother_lsu(&mut self, stake_bucket: Bucket) -> Bucket {
             self.mother_pool.contribute(stake_bucket)
         }
 
+        /// This method checks whether tokens recently staked to a staking ID are still in their warm-up
+        fn is_warming_up(&self, id_data: &Id) -> bool {
+            id_data
+                .warmup_tranches
+                .iter()
+                .any(|tranche| !self.warmup_has_passed(tranche))
+        }
+
+        /// This method checks whether the warm-up of a tranche of staked tokens has passed
+        fn warmup_has_passed(&self, tranche: &WarmupTranche) -> bool {
+            Clock::current_time_is_at_or_after(
+                tranche
+                    .staked_at
+                    .add_days(self.stakable_unit.warmup_days)
+                    .unwrap(),
+                TimePrecision::Second,
+            )
+        }
+
+        /// This method settles the passed warm-ups of a staking ID, forfeiting the rewards its tokens earned while warming up
+        ///
+        /// ## INPUT
+        /// - `id`: the staking ID
+        /// - `id_data`: the data of the staking ID
+        ///
+        /// ## OUTPUT
+        /// - none
+        ///
+        /// ## LOGIC
+        /// - every tranche of which the warm-up has passed is settled, the others stay in warm-up
+        /// - the value of a pool token at the end of the warm-up is taken from the share price history, using the share_price_at method
+        /// - the pool tokens that grew on top of the staked value until the end of the warm-up are forfeited, rewards earned after the warm-up are kept
+        /// - the forfeited pool tokens are redeemed and put back into the reward vault, using the forfeit_pool_tokens method
+        /// - the forfeited pool tokens are removed from the staked amount of the staking ID, and from the amount delegated to its delegate
+        fn settle_passed_warmup(&mut self, id: &NonFungibleLocalId, id_data: &mut Id) {
+            let (passed, warming): (Vec<WarmupTranche>, Vec<WarmupTranche>) = id_data
+                .warmup_tranches
+                .iter()
+                .cloned()
+                .partition(|tranche| self.warmup_has_passed(tranche));
+
+            if passed.is_empty() {
+                return;
+            }
+
+            let mut forfeit_amount: Decimal = dec!(0);
+            for tranche in passed {
+                let warmup_end: Instant = tranche
+                    .staked_at
+                    .add_days(self.stakable_unit.warmup_days)
+                    .unwrap();
+                if let Some(share_price) = self.share_price_at(warmup_end) {
+                    forfeit_amount +=
+                        (tranche.pool_amount - tranche.real_amount / share_price).max(dec!(0));
+                }
+            }
+
+            id_data.warmup_tranches = warming;
+            self.id_manager.update_non_fungible_data(
+                id,
+                "warmup_tranches",
+                id_data.warmup_tranches.clone(),
+            );
+
+            if forfeit_amount == dec!(0) {
+                return;
+            }
+
+            self.forfeit_pool_tokens(forfeit_amount);
+
+            id_data.pool_amount_staked -= forfeit_amount;
+            self.stakable_unit.pool_amount_staked -= forfeit_amount;
+            self.id_manager.update_non_fungible_data(
+                id,
+                "pool_amount_staked",
+                id_data.pool_amount_staked,
+            );
+
+            if let Some(delegate_id) = id_data.delegating_voting_power_to.clone() {
+                let mut delegate_id_data: Id = self.id_manager.get_non_fungible_data(&delegate_id);
+                delegate_id_data.pool_amount_delegated_to_me -= forfeit_amount;
+                self.total_pool_amount_delegated -= forfeit_amount;
+                self.id_manager.update_non_fungible_data(
+                    &delegate_id,
+                    "pool_amount_delegated_to_me",
+                    delegate_id_data.pool_amount_delegated_to_me,
+                );
+            }
+        }
+
+        /// This method forfeits the rewards earned by unstaked tokens that were still in their warm-up
+        ///
+        /// ## INPUT
+        /// - `id`: the staking ID
+        /// - `id_data`: the data of the staking ID
+        /// - `unstake_amount`: the amount of pool tokens unstaked
+        ///
+        /// ## OUTPUT
+        /// - the amount of pool tokens forfeited
+        ///
+        /// ## LOGIC
+        /// - unstaked tokens are taken from the tokens in warm-up first, starting with the most recently staked tranche
+        /// - the pool tokens representing the rewards earned by these tokens are redeemed and put back into the reward vault, using the forfeit_pool_tokens method
+        /// - the warm-up tranches of the staking ID are updated
+        fn forfeit_warmup_rewards(
+            &mut self,
+            id: &NonFungibleLocalId,
+            id_data: &mut Id,
+            unstake_amount: Decimal,
+        ) -> Decimal {
+            let share_price: Decimal = self.get_real_amount(dec!(1));
+            let mut remaining: Decimal = unstake_amount;
+            let mut forfeit_amount: Decimal = dec!(0);
+
+            while remaining > dec!(0) {
+                let tranche: &mut WarmupTranche = match id_data.warmup_tranches.last_mut() {
+                    Some(tranche) => tranche,
+                    None => break,
+                };
+                let warm_pool_amount: Decimal = remaining.min(tranche.pool_amount);
+                let warm_real_amount: Decimal =
+                    tranche.real_amount * warm_pool_amount / tranche.pool_amount;
+                forfeit_amount += (warm_pool_amount - warm_real_amount / share_price).max(dec!(0));
+
+                tranche.pool_amount -= warm_pool_amount;
+                tranche.real_amount -= warm_real_amount;
+                remaining -= warm_pool_amount;
+                if tranche.pool_amount == dec!(0) {
+                    id_data.warmup_tranches.pop();
+                }
+            }
+
+            self.id_manager.update_non_fungible_data(
+                id,
+                "warmup_tranches",
+                id_data.warmup_tranches.clone(),
+            );
+
+            if forfeit_amount > dec!(0) {
+                self.forfeit_pool_tokens(forfeit_amount);
+            }
+
+            forfeit_amount
+        }
+
+        /// This method redeems forfeited pool tokens and puts the mother tokens back into the reward vault
+        fn forfeit_pool_tokens(&mut self, pool_amount: Decimal) {
+            let forfeited_tokens: Decimal = self.unmake_mother_lsu(pool_amount);
+            self.reward_vault.put(
+                self.unstaked_mother_tokens
+                    .take(forfeited_tokens)
+                    .as_fungible(),
+            );
+        }
+
         /// This method converts the LSU back into a fungible token so you can claim rewards manually
         fn unmake_mother_lsu(&mut self, amount: Decimal) -> Decimal {
             let unstake_bucket: Bucket = self.stakable_unit.vault.take(amount);
@@ -1079,5 +2276,148 @@ mod staking {
         pub fn get_real_amount(&self, amount: Decimal) -> Decimal {
             self.mother_pool.get_redemption_value(amount)
         }
+
+        /// This method streams the secondary rewards accrued since their last update to the stakers
+        ///
+        /// ## INPUT
+        /// - none
+        ///
+        /// ## OUTPUT
+        /// - none
+        ///
+        /// ## LOGIC
+        /// - if no secondary reward is set, nothing happens
+        /// - if rewards are frozen or nothing is staked, nothing is distributed over the time since the last update
+        /// - otherwise, the accrued rewards (capped at what's left in the secondary reward vault) are moved to the claimable vault
+        /// - the secondary rewards per staked pool token are raised accordingly
+        /// - a new value for the last update is set
+        fn accrue_secondary_rewards(&mut self) {
+            let pool_amount_staked: Decimal = self.stakable_unit.pool_amount_staked;
+            let frozen: bool = self.rewards_frozen_at.is_some();
+
+            if let Some(secondary_reward) = self.secondary_reward.as_mut() {
+                let now: Instant = Clock::current_time_rounded_to_seconds();
+                let seconds_since_last_update: i64 = now.seconds_since_unix_epoch
+                    - secondary_reward.last_update.seconds_since_unix_epoch;
+
+                if !frozen && pool_amount_staked > dec!(0) && seconds_since_last_update > 0 {
+                    let reward: Decimal = (secondary_reward.reward_amount
+                        * Decimal::from(seconds_since_last_update)
+                        / dec!(86400))
+                    .min(secondary_reward.reward_vault.amount());
+                    secondary_reward
+                        .claimable_vault
+                        .put(secondary_reward.reward_vault.take(reward));
+                    secondary_reward.reward_per_pool_token += reward / pool_amount_staked;
+                }
+
+                secondary_reward.last_update = now;
+            }
+        }
+
+        /// This method records the secondary rewards earned by a staking ID since its last snapshot, which has to happen before its stake changes
+        ///
+        /// ## INPUT
+        /// - `id`: the staking ID
+        /// - `pool_amount_staked`: the pool tokens staked by the staking ID since its last snapshot
+        ///
+        /// ## OUTPUT
+        /// - none
+        ///
+        /// ## LOGIC
+        /// - the method distributes the secondary rewards accrued up to now
+        /// - the method adds the growth of the secondary rewards per pool token since the last snapshot times the staked pool tokens to the unclaimed rewards of the staking ID
+        /// - the method stores the new snapshot
+        fn snapshot_secondary_rewards(
+            &mut self,
+            id: &NonFungibleLocalId,
+            pool_amount_staked: Decimal,
+        ) {
+            self.accrue_secondary_rewards();
+
+            let reward_per_pool_token: Decimal = match &self.secondary_reward {
+                Some(secondary_reward) => secondary_reward.reward_per_pool_token,
+                None => return,
+            };
+
+            let mut snapshot: SecondaryRewardSnapshot = self
+                .secondary_reward_snapshots
+                .get(id)
+                .map(|snapshot| snapshot.clone())
+                .unwrap_or(SecondaryRewardSnapshot {
+                    reward_per_pool_token: dec!(0),
+                    unclaimed: dec!(0),
+                });
+            snapshot.unclaimed +=
+                (reward_per_pool_token - snapshot.reward_per_pool_token) * pool_amount_staked;
+            snapshot.reward_per_pool_token = reward_per_pool_token;
+            self.secondary_reward_snapshots.insert(id.clone(), snapshot);
+        }
+
+        /// This method gets the value of a single pool token at a moment in time, from the most recent share price snapshot at or before it
+        ///
+        /// ## INPUT
+        /// - `time`: the moment to get the value of a pool token at
+        ///
+        /// ## OUTPUT
+        /// - the value of a single pool token, or None if no rewards were distributed before the moment
+        ///
+        /// ## LOGIC
+        /// - the method binary searches the ring buffer, in which snapshots are ordered by time
+        /// - if the moment lies before the oldest snapshot kept while older snapshots have been overwritten, the oldest snapshot kept is used
+        fn share_price_at(&self, time: Instant) -> Option<Decimal> {
+            let available: u64 = self.share_price_counter.min(SHARE_PRICE_HISTORY_SIZE);
+            let oldest: u64 = self.share_price_counter - available;
+            let mut low: u64 = oldest;
+            let mut high: u64 = self.share_price_counter;
+
+            // find the first snapshot taken after the moment
+            while low < high {
+                let middle: u64 = low + (high - low) / 2;
+                let (snapshot_time, _) = *self
+                    .share_price_history
+                    .get(&(middle % SHARE_PRICE_HISTORY_SIZE))
+                    .unwrap();
+                if snapshot_time.compare(time, TimeComparisonOperator::Lte) {
+                    low = middle + 1;
+                } else {
+                    high = middle;
+                }
+            }
+
+            if low > oldest {
+                Some(
+                    self.share_price_history
+                        .get(&((low - 1) % SHARE_PRICE_HISTORY_SIZE))
+                        .unwrap()
+                        .1,
+                )
+            } else if oldest > 0 {
+                Some(
+                    self.share_price_history
+                        .get(&(oldest % SHARE_PRICE_HISTORY_SIZE))
+                        .unwrap()
+                        .1,
+                )
+            } else {
+                None
+            }
+        }
+
+        /// This method records the current value of a single pool token in the share price ring buffer
+        fn record_share_price(&mut self) {
+            let pool_token_supply: Decimal = ResourceManager::from(self.pool_token_address)
+                .total_supply()
+                .unwrap_or(dec!(0));
+
+            if pool_token_supply >= dec!(1) {
+                let share_price: Decimal = self.get_real_amount(dec!(1));
+                self.share_price_history.insert(
+                    self.share_price_counter % SHARE_PRICE_HISTORY_SIZE,
+                    (Clock::current_time_rounded_to_seconds(), share_price),
+                );
+                self.share_price_counter += 1;
+            }
+        }
     }
 }
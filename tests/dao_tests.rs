@@ -1,9 +1,23 @@
 mod helper;
 use helper::Helper;
 
+use dao::dao::StakingSystem;
 use scrypto::prelude::ResourceSpecifier;
 use scrypto_test::prelude::*;
 
+#[test]
+fn test_instantiate_dao_over_allocation() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Attempt to instantiate a DAO allocating 110% of the supply (should fail)
+    let failure =
+        helper.instantiate_dao_with_allocations(dec!(0.2), dec!(0.3), dec!(0.3), dec!(0.3));
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
 #[test]
 fn test_dao_put_tokens() -> Result<(), RuntimeError> {
     // Initialize helper and create a bucket of XRD tokens
@@ -44,6 +58,124 @@ fn test_dao_send_tokens() -> Result<(), RuntimeError> {
     Ok(())
 }
 
+#[test]
+fn test_dao_send_tokens_with_approval() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+
+    // Create two approver badges, both needing to approve sends of more than 5000 tokens
+    let approver_1 = ResourceBuilder::new_fungible(OwnerRole::None)
+        .divisibility(0)
+        .mint_initial_supply(1, &mut helper.env)?;
+    let approver_2 = ResourceBuilder::new_fungible(OwnerRole::None)
+        .divisibility(0)
+        .mint_initial_supply(1, &mut helper.env)?;
+    let mut approver_badges: IndexSet<ResourceAddress> = IndexSet::new();
+    approver_badges.insert(approver_1.resource_address(&mut helper.env)?);
+    approver_badges.insert(approver_2.resource_address(&mut helper.env)?);
+    let address: ResourceAddress = helper.ilis_address;
+    let mut thresholds: IndexMap<ResourceAddress, Decimal> = IndexMap::new();
+    thresholds.insert(address, dec!(5000));
+    helper.set_send_approval(approver_badges, 2, thresholds)?;
+
+    let recipient: ComponentAddress = ComponentAddress::try_from(helper.staking.0.clone()).unwrap();
+
+    // Small sends are executed immediately
+    let _ = helper.dao_send_tokens(address, ResourceSpecifier::Fungible(dec!(1000)), recipient)?;
+    assert_eq!(helper.dao_get_token_amount(address)?, dec!(299000));
+
+    // Large sends wait for approval
+    let _ = helper.dao_send_tokens(address, ResourceSpecifier::Fungible(dec!(10000)), recipient)?;
+    assert_eq!(helper.dao_get_token_amount(address)?, dec!(299000));
+
+    // A single approval is not enough
+    helper.approve_pending_send(0, &approver_1)?;
+    let failure = helper.execute_pending_send(0);
+
+    // After the second approval, the send can be executed
+    helper.approve_pending_send(0, &approver_2)?;
+    helper.execute_pending_send(0)?;
+    assert_eq!(helper.dao_get_token_amount(address)?, dec!(289000));
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_dao_split_sends_need_approval() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+
+    // Sends of more than 5000 tokens a day need approval
+    let approver = ResourceBuilder::new_fungible(OwnerRole::None)
+        .divisibility(0)
+        .mint_initial_supply(1, &mut helper.env)?;
+    let mut approver_badges: IndexSet<ResourceAddress> = IndexSet::new();
+    approver_badges.insert(approver.resource_address(&mut helper.env)?);
+    let address: ResourceAddress = helper.ilis_address;
+    let mut thresholds: IndexMap<ResourceAddress, Decimal> = IndexMap::new();
+    thresholds.insert(address, dec!(5000));
+    helper.set_send_approval(approver_badges, 1, thresholds)?;
+
+    let recipient: ComponentAddress = ComponentAddress::try_from(helper.staking.0.clone()).unwrap();
+
+    // Splitting a large send into smaller ones only executes the part within the threshold
+    let sends: Vec<(ResourceAddress, ResourceSpecifier)> = vec![
+        (address, ResourceSpecifier::Fungible(dec!(3000))),
+        (address, ResourceSpecifier::Fungible(dec!(2000))),
+        (address, ResourceSpecifier::Fungible(dec!(3000))),
+    ];
+    let _ = helper.dao_send_tokens_multi(sends, recipient)?;
+    assert_eq!(helper.dao_get_token_amount(address)?, dec!(295000));
+
+    // Further sends and takes within the same day need approval as well
+    let _ = helper.dao_send_tokens(address, ResourceSpecifier::Fungible(dec!(1)), recipient)?;
+    assert_eq!(helper.dao_get_token_amount(address)?, dec!(295000));
+    let take_failure = helper.dao_take_tokens(address, ResourceSpecifier::Fungible(dec!(1)));
+
+    // The queued sends can be executed after approval
+    helper.approve_pending_send(0, &approver)?;
+    helper.execute_pending_send(0)?;
+    assert_eq!(helper.dao_get_token_amount(address)?, dec!(292000));
+
+    // After the window has passed, sends within the threshold are executed immediately again
+    helper
+        .env
+        .set_current_time(helper.env.get_current_time().add_days(1).unwrap());
+    let _ = helper.dao_send_tokens(address, ResourceSpecifier::Fungible(dec!(5000)), recipient)?;
+    assert_eq!(helper.dao_get_token_amount(address)?, dec!(287000));
+
+    assert!(take_failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_dao_send_tokens_multi() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+
+    // Prepare multiple sends to the staking component
+    let address: ResourceAddress = helper.ilis_address;
+    let sends: Vec<(ResourceAddress, ResourceSpecifier)> = vec![
+        (address, ResourceSpecifier::Fungible(dec!(400))),
+        (address, ResourceSpecifier::Fungible(dec!(600))),
+    ];
+    let recipient: ComponentAddress = ComponentAddress::try_from(helper.staking.0.clone()).unwrap();
+
+    // Send tokens from DAO to the staking component in a single call
+    let _ = helper.dao_send_tokens_multi(sends, recipient)?;
+
+    // Assert the correct amounts after token transfer
+    let remaining_amount = helper.dao_get_token_amount(address)?;
+    let new_staking_amount = helper.get_remaining_staking_rewards()?;
+    assert_eq!(remaining_amount, dec!(299000));
+    assert_eq!(new_staking_amount, dec!(51000));
+
+    Ok(())
+}
+
 #[test]
 fn test_dao_take_tokens() -> Result<(), RuntimeError> {
     let mut helper = Helper::new().unwrap();
@@ -66,6 +198,109 @@ fn test_dao_take_tokens() -> Result<(), RuntimeError> {
     Ok(())
 }
 
+#[test]
+fn test_dao_treasury_flow() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+
+    // Put XRD into the DAO
+    let bucket = helper.xrd.take(dec!(1000), &mut helper.env)?;
+    let _ = helper.dao_put_tokens(bucket)?;
+
+    // Take part of the XRD out of the DAO again
+    let _bucket = helper.dao_take_tokens(helper.xrd_address, ResourceSpecifier::Fungible(dec!(400)))?;
+
+    // Check that both the inflow and outflow were recorded
+    let (inflow, outflow) = helper.dao_get_treasury_flow(helper.xrd_address)?;
+    assert_eq!(inflow, dec!(1000));
+    assert_eq!(outflow, dec!(400));
+
+    Ok(())
+}
+
+#[test]
+fn test_dao_treasury_flow_counts_airdrops() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+    let (_inflow, outflow) = helper.dao_get_treasury_flow(helper.ilis_address)?;
+
+    // Airdrop 1500 tokens from the treasury
+    let account_1: Reference = helper.create_account()?;
+    let mut map: IndexMap<Reference, ResourceSpecifier> = IndexMap::new();
+    map.insert(account_1, ResourceSpecifier::Fungible(dec!(1500)));
+    let _ = helper.airdrop_tokens(map, helper.ilis_address)?;
+
+    // Check that the airdrop was recorded as outflow
+    let (_inflow, outflow_2) = helper.dao_get_treasury_flow(helper.ilis_address)?;
+    assert_eq!(outflow_2, outflow + dec!(1500));
+
+    Ok(())
+}
+
+#[test]
+fn test_dao_reserve_floor() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Set a reserve floor, which only takes effect after the reserve floor delay
+    helper.dao_set_reserve_floor(helper.ilis_address, dec!(298500))?;
+    assert_eq!(helper.dao_get_reserve_floor(helper.ilis_address)?, dec!(0));
+
+    helper.env.disable_auth_module();
+    let _bucket =
+        helper.dao_take_tokens(helper.ilis_address, ResourceSpecifier::Fungible(dec!(1000)))?;
+    helper.env.enable_auth_module();
+
+    // Advance time past the reserve floor delay
+    let new_time = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time);
+    assert_eq!(
+        helper.dao_get_reserve_floor(helper.ilis_address)?,
+        dec!(298500)
+    );
+
+    // Taking tokens down to the reserve floor works, taking more fails
+    helper.env.disable_auth_module();
+    let _bucket =
+        helper.dao_take_tokens(helper.ilis_address, ResourceSpecifier::Fungible(dec!(500)))?;
+    let failure = helper.dao_take_tokens(helper.ilis_address, ResourceSpecifier::Fungible(dec!(1)));
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_dao_reserve_floor_applies_to_airdrops() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Set a reserve floor 2000 tokens below the treasury, and advance time past the reserve floor delay
+    helper.dao_set_reserve_floor(helper.ilis_address, dec!(298000))?;
+    let new_time = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time);
+    helper.env.disable_auth_module();
+
+    // Airdropping down to the reserve floor works
+    let account_1: Reference = helper.create_account()?;
+    let mut map: IndexMap<Reference, ResourceSpecifier> = IndexMap::new();
+    map.insert(account_1, ResourceSpecifier::Fungible(dec!(1500)));
+    let _ = helper.airdrop_tokens(map, helper.ilis_address)?;
+
+    // Airdropping below the reserve floor fails, for plain and membered airdrops alike
+    let account_2: Reference = helper.create_account()?;
+    let mut map_2: IndexMap<Reference, ResourceSpecifier> = IndexMap::new();
+    map_2.insert(account_2, ResourceSpecifier::Fungible(dec!(1000)));
+    let failure_airdrop = helper.airdrop_tokens(map_2, helper.ilis_address);
+
+    let mut map_3: IndexMap<Reference, Decimal> = IndexMap::new();
+    map_3.insert(account_2, dec!(1000));
+    let failure_membered = helper.airdrop_membered_tokens(map_3, 0, 0);
+
+    assert!(failure_airdrop.is_err());
+    assert!(failure_membered.is_err());
+
+    Ok(())
+}
+
 #[test]
 fn test_airdrop_membered_token() -> Result<(), RuntimeError> {
     let mut helper = Helper::new().unwrap();
@@ -257,6 +492,79 @@ fn test_airdrop_tokens() -> Result<(), RuntimeError> {
     Ok(())
 }
 
+#[test]
+fn test_can_airdrop() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    let mut map: IndexMap<Reference, ResourceSpecifier> = IndexMap::new();
+
+    // Create two accounts and assign airdrop amounts, exceeding the treasury
+    let account_1: Reference = helper.create_account()?;
+    map.insert(account_1, ResourceSpecifier::Fungible(dec!(3000)));
+
+    let account_2: Reference = helper.create_account()?;
+    map.insert(account_2, ResourceSpecifier::Fungible(dec!(400000)));
+
+    // The airdrop is not feasible, and the shortfall is reported
+    let (feasible, shortfall) = helper.can_airdrop(map.clone(), helper.ilis_address)?;
+    assert!(!feasible);
+    assert_eq!(shortfall, dec!(103000));
+
+    // Lowering the amount makes the airdrop feasible
+    map.insert(account_2, ResourceSpecifier::Fungible(dec!(4000)));
+    let (feasible_2, shortfall_2) = helper.can_airdrop(map.clone(), helper.ilis_address)?;
+    assert!(feasible_2);
+    assert_eq!(shortfall_2, dec!(0));
+
+    // Once a reserve floor is in effect, only the tokens above it are available
+    helper.dao_set_reserve_floor(helper.ilis_address, dec!(295000))?;
+    helper
+        .env
+        .set_current_time(helper.env.get_current_time().add_days(7).unwrap());
+    let (feasible_3, shortfall_3) = helper.can_airdrop(map.clone(), helper.ilis_address)?;
+    assert!(!feasible_3);
+    assert_eq!(shortfall_3, dec!(2000));
+
+    // Non-fungible ids of a fungible token can never be airdropped
+    let mut ids: IndexSet<NonFungibleLocalId> = IndexSet::new();
+    ids.insert(NonFungibleLocalId::integer(1));
+    map.insert(account_2, ResourceSpecifier::NonFungible(ids));
+    let (feasible_4, shortfall_4) = helper.can_airdrop(map, helper.ilis_address)?;
+    assert!(!feasible_4);
+    assert_eq!(shortfall_4, dec!(1));
+
+    Ok(())
+}
+
+#[test]
+fn test_airdrop_tokens_exceeding_divisibility() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+
+    // Create a non-divisible token and put it into the DAO
+    let whole_token = ResourceBuilder::new_fungible(OwnerRole::None)
+        .divisibility(0)
+        .mint_initial_supply(1000, &mut helper.env)?;
+    let whole_token_address = whole_token.resource_address(&mut helper.env)?;
+    let _ = helper.dao_put_tokens(whole_token)?;
+
+    let mut map: IndexMap<Reference, ResourceSpecifier> = IndexMap::new();
+
+    // Assign an amount with more decimal places than the token allows
+    let account_1: Reference = helper.create_account()?;
+    map.insert(account_1, ResourceSpecifier::Fungible(dec!("1.5")));
+
+    // Attempt the airdrop (should fail before anything is withdrawn)
+    let failure = helper.airdrop_tokens(map, whole_token_address);
+    assert!(failure.is_err());
+
+    // Check that the DAO still holds all tokens
+    let amount = helper.dao_get_token_amount(whole_token_address)?;
+    assert_eq!(amount, dec!(1000));
+
+    Ok(())
+}
+
 #[test]
 fn test_airdrop_nfts() -> Result<(), RuntimeError> {
     let mut helper = Helper::new().unwrap();
@@ -320,6 +628,7 @@ fn test_job_lifetime() -> Result<(), RuntimeError> {
         true,
         "test job".to_string(),
         "test descr".to_string(),
+        None,
     )?;
     let _ = helper.create_job(
         Some(account),
@@ -329,6 +638,7 @@ fn test_job_lifetime() -> Result<(), RuntimeError> {
         true,
         "test job".to_string(),
         "test descr".to_string(),
+        None,
     )?;
     let _ = helper.create_job(
         None,
@@ -338,6 +648,7 @@ fn test_job_lifetime() -> Result<(), RuntimeError> {
         true,
         "test job".to_string(),
         "test descr".to_string(),
+        None,
     )?;
 
     // Attempt to send salary (should not change balance as no job is assigned yet)
@@ -414,6 +725,445 @@ fn test_job_lifetime() -> Result<(), RuntimeError> {
     Ok(())
 }
 
+#[test]
+fn test_employ_invalid_target() -> Result<(), RuntimeError> {
+    // Initialize the helper and disable authentication
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+
+    // Create a job without an employee
+    let _ = helper.create_job(
+        None,
+        dec!(1000),
+        helper.ilis_address,
+        7,
+        true,
+        "test job".to_string(),
+        "test descr".to_string(),
+        None,
+    )?;
+
+    // Attempt to employ the staking component (should fail, as it isn't an account)
+    let failure = helper.employ(0, Reference(helper.staking.0));
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_job_payment_token_whitelist() -> Result<(), RuntimeError> {
+    // Initialize the helper and disable authentication
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+
+    // Only allow the mother token to be used for payments
+    let mut allowed_payment_tokens: IndexSet<ResourceAddress> = IndexSet::new();
+    allowed_payment_tokens.insert(helper.ilis_address);
+    helper.set_allowed_payment_tokens(Some(allowed_payment_tokens))?;
+
+    // Create a job paid in the mother token (should succeed)
+    let _ = helper.create_job(
+        None,
+        dec!(1000),
+        helper.ilis_address,
+        7,
+        true,
+        "test job".to_string(),
+        "test descr".to_string(),
+        None,
+    )?;
+
+    // Attempt to create a job paid in XRD (should fail)
+    let failure = helper.create_job(
+        None,
+        dec!(1000),
+        helper.xrd_address,
+        7,
+        true,
+        "test job".to_string(),
+        "test descr".to_string(),
+        None,
+    );
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_max_jobs_per_employee() -> Result<(), RuntimeError> {
+    // Initialize the helper and disable authentication
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+
+    // Create a test account
+    let account = helper.create_account()?;
+
+    // Create three jobs with the test account as employee, and one without an employee
+    for _ in 0..3 {
+        let _ = helper.create_job(
+            Some(account),
+            dec!(1000),
+            helper.ilis_address,
+            7,
+            true,
+            "test job".to_string(),
+            "test descr".to_string(),
+            None,
+        )?;
+    }
+    let _ = helper.create_job(
+        None,
+        dec!(1000),
+        helper.ilis_address,
+        7,
+        true,
+        "test job".to_string(),
+        "test descr".to_string(),
+        None,
+    )?;
+
+    // Cap the number of jobs per employee at two, below the jobs the account already holds
+    helper.set_max_jobs_per_employee(Some(2))?;
+
+    // Advance time by 7 days
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+
+    // Send salary (should only pay out the first two jobs)
+    helper.send_salary_to_employee(account, None)?;
+    let amount_1 = helper.dao_get_token_amount(helper.ilis_address)?;
+
+    // Send salary again (should pay out the remaining job)
+    helper.send_salary_to_employee(account, None)?;
+    let amount_2 = helper.dao_get_token_amount(helper.ilis_address)?;
+
+    // Attempt to create a job for the account (should fail, as the cap is reached)
+    let create_failure = helper.create_job(
+        Some(account),
+        dec!(1000),
+        helper.ilis_address,
+        7,
+        true,
+        "test job".to_string(),
+        "test descr".to_string(),
+        None,
+    );
+
+    // Attempt to employ the account for the free job (should fail, as the cap is reached)
+    let employ_failure = helper.employ(3, account);
+
+    // Attempt to set a cap of zero jobs (should fail)
+    let cap_failure = helper.set_max_jobs_per_employee(Some(0));
+
+    assert_eq!(amount_1, dec!(298000));
+    assert_eq!(amount_2, dec!(297000));
+    assert!(create_failure.is_err());
+    assert!(employ_failure.is_err());
+    assert!(cap_failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_late_salary_bonus() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+
+    // Create a job with a salary the treasury can't cover, with a 1% daily late payment bonus
+    let account = helper.create_account()?;
+    let _ = helper.create_job(
+        Some(account),
+        dec!(400000),
+        helper.ilis_address,
+        7,
+        true,
+        "test job".to_string(),
+        "test descr".to_string(),
+        None,
+    )?;
+    let _ = helper.set_late_payment_bonus_rate(0, Some(dec!(0.01)))?;
+
+    // Advance time by 14 days, without anyone trying to send the salary when the first period fell due,
+    // and try to send salary (should be skipped, as the treasury is short)
+    let new_time_1 = helper.env.get_current_time().add_days(14).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.send_salary_to_employee(account, None)?;
+    let amount_1 = helper.dao_get_token_amount(helper.ilis_address)?;
+
+    // Refill the treasury
+    let refill = helper.ilis.take(dec!(600000), &mut helper.env)?;
+    helper.dao_put_tokens(refill)?;
+
+    // Advance time by 2 days and send salary (should include 9 days of late payment bonus for the first period
+    // and 2 days for the second period)
+    let new_time_2 = helper.env.get_current_time().add_days(2).unwrap();
+    helper.env.set_current_time(new_time_2);
+    let _ = helper.send_salary_to_employee(account, None)?;
+    let amount_2 = helper.dao_get_token_amount(helper.ilis_address)?;
+
+    let salary = helper.withdraw_from_account(account, helper.ilis_address, dec!(844000))?;
+
+    assert_eq!(amount_1, dec!(300000));
+    assert_eq!(amount_2, dec!(56000));
+    helper.assert_bucket_eq(&salary, helper.ilis_address, dec!(844000))?;
+
+    Ok(())
+}
+
+#[test]
+fn test_count_controller_badges() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Count the controller badges held by the DAO and the Governance component
+    let (total_supply, held) = helper.count_controller_badges()?;
+
+    let dao_address = ComponentAddress::try_from(helper.dao.0.clone()).unwrap();
+    let governance_address = ComponentAddress::try_from(helper.governance.0.clone()).unwrap();
+
+    assert_eq!(total_supply, Some(dec!(100)));
+    assert_eq!(held.get(&dao_address), Some(&dec!(1)));
+    assert_eq!(held.get(&governance_address), Some(&dec!(3)));
+
+    Ok(())
+}
+
+#[test]
+fn test_add_claimed_entity() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+
+    // The DAO components are claimed at instantiation
+    let claimed_entities = helper.get_claimed_entities()?;
+    assert_eq!(claimed_entities.len(), 7);
+
+    // Claim the mother token
+    let ilis_address = GlobalAddress::from(helper.ilis_address);
+    helper.add_claimed_entity(ilis_address)?;
+
+    let claimed_entities_2 = helper.get_claimed_entities()?;
+    assert_eq!(claimed_entities_2.len(), 8);
+    assert_eq!(claimed_entities_2.last(), Some(&ilis_address));
+
+    // Claiming it twice fails
+    let failure = helper.add_claimed_entity(ilis_address);
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_set_dao_metadata() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+
+    // Rebrand the DAO
+    helper.set_dao_metadata("name", MetadataValue::String("New DAO".to_string()))?;
+
+    // Overwrite the claimed entities with only the mother token
+    let ilis_address = GlobalAddress::from(helper.ilis_address);
+    helper.set_dao_metadata(
+        "claimed_entities",
+        MetadataValue::GlobalAddressArray(vec![ilis_address]),
+    )?;
+    let claimed_entities = helper.get_claimed_entities()?;
+
+    // Changing the account type fails
+    let failure =
+        helper.set_dao_metadata("account_type", MetadataValue::String("account".to_string()));
+
+    assert_eq!(claimed_entities, vec![ilis_address]);
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_propose_add_stakable() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake tokens for a voter
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    // Propose to make XRD stakable, submit and vote for the proposal
+    let (_leftover_payment, proposal_bucket) = helper.propose_add_stakable(
+        helper.xrd_address,
+        dec!(5000),
+        dec!(1.001),
+        365,
+        dec!(1.002),
+        dec!(1),
+        dec!(10000),
+    )?;
+    let _ = helper.submit_proposal(proposal_bucket)?;
+    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
+
+    // Advance time past the voting period, finish voting and execute
+    let new_time_1 = helper.env.get_current_time().add_days(4).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.finish_voting(0)?;
+    let _ = helper.execute_proposal_step(0, 1)?;
+
+    let stakables = helper.get_stakables()?;
+    assert_eq!(stakables, vec![(helper.xrd_address, dec!(5000), dec!(0))]);
+
+    // Proposing a malformed stakable fails at proposal creation
+    let failure = helper.propose_add_stakable(
+        helper.ilis_address,
+        dec!(5000),
+        dec!(0.5),
+        365,
+        dec!(1.002),
+        dec!(1),
+        dec!(10000),
+    );
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_propose_send_tokens() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake tokens for a voter
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    // Propose to send tokens to the staking component, submit and vote for the proposal
+    let recipient: ComponentAddress = ComponentAddress::try_from(helper.staking.0.clone()).unwrap();
+    let (_leftover_payment, proposal_bucket) = helper.propose_send_tokens(
+        helper.ilis_address,
+        ResourceSpecifier::Fungible(dec!(1000)),
+        recipient,
+        dec!(10000),
+    )?;
+    let _ = helper.submit_proposal(proposal_bucket)?;
+    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
+
+    // Advance time past the voting period, finish voting and execute
+    let new_time_1 = helper.env.get_current_time().add_days(4).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.finish_voting(0)?;
+    let _ = helper.execute_proposal_step(0, 1)?;
+
+    assert_eq!(
+        helper.dao_get_token_amount(helper.ilis_address)?,
+        dec!(299000)
+    );
+
+    // Proposing a send of a non-positive amount fails at proposal creation
+    let failure = helper.propose_send_tokens(
+        helper.ilis_address,
+        ResourceSpecifier::Fungible(dec!(0)),
+        recipient,
+        dec!(10000),
+    );
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_payroll_summary() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+
+    // Create two jobs for a test account, paid in different tokens, and one job without an employee
+    let account = helper.create_account()?;
+    let _ = helper.create_job(
+        Some(account),
+        dec!(1000),
+        helper.ilis_address,
+        7,
+        true,
+        "test job".to_string(),
+        "test descr".to_string(),
+        None,
+    )?;
+    let _ = helper.create_job(
+        None,
+        dec!(1000),
+        helper.ilis_address,
+        7,
+        true,
+        "test job".to_string(),
+        "test descr".to_string(),
+        None,
+    )?;
+    let _ = helper.create_job(
+        Some(account),
+        dec!(10),
+        helper.xrd_address,
+        1,
+        true,
+        "test job".to_string(),
+        "test descr".to_string(),
+        None,
+    )?;
+
+    // Advance time by 15 days
+    let new_time_1 = helper.env.get_current_time().add_days(15).unwrap();
+    helper.env.set_current_time(new_time_1);
+
+    // Check the accrued salaries of the employee
+    let summary = helper.get_payroll_summary(0, 10)?;
+    assert_eq!(summary.len(), 1);
+    let (employee, job_ids, liabilities) = &summary[0];
+    assert_eq!(*employee, account);
+    assert_eq!(*job_ids, vec![0, 2]);
+    assert_eq!(*liabilities.get(&helper.ilis_address).unwrap(), dec!(2000));
+    assert_eq!(*liabilities.get(&helper.xrd_address).unwrap(), dec!(150));
+
+    Ok(())
+}
+
+#[test]
+fn test_job_nft_grant() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Create and put a role NFT into the DAO
+    let nft_bucket = helper.create_staking_id()?;
+    let _ = helper.dao_put_tokens(nft_bucket)?;
+
+    helper.env.disable_auth_module();
+
+    // Create a job for a test account, granting the NFT
+    let account = helper.create_account()?;
+    let mut nft_ids: IndexSet<NonFungibleLocalId> = IndexSet::new();
+    nft_ids.insert(NonFungibleLocalId::integer(1));
+    let _ = helper.create_job(
+        Some(account),
+        dec!(1000),
+        helper.ilis_address,
+        7,
+        true,
+        "test job".to_string(),
+        "test descr".to_string(),
+        Some((helper.staking_id_address, nft_ids)),
+    )?;
+
+    // Send salary, which sends the NFT grant even though no full period has been worked
+    let _ = helper.send_salary_to_employee(account, None)?;
+
+    // Withdraw the granted NFT from the account
+    let _grant = helper.withdraw_nft_from_account(
+        account,
+        helper.staking_id_address,
+        NonFungibleLocalId::integer(1),
+    )?;
+
+    // Send salary again, which doesn't fail now that the grant has already been sent
+    let _ = helper.send_salary_to_employee(account, None)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_post_remove_announcement() -> Result<(), RuntimeError> {
     let mut helper = Helper::new().unwrap();
@@ -428,6 +1178,43 @@ fn test_post_remove_announcement() -> Result<(), RuntimeError> {
     Ok(())
 }
 
+#[test]
+fn test_get_announcements() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+
+    // Post three announcements and remove the second one
+    let _ = helper.post_announcement("First announcement".to_string())?;
+    let _ = helper.post_announcement("Second announcement".to_string())?;
+    let _ = helper.post_announcement("Third announcement".to_string())?;
+    let _ = helper.remove_announcement(1)?;
+
+    // The removed announcement is skipped
+    let announcements: Vec<(u64, String)> = helper
+        .get_announcements(0, 10)?
+        .into_iter()
+        .map(|(announcement_id, text, _files)| (announcement_id, text))
+        .collect();
+
+    // Pages only cover the requested ids
+    let page: Vec<u64> = helper
+        .get_announcements(1, 1)?
+        .into_iter()
+        .map(|(announcement_id, _text, _files)| announcement_id)
+        .collect();
+
+    assert_eq!(
+        announcements,
+        vec![
+            (0, "First announcement".to_string()),
+            (2, "Third announcement".to_string())
+        ]
+    );
+    assert!(page.is_empty());
+
+    Ok(())
+}
+
 #[test]
 fn test_rewarded_calls() -> Result<(), RuntimeError> {
     // Initialize the helper and disable authentication
@@ -460,6 +1247,66 @@ fn test_rewarded_calls() -> Result<(), RuntimeError> {
     Ok(())
 }
 
+#[test]
+fn test_auto_reward_topup() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+
+    // Top up the staking rewards with 1000 tokens whenever they drop below twice the current amount
+    let remaining_rewards = helper.get_remaining_staking_rewards()?;
+    let _ = helper.set_auto_reward_topup(dec!(1000), remaining_rewards * dec!(2))?;
+
+    // The rewarded update tops up the staking rewards from the treasury
+    let _bucket = helper.rewarded_update()?;
+    let remaining_rewards_2 = helper.get_remaining_staking_rewards()?;
+    assert!(remaining_rewards_2 > remaining_rewards);
+    let (_inflow, outflow) = helper.dao_get_treasury_flow(helper.ilis_address)?;
+    assert_eq!(outflow, dec!(1000));
+
+    // After disabling the top-up, the treasury is no longer touched
+    let _ = helper.set_auto_reward_topup(dec!(0), dec!(0))?;
+    let _bucket_2 = helper.rewarded_update()?;
+    let (_inflow, outflow_2) = helper.dao_get_treasury_flow(helper.ilis_address)?;
+    assert_eq!(outflow_2, dec!(1000));
+
+    Ok(())
+}
+
+#[test]
+fn test_auto_reward_topup_respects_reserve_floor() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Keep all but 400 tokens of the treasury as reserve floor
+    let treasury_amount = helper.dao_get_token_amount(helper.ilis_address)?;
+    helper.dao_set_reserve_floor(helper.ilis_address, treasury_amount - dec!(400))?;
+
+    // Top up the staking rewards with 1000 tokens whenever they drop below twice the current amount
+    helper.env.disable_auth_module();
+    let _ = helper.set_update_reward(dec!(0))?;
+    let remaining_rewards = helper.get_remaining_staking_rewards()?;
+    let _ = helper.set_auto_reward_topup(dec!(1000), remaining_rewards * dec!(2))?;
+
+    // Advance time past the reserve floor delay
+    let new_time = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time);
+
+    // The top-up is capped at the tokens above the reserve floor
+    let _bucket = helper.rewarded_update()?;
+    let (_inflow, outflow) = helper.dao_get_treasury_flow(helper.ilis_address)?;
+    assert_eq!(outflow, dec!(400));
+    assert_eq!(
+        helper.dao_get_token_amount(helper.ilis_address)?,
+        treasury_amount - dec!(400)
+    );
+
+    // With the treasury at the reserve floor, the top-up is skipped
+    let _bucket_2 = helper.rewarded_update()?;
+    let (_inflow, outflow_2) = helper.dao_get_treasury_flow(helper.ilis_address)?;
+    assert_eq!(outflow_2, dec!(400));
+
+    Ok(())
+}
+
 #[test]
 fn test_rewarded_call_addition() -> Result<(), RuntimeError> {
     // Initialize the helper and disable authentication
@@ -491,3 +1338,272 @@ fn test_rewarded_call_addition() -> Result<(), RuntimeError> {
 
     Ok(())
 }
+
+#[test]
+fn test_owner_transfer() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Propose to transfer ownership
+    helper.propose_owner_transfer(rule!(allow_all))?;
+
+    // Advance time past the owner transfer delay and accept
+    let new_time = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time);
+    helper.accept_owner_transfer()?;
+
+    // The transfer can't be accepted twice
+    let failure = helper.accept_owner_transfer();
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_owner_transfer_moves_component_ownership() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Without the controller badge, owner methods can't be called
+    let failure_staking = helper.staking.freeze_rewards(&mut helper.env);
+    let failure_dao = helper
+        .dao
+        .set_max_jobs_per_employee(Some(2), &mut helper.env);
+
+    // Transfer ownership to a rule anyone satisfies
+    helper.propose_owner_transfer(rule!(allow_all))?;
+    let new_time = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time);
+    helper.accept_owner_transfer()?;
+
+    // The new owner controls the DAO and Staking components
+    helper.staking.freeze_rewards(&mut helper.env)?;
+    helper
+        .dao
+        .set_max_jobs_per_employee(Some(2), &mut helper.env)?;
+
+    assert!(failure_staking.is_err());
+    assert!(failure_dao.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_owner_transfer_before_delay() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Propose to transfer ownership
+    helper.propose_owner_transfer(rule!(allow_all))?;
+
+    // Accepting before the owner transfer delay has passed fails
+    let new_time = helper.env.get_current_time().add_days(6).unwrap();
+    helper.env.set_current_time(new_time);
+    let failure = helper.accept_owner_transfer();
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_airdrop_to_components() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+
+    let staking_rewards = helper.get_remaining_staking_rewards()?;
+    let incentives_rewards = helper.get_remaining_incentives_rewards()?;
+
+    // Airdrop tokens to the staking and incentives components
+    let mut targets: IndexMap<ComponentAddress, ResourceSpecifier> = IndexMap::new();
+    targets.insert(
+        ComponentAddress::try_from(helper.staking.0.clone()).unwrap(),
+        ResourceSpecifier::Fungible(dec!(500)),
+    );
+    targets.insert(
+        ComponentAddress::try_from(helper.incentives.0.clone()).unwrap(),
+        ResourceSpecifier::Fungible(dec!(700)),
+    );
+    let _ = helper.airdrop_to_components(targets, helper.ilis_address)?;
+
+    // Assert both components received their tokens
+    assert_eq!(
+        helper.get_remaining_staking_rewards()?,
+        staking_rewards + dec!(500)
+    );
+    assert_eq!(
+        helper.get_remaining_incentives_rewards()?,
+        incentives_rewards + dec!(700)
+    );
+    assert_eq!(helper.dao_get_token_amount(helper.ilis_address)?, dec!(298800));
+
+    Ok(())
+}
+
+#[test]
+fn test_migrate_position() -> Result<(), RuntimeError> {
+    // Initialize the helper and make the mother token stakable in the Incentives component
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+    helper.add_stakable(helper.ilis_address, dec!(100), dec!("1.01"), 365, dec!(3))?;
+    helper.env.enable_auth_module();
+
+    // Stake 10000 tokens in the Staking component
+    let bucket = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket)?.0.unwrap();
+    let stake_local_id = stake_id.non_fungible_local_id(&mut helper.env)?;
+    let pool_amount_1 = helper
+        .get_member_data(stake_local_id.clone())?
+        .pool_amount_staked;
+
+    // Migrate part of the stake to a new Incentives ID, without waiting for the unstake delay
+    let (incentives_id, _lock_reward) = helper.migrate_position(
+        StakingSystem::Staking,
+        StakingSystem::Incentives,
+        dec!(4000),
+        &stake_id,
+        None,
+    )?;
+    let incentives_id = incentives_id.unwrap();
+    let incentives_local_id = incentives_id.non_fungible_local_id(&mut helper.env)?;
+    let pool_amount_2 = helper
+        .get_member_data(stake_local_id.clone())?
+        .pool_amount_staked;
+    let incentives_staked_1 = helper
+        .get_incentive_data(incentives_local_id.clone())?
+        .resources
+        .get(&helper.ilis_address)
+        .unwrap()
+        .amount_staked;
+
+    // Migrate part of it back to the existing Staking ID
+    let (no_new_id, _lock_reward_2) = helper.migrate_position(
+        StakingSystem::Incentives,
+        StakingSystem::Staking,
+        dec!(1000),
+        &incentives_id,
+        Some(&stake_id),
+    )?;
+    let pool_amount_3 = helper
+        .get_member_data(stake_local_id.clone())?
+        .pool_amount_staked;
+    let incentives_staked_2 = helper
+        .get_incentive_data(incentives_local_id)?
+        .resources
+        .get(&helper.ilis_address)
+        .unwrap()
+        .amount_staked;
+
+    // Attempt to migrate within the same component (should fail)
+    let failure = helper.migrate_position(
+        StakingSystem::Staking,
+        StakingSystem::Staking,
+        dec!(1000),
+        &stake_id,
+        None,
+    );
+
+    assert_eq!(pool_amount_1 - pool_amount_2, dec!(4000));
+    assert!(incentives_staked_1 > dec!(0));
+    assert_eq!(incentives_staked_1 - incentives_staked_2, dec!(1000));
+    assert!(pool_amount_3 > pool_amount_2);
+    assert!(no_new_id.is_none());
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_migrate_position_restrictions() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+    helper.add_stakable(helper.ilis_address, dec!(100), dec!("1.01"), 365, dec!(3))?;
+    helper.env.enable_auth_module();
+
+    // Stake tokens to four Staking IDs
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_1 = helper.stake_without_id(bucket_1)?.0.unwrap();
+    let bucket_2 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_2 = helper.stake_without_id(bucket_2)?.0.unwrap();
+    let bucket_3 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_3 = helper.stake_without_id(bucket_3)?.0.unwrap();
+    let bucket_4 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_4 = helper.stake_without_id(bucket_4)?.0.unwrap();
+
+    // The first ID locks its stake, the second votes and the third delegates its vote to the second
+    let stake_id_1 = helper.lock_stake(stake_id_1, 10, false)?;
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let _ = helper.submit_proposal(proposal_bucket)?;
+    let stake_id_2 = helper.vote_on_proposal(true, stake_id_2, 0)?;
+    let stake_id_3 = helper.delegate_vote(stake_id_3, NonFungibleLocalId::integer(2))?;
+
+    // None of them can migrate their position
+    let failure_locked = helper.migrate_position(
+        StakingSystem::Staking,
+        StakingSystem::Incentives,
+        dec!(1000),
+        &stake_id_1,
+        None,
+    );
+    let failure_voting = helper.migrate_position(
+        StakingSystem::Staking,
+        StakingSystem::Incentives,
+        dec!(1000),
+        &stake_id_2,
+        None,
+    );
+    let failure_delegating = helper.migrate_position(
+        StakingSystem::Staking,
+        StakingSystem::Incentives,
+        dec!(1000),
+        &stake_id_3,
+        None,
+    );
+
+    // Migrating to the Incentives component with a shorter unstake delay fails, even for an unrestricted ID
+    helper.set_staking_unstake_delay(14)?;
+    let failure_delay = helper.migrate_position(
+        StakingSystem::Staking,
+        StakingSystem::Incentives,
+        dec!(1000),
+        &stake_id_4,
+        None,
+    );
+
+    assert!(failure_locked.is_err());
+    assert!(failure_voting.is_err());
+    assert!(failure_delegating.is_err());
+    assert!(failure_delay.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_migrate_position_of_removed_stakable() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+    helper.add_stakable(helper.ilis_address, dec!(100), dec!("1.01"), 365, dec!(3))?;
+    helper.env.enable_auth_module();
+
+    // Stake tokens in the Incentives component and unstake all but a residual amount
+    let bucket = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let incentives_id = helper.stake_incentives_without_id(bucket)?.0.unwrap();
+    helper.set_max_removal_residual(dec!(1))?;
+    let (_unstake_receipt, incentives_id) =
+        helper.start_incentives_unstake(helper.ilis_address, incentives_id, dec!(9999.9))?;
+
+    // Remove the stakable, recovering the residual stake
+    let _recovered = helper.drain_and_remove_stakable(helper.ilis_address)?;
+
+    // The position of the removed stakable can't be migrated, but can still be unstaked
+    let failure = helper.migrate_position(
+        StakingSystem::Incentives,
+        StakingSystem::Staking,
+        dec!(0.1),
+        &incentives_id,
+        None,
+    );
+    let (_residual_receipt, _incentives_id) =
+        helper.start_incentives_unstake(helper.ilis_address, incentives_id, dec!(0.1))?;
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
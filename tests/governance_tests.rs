@@ -1,6 +1,11 @@
 mod helper;
 use helper::Helper;
 
+use dao::governance::{
+    ExecutionPermission, ProposalCreatedEvent, ProposalStatus, ProposalStepExecutedEvent,
+    ProposalSubmittedEvent, RejectedFeeHandling, VoteCastEvent, VotingFinishedEvent,
+};
+use dao::staking::VoteDecay;
 use scrypto_test::prelude::*;
 
 // Test to ensure proposal creation fails when insufficient tokens are provided
@@ -15,6 +20,28 @@ fn test_failed_create_proposal() -> Result<(), RuntimeError> {
     Ok(())
 }
 
+// Test that a proposal with an empty description fails
+#[test]
+fn test_create_proposal_empty_description() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Without minimum lengths, a proposal without a description can be created
+    let _ = helper.create_proposal_with_text(
+        "Test Proposal".to_string(),
+        "".to_string(),
+        dec!(19999),
+    )?;
+
+    // Attempt to create a proposal without a description once minimum lengths are set (should fail)
+    helper.set_min_text_lengths(5, 10)?;
+    let failure =
+        helper.create_proposal_with_text("Test Proposal".to_string(), "".to_string(), dec!(19999));
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
 // Test successful proposal creation
 #[test]
 fn test_create_proposal() -> Result<(), RuntimeError> {
@@ -28,6 +55,99 @@ fn test_create_proposal() -> Result<(), RuntimeError> {
     Ok(())
 }
 
+#[test]
+fn test_create_proposal_with_max_fee() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Create a proposal with a maximum fee equal to the fee (10000)
+    let (bucket_1, _bucket_2) = helper.create_proposal_with_max_fee(
+        "Test Proposal".to_string(),
+        "This is a test proposal".to_string(),
+        dec!(19999),
+        Some(dec!(10000)),
+    )?;
+    let _ = helper.assert_bucket_eq(&bucket_1, helper.ilis_address, dec!(9999))?;
+
+    // Creating a proposal with a maximum fee below the fee fails
+    let failure = helper.create_proposal_with_max_fee(
+        "Test Proposal".to_string(),
+        "This is a test proposal".to_string(),
+        dec!(19999),
+        Some(dec!(9999)),
+    );
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_execution_restricted_to_proposer() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.set_execution_permission(ExecutionPermission::ProposerOnly)?;
+
+    // Stake tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    // Create and submit a proposal, and vote on it
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let proposal_bucket_return = helper.submit_proposal(proposal_bucket)?;
+    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
+
+    // Advance time by 7 days
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+    helper.finish_voting(0)?;
+
+    // Executing without the proposal receipt fails
+    let failure = helper.execute_proposal_step(0, 1);
+
+    // The proposer can execute the proposal
+    helper.execute_proposal_step_with_proof(0, 1, &proposal_bucket_return)?;
+
+    // Verify rewarded update (which has been changed through accepting the proposal to 100 ILIS / day)
+    let bucket_2 = helper.rewarded_update()?;
+    helper.assert_bucket_eq(&bucket_2, helper.ilis_address, dec!(700))?;
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_execution_restricted_to_executor_badge() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.set_execution_permission(ExecutionPermission::ExecutorBadge(helper.xrd_address))?;
+
+    // Stake tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    // Create and submit a proposal, and vote on it
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let proposal_bucket_return = helper.submit_proposal(proposal_bucket)?;
+    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
+
+    // Advance time by 7 days
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+    helper.finish_voting(0)?;
+
+    // Executing with the proposal receipt instead of the executor badge fails
+    let failure = helper.execute_proposal_step_with_proof(0, 1, &proposal_bucket_return);
+
+    // The holder of the executor badge can execute the proposal
+    let executor_badge = helper.xrd.take(dec!(1), &mut helper.env)?;
+    helper.execute_proposal_step_with_proof(0, 1, &executor_badge)?;
+
+    let bucket_2 = helper.rewarded_update()?;
+    helper.assert_bucket_eq(&bucket_2, helper.ilis_address, dec!(700))?;
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
 // Test the full lifecycle of a proposal from creation to execution
 #[test]
 fn test_proposal_lifetime_to_excecution() -> Result<(), RuntimeError> {
@@ -62,6 +182,185 @@ fn test_proposal_lifetime_to_excecution() -> Result<(), RuntimeError> {
     Ok(())
 }
 
+// Test whether the governance stats are updated at every status transition
+#[test]
+fn test_governance_stats() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    // Create, submit and vote on a proposal
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let _ = helper.submit_proposal(proposal_bucket)?;
+    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
+
+    let stats = helper.get_governance_stats()?;
+    assert_eq!(stats.proposals_created, 1);
+    assert_eq!(stats.active, 1);
+
+    // Advance time by 7 days, finish voting and execute the proposal
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+    helper.finish_voting(0)?;
+    helper.execute_proposal_step(0, 1)?;
+
+    let stats = helper.get_governance_stats()?;
+    let first_turnout = stats.average_turnout;
+    assert_eq!(stats.executed, 1);
+    assert_eq!(stats.active, 0);
+    assert_eq!(stats.votes_concluded, 1);
+    assert!(first_turnout > dec!(0));
+
+    // Create and submit a proposal nobody votes on, which is rejected
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let _ = helper.submit_proposal(proposal_bucket)?;
+
+    let new_time_2 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_2);
+    helper.finish_voting(1)?;
+
+    let stats = helper.get_governance_stats()?;
+    assert_eq!(stats.proposals_created, 2);
+    assert_eq!(stats.executed, 1);
+    assert_eq!(stats.rejected, 1);
+    assert_eq!(stats.vetoed, 0);
+    assert_eq!(stats.average_turnout, first_turnout / dec!(2));
+
+    Ok(())
+}
+
+// Test that changing a vote from for to against flips the outcome of a proposal
+#[test]
+fn test_change_vote() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake tokens for two voters
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_1 = helper.stake_without_id(bucket_1)?.0.unwrap();
+    let bucket_2 = helper.ilis.take(dec!(5000), &mut helper.env)?;
+    let stake_id_2 = helper.stake_without_id(bucket_2)?.0.unwrap();
+
+    // Create and submit a proposal, both voters vote for it
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let _ = helper.submit_proposal(proposal_bucket)?;
+    let stake_id_1 = helper.vote_on_proposal(true, stake_id_1, 0)?;
+    let _ = helper.vote_on_proposal(true, stake_id_2, 0)?;
+    let info_before = helper.get_proposal_info(0)?.unwrap();
+
+    // Advance time by 1 day, the first voter switches sides
+    let new_time_1 = helper.env.get_current_time().add_days(1).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let stake_id_1 = helper.change_vote(false, stake_id_1, 0)?;
+
+    // The vote power of the first voter moved from for to against, without changing the deadline
+    let info_after = helper.get_proposal_info(0)?.unwrap();
+    assert_eq!(info_before.votes_against, dec!(0));
+    assert_eq!(
+        info_after.votes_for + info_after.votes_against,
+        info_before.votes_for
+    );
+    assert!(info_after.votes_against > info_after.votes_for);
+    assert!(info_after.votes_for > dec!(0));
+    assert_eq!(info_after.deadline, info_before.deadline);
+
+    // The vote can't be changed to the side it is already on
+    let failure = helper.change_vote(false, stake_id_1, 0);
+
+    // Advance time past the deadline and finish voting
+    let new_time_2 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_2);
+    helper.finish_voting(0)?;
+
+    // The proposal is rejected, 10000 against and 5000 for
+    let (executable, _reason) = helper.is_executable(0)?;
+    let failure_execute = helper.execute_proposal_step(0, 1);
+
+    assert!(failure.is_err());
+    assert!(!executable);
+    assert!(failure_execute.is_err());
+
+    Ok(())
+}
+
+// Test whether a proposal is reported as executable throughout its lifetime
+#[test]
+fn test_is_executable() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    // Create and submit a proposal, and vote on it
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let _ = helper.submit_proposal(proposal_bucket)?;
+    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
+
+    // Not executable while voting is ongoing
+    assert!(!helper.is_executable(0)?.0);
+
+    // Advance time by 7 days and finish voting, now it is executable
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+    helper.finish_voting(0)?;
+    let (executable_1, reason_1) = helper.is_executable(0)?;
+
+    // Restrict execution to the proposer, which is reflected in the reason
+    helper.set_execution_permission(ExecutionPermission::ProposerOnly)?;
+    let (executable_2, reason_2) = helper.is_executable(0)?;
+    helper.set_execution_permission(ExecutionPermission::Anyone)?;
+
+    // Execute the proposal, now it is no longer executable
+    helper.execute_proposal_step(0, 1)?;
+    let (executable_3, reason_3) = helper.is_executable(0)?;
+
+    assert!(executable_1);
+    assert_eq!(reason_1, "Proposal can be executed by anyone.");
+    assert!(executable_2);
+    assert_eq!(
+        reason_2,
+        "Proposal can be executed with a proof of its proposal receipt."
+    );
+    assert!(!executable_3);
+    assert_eq!(reason_3, "Proposal already executed.");
+
+    Ok(())
+}
+
+// Test the lifecycle of a proposal that is created and submitted in one go
+#[test]
+fn test_create_and_submit_proposal_to_execution() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    // Create and submit a proposal in a single call
+    let (bucket_return_payment, proposal_bucket) =
+        helper.create_and_submit_basic_proposal(dec!(15000))?;
+    helper.assert_bucket_eq(&bucket_return_payment, helper.ilis_address, dec!(5000))?;
+
+    // Vote on the proposal immediately, as it is already ongoing
+    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
+
+    // Advance time by 7 days
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+
+    // Finish voting and execute the proposal
+    helper.finish_voting(0)?;
+    helper.execute_proposal_step(0, 1)?;
+
+    // Retrieve and verify the proposal fee
+    let returned_payment = helper.retrieve_fee(proposal_bucket)?;
+    helper.assert_bucket_eq(&returned_payment, helper.ilis_address, dec!(10000))?;
+
+    Ok(())
+}
+
 // Test to ensure voting twice on the same proposal fails
 #[test]
 fn test_proposal_vote_twice() -> Result<(), RuntimeError> {
@@ -417,31 +716,25 @@ fn test_proposal_fail_below_quorum() -> Result<(), RuntimeError> {
 }
 
 #[test]
-pub fn test_proposal_with_multiple_steps_fail_to_retrieve_fee() -> Result<(), RuntimeError> {
+fn test_proposal_fail_below_quorum_fee_not_refunded() -> Result<(), RuntimeError> {
     let mut helper = Helper::new().unwrap();
 
     // Stake tokens for a single voter
-    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let bucket_1 = helper.ilis.take(dec!(5000), &mut helper.env)?;
     let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
 
-    // Create a proposal with multiple steps
+    // Create, submit and vote on a proposal
     let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
-    let proposal_bucket_return = helper.add_normal_proposal_step(proposal_bucket)?;
-
-    // Submit the proposal and vote
-    let proposal_bucket_return_2 = helper.submit_proposal(proposal_bucket_return)?;
+    let proposal_bucket_return = helper.submit_proposal(proposal_bucket)?;
     let _ = helper.vote_on_proposal(true, stake_id, 0)?;
 
-    // Advance time by 7 days (end of voting period)
+    // Advance time by 7 days and finish voting (fails due to not meeting quorum)
     let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
     helper.env.set_current_time(new_time_1);
-
-    // Finish voting and execute only the first step
     let _ = helper.finish_voting(0)?;
-    let _ = helper.execute_proposal_step(0, 1)?;
 
-    // Attempt to retrieve fee (should fail as not all steps are executed)
-    let failure = helper.retrieve_fee(proposal_bucket_return_2);
+    // Attempt to retrieve fee (should fail as the proposal didn't reach quorum)
+    let failure = helper.retrieve_fee(proposal_bucket_return);
 
     assert!(failure.is_err());
 
@@ -449,102 +742,299 @@ pub fn test_proposal_with_multiple_steps_fail_to_retrieve_fee() -> Result<(), Ru
 }
 
 #[test]
-pub fn test_proposal_with_multiple_steps_succeed_in_one_call() -> Result<(), RuntimeError> {
+fn test_rejected_fee_returned_to_treasury() -> Result<(), RuntimeError> {
     let mut helper = Helper::new().unwrap();
+    let treasury_amount_1 = helper.get_governance_token_amount(helper.ilis_address)?;
 
-    // Stake tokens for a single voter
-    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
-    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
-
-    // Create a proposal with multiple steps
-    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
-    let proposal_bucket_return = helper.add_normal_proposal_step(proposal_bucket)?;
-
-    // Submit the proposal and vote
-    let proposal_bucket_return_2 = helper.submit_proposal(proposal_bucket_return)?;
-    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
+    // Create and submit a proposal nobody votes on
+    let (_bucket_return_payment, proposal_bucket) =
+        helper.create_and_submit_basic_proposal(dec!(10000))?;
 
-    // Advance time by 7 days (end of voting period)
+    // Advance time by 7 days and finish voting (rejected without reaching quorum)
     let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
     helper.env.set_current_time(new_time_1);
-
-    // Finish voting and execute all steps in one call
     let _ = helper.finish_voting(0)?;
-    let _ = helper.execute_proposal_step(0, 2)?;
 
-    // Successfully retrieve fee
-    let _ = helper.retrieve_fee(proposal_bucket_return_2)?;
+    // The fee is taken and put in the treasury
+    let treasury_amount_2 = helper.get_governance_token_amount(helper.ilis_address)?;
+    let failure = helper.retrieve_fee(proposal_bucket);
+
+    assert_eq!(treasury_amount_2 - treasury_amount_1, dec!(10000));
+    assert!(failure.is_err());
 
     Ok(())
 }
 
 #[test]
-pub fn test_hurried_proposal() -> Result<(), RuntimeError> {
-    let mut helper = Helper::new().unwrap();
-    helper.env.disable_auth_module();
-
-    // Stake tokens for a single voter
-    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
-    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
-
-    // Create a proposal with multiple steps
-    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+fn test_rejected_fee_burned() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new_with_burnable_mother_token(true).unwrap();
+    helper.set_rejected_fee_handling(RejectedFeeHandling::Burn)?;
+    let treasury_amount_1 = helper.get_governance_token_amount(helper.ilis_address)?;
+
+    // Create and submit a proposal nobody votes on
+    let (_bucket_return_payment, proposal_bucket) =
+        helper.create_and_submit_basic_proposal(dec!(10000))?;
+    let supply_1 = ResourceManager(helper.ilis_address)
+        .total_supply(&mut helper.env)?
+        .unwrap();
+
+    // Advance time by 7 days and finish voting (rejected without reaching quorum)
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.finish_voting(0)?;
 
-    // Submit the proposal and vote
-    let proposal_bucket_return_2 = helper.submit_proposal(proposal_bucket)?;
-    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
-    let _ = helper.hurry_proposal(0, 1)?;
+    // The fee is burned, using the controller badge, instead of put in the treasury
+    let supply_2 = ResourceManager(helper.ilis_address)
+        .total_supply(&mut helper.env)?
+        .unwrap();
+    let treasury_amount_2 = helper.get_governance_token_amount(helper.ilis_address)?;
+    let failure = helper.retrieve_fee(proposal_bucket);
 
-    // Advance time by 1 day (end of voting period due to hurry)
-    let new_time_1 = helper.env.get_current_time().add_days(1).unwrap();
-    helper.env.set_current_time(new_time_1);
+    assert_eq!(supply_1 - supply_2, dec!(10000));
+    assert_eq!(treasury_amount_2, treasury_amount_1);
+    assert!(failure.is_err());
 
-    // Finish voting and execute all steps in one call
-    let _ = helper.finish_voting(0)?;
-    let _ = helper.execute_proposal_step(0, 1)?;
+    // Burning fails if the mother token has no burn role
+    let mut helper_2 = Helper::new().unwrap();
+    let failure_2 = helper_2.set_rejected_fee_handling(RejectedFeeHandling::Burn);
 
-    // Successfully retrieve fee
-    let _ = helper.retrieve_fee(proposal_bucket_return_2)?;
+    assert!(failure_2.is_err());
 
     Ok(())
 }
 
 #[test]
-pub fn test_proposal_with_multiple_steps_succeed_in_one_call_overshoot() -> Result<(), RuntimeError>
-{
+fn test_fee_accounting() -> Result<(), RuntimeError> {
     let mut helper = Helper::new().unwrap();
 
-    // Stake tokens for a single voter
+    // Stake tokens
     let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
     let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
 
-    // Create a proposal with multiple steps
-    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
-    let proposal_bucket_return = helper.add_normal_proposal_step(proposal_bucket)?;
-
-    // Submit the proposal and vote
-    let proposal_bucket_return_2 = helper.submit_proposal(proposal_bucket_return)?;
+    // Create and submit two proposals, only voting on the first
+    let (_bucket_return_payment, proposal_bucket) =
+        helper.create_and_submit_basic_proposal(dec!(10000))?;
+    let (_bucket_return_payment_2, _proposal_bucket_2) =
+        helper.create_and_submit_basic_proposal(dec!(10000))?;
     let _ = helper.vote_on_proposal(true, stake_id, 0)?;
 
-    // Advance time by 7 days (end of voting period)
+    // Both fees are locked
+    assert_eq!(helper.get_locked_fee_total()?, dec!(20000));
+    assert!(helper.verify_fee_accounting()?);
+
+    // Advance time by 7 days and finish voting, the second proposal's fee is taken as it didn't reach quorum
     let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
     helper.env.set_current_time(new_time_1);
+    helper.finish_voting(0)?;
+    helper.finish_voting(1)?;
+    helper.execute_proposal_step(0, 1)?;
 
-    // Finish voting and execute all steps with overshoot
-    let _ = helper.finish_voting(0)?;
-    let _ = helper.execute_proposal_step(0, 3)?; // Overshoot: only 2 steps exist
+    assert_eq!(helper.get_locked_fee_total()?, dec!(10000));
+    assert!(helper.verify_fee_accounting()?);
 
-    // Successfully retrieve fee
-    let _ = helper.retrieve_fee(proposal_bucket_return_2)?;
+    // Retrieve the first proposal's fee
+    let _ = helper.retrieve_fee(proposal_bucket)?;
+
+    assert_eq!(helper.get_locked_fee_total()?, dec!(0));
+    assert!(helper.verify_fee_accounting()?);
 
     Ok(())
 }
 
 #[test]
-pub fn test_proposal_with_multiple_steps_succeed_in_individual_calls() -> Result<(), RuntimeError> {
+fn test_proposal_rejected_with_quorum_fee_refunded() -> Result<(), RuntimeError> {
     let mut helper = Helper::new().unwrap();
 
-    // Stake tokens for a single voter
+    // Stake tokens for two voters
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    let bucket_2 = helper.ilis.take(dec!(15000), &mut helper.env)?;
+    let stake_id_2 = helper.stake_without_id(bucket_2)?.0.unwrap();
+
+    // Create, submit and vote on a proposal
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let proposal_bucket_return = helper.submit_proposal(proposal_bucket)?;
+    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
+    let _ = helper.vote_on_proposal(false, stake_id_2, 0)?;
+
+    // Advance time by 7 days and finish voting (rejected, but quorum reached)
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.finish_voting(0)?;
+
+    // Retrieve the fee
+    let returned_payment = helper.retrieve_fee(proposal_bucket_return)?;
+    helper.assert_bucket_eq(&returned_payment, helper.ilis_address, dec!(10000))?;
+
+    Ok(())
+}
+
+#[test]
+pub fn test_proposal_with_multiple_steps_fail_to_retrieve_fee() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake tokens for a single voter
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    // Create a proposal with multiple steps
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let proposal_bucket_return = helper.add_normal_proposal_step(proposal_bucket)?;
+
+    // Submit the proposal and vote
+    let proposal_bucket_return_2 = helper.submit_proposal(proposal_bucket_return)?;
+    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
+
+    // Advance time by 7 days (end of voting period)
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+
+    // Finish voting and execute only the first step
+    let _ = helper.finish_voting(0)?;
+    let _ = helper.execute_proposal_step(0, 1)?;
+
+    // Attempt to retrieve fee (should fail as not all steps are executed)
+    let failure = helper.retrieve_fee(proposal_bucket_return_2);
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+pub fn test_proposal_with_multiple_steps_succeed_in_one_call() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake tokens for a single voter
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    // Create a proposal with multiple steps
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let proposal_bucket_return = helper.add_normal_proposal_step(proposal_bucket)?;
+
+    // Submit the proposal and vote
+    let proposal_bucket_return_2 = helper.submit_proposal(proposal_bucket_return)?;
+    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
+
+    // Advance time by 7 days (end of voting period)
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+
+    // Finish voting and execute all steps in one call
+    let _ = helper.finish_voting(0)?;
+    let _ = helper.execute_proposal_step(0, 2)?;
+
+    // Successfully retrieve fee
+    let _ = helper.retrieve_fee(proposal_bucket_return_2)?;
+
+    Ok(())
+}
+
+#[test]
+pub fn test_hurried_proposal() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+
+    // Stake tokens for a single voter
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    // Create a proposal with multiple steps
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+
+    // Submit the proposal and vote
+    let proposal_bucket_return_2 = helper.submit_proposal(proposal_bucket)?;
+    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
+    let _ = helper.hurry_proposal(0, 1)?;
+
+    // Advance time by 1 day (end of voting period due to hurry)
+    let new_time_1 = helper.env.get_current_time().add_days(1).unwrap();
+    helper.env.set_current_time(new_time_1);
+
+    // Finish voting and execute all steps in one call
+    let _ = helper.finish_voting(0)?;
+    let _ = helper.execute_proposal_step(0, 1)?;
+
+    // Successfully retrieve fee
+    let _ = helper.retrieve_fee(proposal_bucket_return_2)?;
+
+    Ok(())
+}
+
+#[test]
+pub fn test_proposal_with_multiple_steps_succeed_in_one_call_overshoot() -> Result<(), RuntimeError>
+{
+    let mut helper = Helper::new().unwrap();
+
+    // Stake tokens for a single voter
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    // Create a proposal with multiple steps
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let proposal_bucket_return = helper.add_normal_proposal_step(proposal_bucket)?;
+
+    // Submit the proposal and vote
+    let proposal_bucket_return_2 = helper.submit_proposal(proposal_bucket_return)?;
+    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
+
+    // Advance time by 7 days (end of voting period)
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+
+    // Finish voting and execute all steps with overshoot
+    let _ = helper.finish_voting(0)?;
+    let _ = helper.execute_proposal_step(0, 3)?; // Overshoot: only 2 steps exist
+
+    // Successfully retrieve fee
+    let _ = helper.retrieve_fee(proposal_bucket_return_2)?;
+
+    Ok(())
+}
+
+#[test]
+pub fn test_proposal_with_multiple_steps_capped_per_call() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Execute at most 1 step per call
+    helper.set_max_steps_per_execution(1)?;
+
+    // Stake tokens for a single voter
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    // Create a proposal with multiple steps
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let proposal_bucket_return = helper.add_normal_proposal_step(proposal_bucket)?;
+
+    // Submit the proposal and vote
+    let proposal_bucket_return_2 = helper.submit_proposal(proposal_bucket_return)?;
+    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
+
+    // Advance time by 7 days (end of voting period)
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+
+    // Finish voting and request all steps, only the first is executed
+    let _ = helper.finish_voting(0)?;
+    let _ = helper.execute_proposal_step(0, 2)?;
+    assert!(helper.is_executable(0)?.0);
+
+    // Execute the remaining step and retrieve the fee
+    let _ = helper.execute_proposal_step(0, 2)?;
+    assert!(!helper.is_executable(0)?.0);
+    let _ = helper.retrieve_fee(proposal_bucket_return_2)?;
+
+    Ok(())
+}
+
+#[test]
+pub fn test_proposal_with_multiple_steps_succeed_in_individual_calls() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake tokens for a single voter
     let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
     let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
 
@@ -602,6 +1092,37 @@ fn test_proposal_deadline_set_at_submission() -> Result<(), RuntimeError> {
     Ok(())
 }
 
+// Test to ensure the maximum proposal submit delay is counted in days
+#[test]
+fn test_maximum_proposal_submit_delay_in_days() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    // Create a proposal and submit it after 6 days, within the 7 day submit delay
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+
+    let new_time_1 = helper.env.get_current_time().add_days(6).unwrap();
+    helper.env.set_current_time(new_time_1);
+
+    let _ = helper.submit_proposal(proposal_bucket)?;
+    let stake_id = helper.vote_on_proposal(true, stake_id, 0)?;
+
+    // Create a second proposal and submit it after 8 days, which rejects it
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+
+    let new_time_2 = helper.env.get_current_time().add_days(8).unwrap();
+    helper.env.set_current_time(new_time_2);
+
+    let _ = helper.submit_proposal(proposal_bucket)?;
+    let failure = helper.vote_on_proposal(true, stake_id, 1);
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
 #[test]
 fn test_pool_to_real_for_voting() -> Result<(), RuntimeError> {
     let mut helper = Helper::new().unwrap();
@@ -737,59 +1258,189 @@ fn test_reentrancy_step_in_middle_of_proposal_succeed_execute() -> Result<(), Ru
 }
 
 #[test]
-fn test_delegate_and_vote_and_unstake_immediately_fail() -> Result<(), RuntimeError> {
+fn test_get_pending_reentrancy_step() -> Result<(), RuntimeError> {
     let mut helper = Helper::new().unwrap();
 
-    // Create and submit a proposal
-    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
-    let _ = helper.submit_proposal(proposal_bucket)?;
-
-    // Stake tokens for two voters
+    // Stake tokens for a single voter
     let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
     let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
 
-    let bucket_2 = helper.ilis.take(dec!(10000), &mut helper.env)?;
-    let stake_id_2 = helper.stake_without_id(bucket_2)?.0.unwrap();
+    // Create a proposal with a reentrancy step
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let proposal_bucket_return = helper.add_reentrancy_proposal_step(proposal_bucket)?;
+    let _proposal_bucket_return_2 = helper.submit_proposal(proposal_bucket_return)?;
 
-    // Delegate vote, vote, and then undelegate
-    let stake_id_returned = helper.delegate_vote(stake_id, NonFungibleLocalId::integer(2))?;
-    let _ = helper.vote_on_proposal(true, stake_id_2, 0)?;
-    let stake_id_returned_2 = helper.undelegate_vote(stake_id_returned)?;
+    // Vote on the proposal
+    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
 
-    // Attempt to unstake immediately (should fail)
-    let failure = helper.start_unstake(stake_id_returned_2, dec!(5000));
+    // Advance time by 7 days (end of voting period)
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
 
-    assert!(failure.is_err());
+    // No step is pending before execution reaches the reentrancy step
+    let _ = helper.finish_voting(0)?;
+    let pending_before = helper.get_pending_reentrancy_step(0)?;
+
+    // Execute steps up to the reentrancy step, which is then pending in the proxy
+    let _ = helper.execute_proposal_step(0, 2)?;
+    let pending_during = helper.get_pending_reentrancy_step(0)?;
+
+    // Complete the reentrancy step, after which nothing is pending anymore
+    let _ = helper.execute_reentrancy(0)?;
+    let pending_after = helper.get_pending_reentrancy_step(0)?;
+
+    assert!(pending_before.is_none());
+    assert_eq!(
+        pending_during,
+        Some((
+            ComponentAddress::try_from(helper.governance.0.clone()).unwrap(),
+            "set_parameters".to_string()
+        ))
+    );
+    assert!(pending_after.is_none());
 
     Ok(())
 }
 
 #[test]
-fn test_delegate_and_vote_and_unstake_succeed() -> Result<(), RuntimeError> {
+fn test_abort_reentrancy_step_and_retry() -> Result<(), RuntimeError> {
     let mut helper = Helper::new().unwrap();
 
-    // Create and submit a proposal
-    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
-    let _ = helper.submit_proposal(proposal_bucket)?;
-
-    // Stake tokens for two voters
+    // Stake tokens for a single voter
     let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
     let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
 
-    let bucket_2 = helper.ilis.take(dec!(10000), &mut helper.env)?;
-    let stake_id_2 = helper.stake_without_id(bucket_2)?.0.unwrap();
+    // Create a proposal with a reentrancy step
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let proposal_bucket_return = helper.add_reentrancy_proposal_step(proposal_bucket)?;
+    let proposal_bucket_return_2 = helper.submit_proposal(proposal_bucket_return)?;
 
-    // Delegate vote, vote, and then undelegate
-    let stake_id_returned = helper.delegate_vote(stake_id, NonFungibleLocalId::integer(2))?;
-    let _ = helper.vote_on_proposal(true, stake_id_2, 0)?;
-    let stake_id_returned_2 = helper.undelegate_vote(stake_id_returned)?;
+    // Vote on the proposal
+    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
 
-    // Advance time by 8 days
-    let new_time_1 = helper.env.get_current_time().add_days(8).unwrap();
+    // Advance time by 7 days (end of voting period)
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
     helper.env.set_current_time(new_time_1);
 
-    // Attempt to unstake (should succeed)
-    let _ = helper.start_unstake(stake_id_returned_2, dec!(5000))?;
+    // Finish voting and execute steps up to the reentrancy step
+    let _ = helper.finish_voting(0)?;
+    let _ = helper.execute_proposal_step(0, 2)?;
+
+    // Abort the pending reentrancy step (as the DAO), allowing a retry
+    helper.env.disable_auth_module();
+    let _ = helper.abort_reentrancy_step(0, false)?;
+    helper.env.enable_auth_module();
+    let pending_after_abort = helper.get_pending_reentrancy_step(0)?;
+
+    // Retry the reentrancy step and complete it
+    let _ = helper.execute_proposal_step(0, 1)?;
+    let _ = helper.execute_reentrancy(0)?;
+
+    // Retrieve fee, as the proposal has now been executed
+    let _ = helper.retrieve_fee(proposal_bucket_return_2)?;
+
+    // Completing the reentrancy step again fails, as it was only dispatched once after the retry
+    let failure_second_run = helper.execute_reentrancy(0);
+
+    assert!(pending_after_abort.is_none());
+    assert!(failure_second_run.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_aborted_reentrancy_step_cannot_be_completed() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake tokens for a single voter
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    // Create a proposal with a reentrancy step
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let proposal_bucket_return = helper.add_reentrancy_proposal_step(proposal_bucket)?;
+    let _proposal_bucket_return_2 = helper.submit_proposal(proposal_bucket_return)?;
+
+    // Vote on the proposal
+    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
+
+    // Advance time by 7 days (end of voting period)
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+
+    // Finish voting and execute steps up to the reentrancy step
+    let _ = helper.finish_voting(0)?;
+    let _ = helper.execute_proposal_step(0, 2)?;
+
+    // Abort the pending reentrancy step (as the DAO), marking the proposal as failed
+    helper.env.disable_auth_module();
+    let _ = helper.abort_reentrancy_step(0, true)?;
+    helper.env.enable_auth_module();
+
+    // The step of the failed proposal can't be completed through the ReentrancyProxy anymore
+    let failure_reentrancy = helper.execute_reentrancy(0);
+    let info = helper.get_proposal_info(0)?.unwrap();
+
+    assert!(failure_reentrancy.is_err());
+    assert_eq!(info.status, ProposalStatus::Rejected);
+
+    Ok(())
+}
+
+#[test]
+fn test_delegate_and_vote_and_unstake_immediately_fail() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Create and submit a proposal
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let _ = helper.submit_proposal(proposal_bucket)?;
+
+    // Stake tokens for two voters
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    let bucket_2 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_2 = helper.stake_without_id(bucket_2)?.0.unwrap();
+
+    // Delegate vote, vote, and then undelegate
+    let stake_id_returned = helper.delegate_vote(stake_id, NonFungibleLocalId::integer(2))?;
+    let _ = helper.vote_on_proposal(true, stake_id_2, 0)?;
+    let stake_id_returned_2 = helper.undelegate_vote(stake_id_returned)?;
+
+    // Attempt to unstake immediately (should fail)
+    let failure = helper.start_unstake(stake_id_returned_2, dec!(5000));
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_delegate_and_vote_and_unstake_succeed() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Create and submit a proposal
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let _ = helper.submit_proposal(proposal_bucket)?;
+
+    // Stake tokens for two voters
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    let bucket_2 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_2 = helper.stake_without_id(bucket_2)?.0.unwrap();
+
+    // Delegate vote, vote, and then undelegate
+    let stake_id_returned = helper.delegate_vote(stake_id, NonFungibleLocalId::integer(2))?;
+    let _ = helper.vote_on_proposal(true, stake_id_2, 0)?;
+    let stake_id_returned_2 = helper.undelegate_vote(stake_id_returned)?;
+
+    // Advance time by 8 days
+    let new_time_1 = helper.env.get_current_time().add_days(8).unwrap();
+    helper.env.set_current_time(new_time_1);
+
+    // Attempt to unstake (should succeed)
+    let _ = helper.start_unstake(stake_id_returned_2, dec!(5000))?;
 
     Ok(())
 }
@@ -921,3 +1572,838 @@ fn test_delegate_and_stake_extra_win_vote_through_delegation() -> Result<(), Run
 
     Ok(())
 }
+
+#[test]
+fn test_stake_to_voting_id_does_not_change_cast_vote() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Create and submit a proposal
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let _ = helper.submit_proposal(proposal_bucket)?;
+
+    // Stake tokens for two voters
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    let bucket_2 = helper.ilis.take(dec!(15000), &mut helper.env)?;
+    let stake_id_2 = helper.stake_without_id(bucket_2)?.0.unwrap();
+
+    // Vote, then stake extra tokens to the ID that voted for
+    let stake_id_returned = helper.vote_on_proposal(true, stake_id, 0)?;
+    let _ = helper.vote_on_proposal(false, stake_id_2, 0)?;
+
+    let bucket_3 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let _stake_id_returned_2 = helper.stake_with_id(bucket_3, stake_id_returned)?;
+
+    // Advance time by 8 days
+    let new_time_1 = helper.env.get_current_time().add_days(8).unwrap();
+    helper.env.set_current_time(new_time_1);
+
+    // Finish voting, the extra stake did not count, so the proposal is rejected and can't be executed
+    let _ = helper.finish_voting(0)?;
+    let failure = helper.execute_proposal_step(0, 1);
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_vote_decay_for_inactive_stakers() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Halve the vote power of IDs for every 20 days they haven't voted
+    helper.set_vote_decay(Some(VoteDecay {
+        period_days: 20,
+        factor: dec!("0.5"),
+        floor: dec!("0.5"),
+    }))?;
+
+    // Stake tokens for two voters
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    let bucket_2 = helper.ilis.take(dec!(15000), &mut helper.env)?;
+    let stake_id_2 = helper.stake_without_id(bucket_2)?.0.unwrap();
+
+    // Both voters vote on a first proposal
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let _ = helper.submit_proposal(proposal_bucket)?;
+    let stake_id = helper.vote_on_proposal(true, stake_id, 0)?;
+    let stake_id_2 = helper.vote_on_proposal(true, stake_id_2, 0)?;
+
+    // Only the first voter votes on a second proposal, 8 days later
+    let new_time_1 = helper.env.get_current_time().add_days(8).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let (_bucket_return_payment_2, proposal_bucket_2) =
+        helper.create_basic_proposal(dec!(10000))?;
+    let _ = helper.submit_proposal(proposal_bucket_2)?;
+    let stake_id = helper.vote_on_proposal(true, stake_id, 1)?;
+
+    // 17 days later, the second voter has been inactive for a full period, so their vote power is halved
+    let new_time_2 = helper.env.get_current_time().add_days(17).unwrap();
+    helper.env.set_current_time(new_time_2);
+    let (_bucket_return_payment_3, proposal_bucket_3) =
+        helper.create_basic_proposal(dec!(10000))?;
+    let _ = helper.submit_proposal(proposal_bucket_3)?;
+    let _ = helper.vote_on_proposal(true, stake_id, 2)?;
+    let _ = helper.vote_on_proposal(false, stake_id_2, 2)?;
+
+    // Advance time by 8 days
+    let new_time_3 = helper.env.get_current_time().add_days(8).unwrap();
+    helper.env.set_current_time(new_time_3);
+
+    // Finish voting, the decayed vote against is outweighed, so the proposal can be executed
+    let _ = helper.finish_voting(2)?;
+    let _ = helper.execute_proposal_step(2, 1)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_vote_decay_without_earlier_vote() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Halve the vote power of IDs for every 20 days they haven't voted
+    helper.set_vote_decay(Some(VoteDecay {
+        period_days: 20,
+        factor: dec!("0.5"),
+        floor: dec!("0.25"),
+    }))?;
+
+    // Stake tokens to an ID that never votes
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let _stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+    let vote_power_1 = helper.get_effective_vote_power(NonFungibleLocalId::integer(1))?;
+
+    // 20 days after its creation, its vote power is halved
+    let new_time_1 = helper.env.get_current_time().add_days(20).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let vote_power_2 = helper.get_effective_vote_power(NonFungibleLocalId::integer(1))?;
+
+    // The vote power never drops below the floor
+    let new_time_2 = helper.env.get_current_time().add_days(100).unwrap();
+    helper.env.set_current_time(new_time_2);
+    let vote_power_3 = helper.get_effective_vote_power(NonFungibleLocalId::integer(1))?;
+
+    assert_eq!(vote_power_1, dec!(10000));
+    assert_eq!(vote_power_2, dec!(5000));
+    assert_eq!(vote_power_3, dec!(2500));
+
+    Ok(())
+}
+
+#[test]
+fn test_vote_with_extra_lock() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Give a 10% vote-weight bonus per extra lock day, up to 10 days
+    helper.set_conviction_voting(dec!("0.1"), 10)?;
+
+    // Stake tokens for two voters
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    let bucket_2 = helper.ilis.take(dec!(15000), &mut helper.env)?;
+    let stake_id_2 = helper.stake_without_id(bucket_2)?.0.unwrap();
+
+    // Create and submit a proposal
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let _ = helper.submit_proposal(proposal_bucket)?;
+
+    // Locking for longer than allowed fails
+    let bucket_3 = helper.ilis.take(dec!(1000), &mut helper.env)?;
+    let stake_id_3 = helper.stake_without_id(bucket_3)?.0.unwrap();
+    let failure_lock = helper.vote_on_proposal_with_extra_lock(true, stake_id_3, 0, 11);
+
+    // The smaller stake locks for 10 extra days, doubling its vote weight and outweighing the larger stake
+    let stake_id = helper.vote_on_proposal_with_extra_lock(true, stake_id, 0, 10)?;
+    let _ = helper.vote_on_proposal(false, stake_id_2, 0)?;
+
+    // Advance time by 7 days, finish voting and execute the proposal
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+    helper.finish_voting(0)?;
+    helper.execute_proposal_step(0, 1)?;
+
+    // The turnout only counts the staked tokens that voted, not the conviction multiplier
+    let stats = helper.get_governance_stats()?;
+
+    // The tokens are still locked for the extra days
+    let failure_unstake = helper.start_unstake(stake_id, dec!(5000));
+
+    assert!(stats.average_turnout < dec!(1));
+    assert!(failure_lock.is_err());
+    assert!(failure_unstake.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_vote_weight_cap() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Cap the vote of a single staking ID at 25% of the total amount staked
+    helper.set_max_vote_weight_fraction(dec!("0.25"))?;
+    let failure_fraction = helper.set_max_vote_weight_fraction(dec!("1.5"));
+
+    // Stake tokens for a whale and two smaller voters, 50000 in total
+    let bucket_1 = helper.ilis.take(dec!(30000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    let bucket_2 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_2 = helper.stake_without_id(bucket_2)?.0.unwrap();
+
+    let bucket_3 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_3 = helper.stake_without_id(bucket_3)?.0.unwrap();
+
+    // Create and submit a proposal
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let _ = helper.submit_proposal(proposal_bucket)?;
+
+    // The whale votes against, but only counts for 12500, so the smaller voters outweigh it
+    let _ = helper.vote_on_proposal(false, stake_id, 0)?;
+    let _ = helper.vote_on_proposal(true, stake_id_2, 0)?;
+    let _ = helper.vote_on_proposal(true, stake_id_3, 0)?;
+
+    // Advance time by 7 days, finish voting and execute the proposal
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+    helper.finish_voting(0)?;
+    helper.execute_proposal_step(0, 1)?;
+
+    assert!(failure_fraction.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_approval_threshold_bps() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Set the approval threshold to 60%
+    helper.set_approval_threshold_bps(6000)?;
+    let approval_threshold_bps = helper.get_approval_threshold_bps()?;
+
+    // Create and submit a proposal
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let _ = helper.submit_proposal(proposal_bucket)?;
+
+    // Stake tokens for two voters
+    let bucket_1 = helper.ilis.take(dec!(11000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    let bucket_2 = helper.ilis.take(dec!(9000), &mut helper.env)?;
+    let stake_id_2 = helper.stake_without_id(bucket_2)?.0.unwrap();
+
+    // Vote 55% for, which is below the threshold
+    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
+    let _ = helper.vote_on_proposal(false, stake_id_2, 0)?;
+
+    // Advance time by 8 days
+    let new_time_1 = helper.env.get_current_time().add_days(8).unwrap();
+    helper.env.set_current_time(new_time_1);
+
+    // Finish voting, the proposal is rejected and can't be executed
+    let _ = helper.finish_voting(0)?;
+    let failure = helper.execute_proposal_step(0, 1);
+
+    assert_eq!(approval_threshold_bps, 6000);
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_get_parameters() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Read the parameters set at instantiation
+    let parameters = helper.get_governance_parameters()?;
+
+    assert_eq!(parameters.fee, dec!(10000));
+    assert_eq!(parameters.proposal_duration, 3);
+    assert_eq!(parameters.quorum, dec!(10000));
+    assert_eq!(parameters.approval_threshold, dec!("0.5"));
+    assert_eq!(parameters.maximum_proposal_submit_delay, 7);
+    assert_eq!(parameters.min_title_len, 0);
+    assert_eq!(parameters.min_description_len, 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_early_voter_reward() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Fund and set an early voter reward of 1000 tokens per proposal
+    let reward_bucket = helper.ilis.take(dec!(1000), &mut helper.env)?;
+    helper.fund_early_voter_rewards(reward_bucket)?;
+    helper.set_early_voter_reward(dec!(1000))?;
+
+    // Stake tokens for two voters
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    let bucket_2 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_2 = helper.stake_without_id(bucket_2)?.0.unwrap();
+
+    // Create and submit a proposal, with a voting period of 3 days
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let _ = helper.submit_proposal(proposal_bucket)?;
+
+    // First voter votes with 3 days left, second voter with 2 days left
+    let stake_id = helper.vote_on_proposal(true, stake_id, 0)?;
+
+    let new_time_1 = helper.env.get_current_time().add_days(1).unwrap();
+    helper.env.set_current_time(new_time_1);
+
+    let stake_id_2 = helper.vote_on_proposal(true, stake_id_2, 0)?;
+
+    // Advance time past the deadline and finish voting
+    let new_time_2 = helper.env.get_current_time().add_days(3).unwrap();
+    helper.env.set_current_time(new_time_2);
+    let _ = helper.finish_voting(0)?;
+
+    // Claim the rewards, split 3:2
+    let (reward_1, stake_id) = helper.claim_early_voter_reward(0, stake_id)?;
+    let (reward_2, _stake_id_2) = helper.claim_early_voter_reward(0, stake_id_2)?;
+
+    helper.assert_bucket_eq(&reward_1, helper.ilis_address, dec!(600))?;
+    helper.assert_bucket_eq(&reward_2, helper.ilis_address, dec!(400))?;
+
+    // Claiming twice fails
+    let failure = helper.claim_early_voter_reward(0, stake_id);
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_proposal_recover_from_veto_mode() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.set_allow_veto_recovery(true)?;
+
+    // Stake tokens for three voters
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+    let bucket_2 = helper.ilis.take(dec!(15000), &mut helper.env)?;
+    let stake_id_2 = helper.stake_without_id(bucket_2)?.0.unwrap();
+    let bucket_3 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_3 = helper.stake_without_id(bucket_3)?.0.unwrap();
+
+    // Create and submit a proposal, with a voting period of 3 days
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let _ = helper.submit_proposal(proposal_bucket)?;
+
+    // First vote for
+    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
+
+    // Advance time by 2 days (last day), vote against, entering veto mode (extending the deadline by a day)
+    let new_time_1 = helper.env.get_current_time().add_days(2).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.vote_on_proposal(false, stake_id_2, 0)?;
+
+    // Advance time by 1 day (last day of veto mode), vote for, recovering from veto mode
+    let new_time_2 = helper.env.get_current_time().add_days(1).unwrap();
+    helper.env.set_current_time(new_time_2);
+    let _ = helper.vote_on_proposal(true, stake_id_3, 0)?;
+
+    // Advance time past the deadline
+    let new_time_3 = helper.env.get_current_time().add_days(1).unwrap();
+    helper.env.set_current_time(new_time_3);
+
+    // Finish voting and execute (should succeed)
+    let _ = helper.finish_voting(0)?;
+    let _ = helper.execute_proposal_step(0, 1)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_veto_mode_extends_deadline_once() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.set_allow_veto_recovery(true)?;
+
+    // Stake tokens for three voters
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+    let bucket_2 = helper.ilis.take(dec!(15000), &mut helper.env)?;
+    let stake_id_2 = helper.stake_without_id(bucket_2)?.0.unwrap();
+    let bucket_3 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_3 = helper.stake_without_id(bucket_3)?.0.unwrap();
+
+    // Create and submit a proposal, with a voting period of 3 days
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let _ = helper.submit_proposal(proposal_bucket)?;
+    let original_deadline = helper.get_proposal_info(0)?.unwrap().deadline;
+
+    // First vote for
+    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
+
+    // Advance time by 2 days (last day), vote against, entering veto mode (extending the deadline by a day)
+    let new_time_1 = helper.env.get_current_time().add_days(2).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.vote_on_proposal(false, stake_id_2, 0)?;
+    let extended_deadline = helper.get_proposal_info(0)?.unwrap().deadline;
+
+    // Advance time by 1 day, vote for, recovering from veto mode
+    let new_time_2 = helper.env.get_current_time().add_days(1).unwrap();
+    helper.env.set_current_time(new_time_2);
+    let stake_id_3 = helper.vote_on_proposal(true, stake_id_3, 0)?;
+    let info_recovered = helper.get_proposal_info(0)?.unwrap();
+
+    // Switch the vote back to against, re-entering veto mode without extending the deadline again
+    let _ = helper.change_vote(false, stake_id_3, 0)?;
+    let info_vetoed = helper.get_proposal_info(0)?.unwrap();
+
+    assert_eq!(extended_deadline, original_deadline.add_days(1).unwrap());
+    assert!(info_recovered.status == ProposalStatus::Ongoing);
+    assert!(info_vetoed.status == ProposalStatus::VetoMode);
+    assert_eq!(info_vetoed.deadline, extended_deadline);
+
+    Ok(())
+}
+
+#[test]
+fn test_proposal_no_recovery_from_veto_mode_by_default() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake tokens for three voters
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+    let bucket_2 = helper.ilis.take(dec!(15000), &mut helper.env)?;
+    let stake_id_2 = helper.stake_without_id(bucket_2)?.0.unwrap();
+    let bucket_3 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_3 = helper.stake_without_id(bucket_3)?.0.unwrap();
+
+    // Create and submit a proposal, with a voting period of 3 days
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let _ = helper.submit_proposal(proposal_bucket)?;
+
+    // First vote for
+    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
+
+    // Advance time by 2 days (last day), vote against, entering veto mode (extending the deadline by a day)
+    let new_time_1 = helper.env.get_current_time().add_days(2).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.vote_on_proposal(false, stake_id_2, 0)?;
+
+    // Advance time by 1 day (last day of veto mode), voting for is impossible
+    let new_time_2 = helper.env.get_current_time().add_days(1).unwrap();
+    helper.env.set_current_time(new_time_2);
+    let failure = helper.vote_on_proposal(true, stake_id_3, 0);
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_proposer_fee_discount() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // 25% discount per executed proposal, capped at 50%
+    helper.set_proposer_discount(dec!("0.25"), dec!("0.5"))?;
+    let proposer_badge = helper.create_account_owner_badge()?;
+
+    // Stake tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    // First proposal pays the full fee
+    let (bucket_return_payment, _proposal_bucket) =
+        helper.create_and_submit_basic_proposal_as_proposer(dec!(10000), &proposer_badge)?;
+    helper.assert_bucket_eq(&bucket_return_payment, helper.ilis_address, dec!(0))?;
+
+    // Vote, finish voting and execute the proposal
+    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
+    let new_time_1 = helper.env.get_current_time().add_days(3).unwrap();
+    helper.env.set_current_time(new_time_1);
+    helper.finish_voting(0)?;
+    helper.execute_proposal_step(0, 1)?;
+
+    // Second proposal is discounted by 25%
+    let (bucket_return_payment, _proposal_bucket) =
+        helper.create_and_submit_basic_proposal_as_proposer(dec!(10000), &proposer_badge)?;
+    helper.assert_bucket_eq(&bucket_return_payment, helper.ilis_address, dec!(2500))?;
+
+    // Proposals without a proposer proof still pay the full fee
+    let (bucket_return_payment, _proposal_bucket) =
+        helper.create_and_submit_basic_proposal(dec!(10000))?;
+    helper.assert_bucket_eq(&bucket_return_payment, helper.ilis_address, dec!(0))?;
+
+    Ok(())
+}
+
+#[test]
+fn test_proposer_cooldown() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Proposers have to wait 3 days between proposals
+    helper.set_proposer_cooldown(3)?;
+    let proposer_badge = helper.create_account_owner_badge()?;
+
+    // First proposal succeeds
+    let _ = helper.create_and_submit_basic_proposal_as_proposer(dec!(10000), &proposer_badge)?;
+
+    // A second proposal within the cooldown fails
+    let failure_cooldown =
+        helper.create_and_submit_basic_proposal_as_proposer(dec!(10000), &proposer_badge);
+
+    // Proposals without a proposer proof fail while a cooldown is set
+    let failure_no_proof = helper.create_and_submit_basic_proposal(dec!(10000));
+
+    // After the cooldown, the proposer can propose again
+    let new_time_1 = helper.env.get_current_time().add_days(3).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.create_and_submit_basic_proposal_as_proposer(dec!(10000), &proposer_badge)?;
+
+    assert!(failure_cooldown.is_err());
+    assert!(failure_no_proof.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_fail_stuck_proposal() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    // Create and submit a proposal, and vote for it
+    let (_bucket_return_payment, proposal_bucket) =
+        helper.create_and_submit_basic_proposal(dec!(10000))?;
+    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
+
+    // Advance time past the deadline and finish voting, accepting the proposal
+    let new_time_1 = helper.env.get_current_time().add_days(3).unwrap();
+    helper.env.set_current_time(new_time_1);
+    helper.finish_voting(0)?;
+
+    // The proposal can't be failed while it can still be executed
+    let failure_early = helper.fail_stuck_proposal(0);
+
+    // Advance time past the execution window, the proposal can't be executed anymore
+    let new_time_2 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_2);
+    let (executable, _reason) = helper.is_executable(0)?;
+    let failure_late_execute = helper.execute_proposal_step(0, 1);
+
+    // Fail the proposal (as the DAO)
+    helper.fail_stuck_proposal(0)?;
+
+    // The proposal can't be executed anymore, but the fee is refunded
+    let failure_execute = helper.execute_proposal_step(0, 1);
+    let returned_payment = helper.retrieve_fee(proposal_bucket)?;
+    helper.assert_bucket_eq(&returned_payment, helper.ilis_address, dec!(10000))?;
+
+    assert!(failure_early.is_err());
+    assert!(!executable);
+    assert!(failure_late_execute.is_err());
+    assert!(failure_execute.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_cosponsor_proposal_to_meet_min_sponsor_stake() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Require a combined sponsor stake of 15000 tokens to submit a proposal
+    helper.set_min_sponsor_stake(dec!(15000))?;
+
+    // Stake tokens for two sponsors
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    let bucket_2 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_2 = helper.stake_without_id(bucket_2)?.0.unwrap();
+
+    // Both sponsors co-sponsor a proposal, which can then be submitted
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let (proposal_bucket, stake_id) = helper.cosponsor_proposal(proposal_bucket, stake_id)?;
+    let (proposal_bucket, _stake_id_2) = helper.cosponsor_proposal(proposal_bucket, stake_id_2)?;
+    let _ = helper.submit_proposal(proposal_bucket)?;
+
+    // A proposal with a single sponsor can't be submitted
+    let (_bucket_return_payment_2, proposal_bucket_2) =
+        helper.create_basic_proposal(dec!(10000))?;
+    let (proposal_bucket_2, _stake_id) = helper.cosponsor_proposal(proposal_bucket_2, stake_id)?;
+    let failure = helper.submit_proposal(proposal_bucket_2);
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_min_proposer_stake() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Require a proposer stake of 15000 tokens to create a proposal
+    helper.set_governance_parameters(dec!(10000), 3, dec!(10000), dec!("0.5"), 7, dec!(15000))?;
+
+    // Stake tokens for a small and a large proposer
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    let bucket_2 = helper.ilis.take(dec!(20000), &mut helper.env)?;
+    let stake_id_2 = helper.stake_without_id(bucket_2)?.0.unwrap();
+
+    // The large proposer can create a proposal
+    let (_bucket_return_payment, _proposal_bucket) =
+        helper.create_basic_proposal_with_stake_id(dec!(10000), &stake_id_2)?;
+
+    // The small proposer can't create a proposal, nor can a proposer without staking ID
+    let failure_below = helper.create_basic_proposal_with_stake_id(dec!(10000), &stake_id);
+    let failure_without_id = helper.create_basic_proposal(dec!(10000));
+
+    assert!(failure_below.is_err());
+    assert!(failure_without_id.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_min_proposer_stake_separate_from_sponsor_stake() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Require a proposer stake of 5000 tokens to create, and a combined sponsor stake of 15000 tokens to submit a proposal
+    helper.set_min_proposer_stake(dec!(5000))?;
+    helper.set_min_sponsor_stake(dec!(15000))?;
+
+    // Stake tokens for a small proposer, a large proposer and a sponsor
+    let bucket_1 = helper.ilis.take(dec!(1000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    let bucket_2 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_2 = helper.stake_without_id(bucket_2)?.0.unwrap();
+
+    let bucket_3 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_3 = helper.stake_without_id(bucket_3)?.0.unwrap();
+
+    // The small proposer can't create a proposal, as sponsors can only join after creation
+    let failure_small_proposer = helper.create_basic_proposal_with_stake_id(dec!(10000), &stake_id);
+
+    // The large proposer can create a proposal, but its stake alone doesn't meet the minimum sponsor stake
+    let (_bucket_return_payment, proposal_bucket) =
+        helper.create_basic_proposal_with_stake_id(dec!(10000), &stake_id_2)?;
+    let (proposal_bucket, stake_id_2) = helper.cosponsor_proposal(proposal_bucket, stake_id_2)?;
+    let failure_single_sponsor = helper.submit_proposal(proposal_bucket);
+
+    // Together with a co-sponsor, the combined stake meets the minimum sponsor stake
+    let (_bucket_return_payment_2, proposal_bucket_2) =
+        helper.create_basic_proposal_with_stake_id(dec!(10000), &stake_id_2)?;
+    let (proposal_bucket_2, _stake_id_2) =
+        helper.cosponsor_proposal(proposal_bucket_2, stake_id_2)?;
+    let (proposal_bucket_2, _stake_id_3) =
+        helper.cosponsor_proposal(proposal_bucket_2, stake_id_3)?;
+    let _ = helper.submit_proposal(proposal_bucket_2)?;
+
+    assert!(failure_small_proposer.is_err());
+    assert!(failure_single_sponsor.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_individual_parameter_setters() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Change every parameter on its own
+    helper.set_fee(dec!(5000))?;
+    helper.set_proposal_duration(5)?;
+    helper.set_quorum(dec!(20000))?;
+    helper.set_approval_threshold(dec!("0.6"))?;
+    helper.set_maximum_proposal_submit_delay(14)?;
+    helper.set_min_proposer_stake(dec!(1000))?;
+
+    let parameters = helper.get_governance_parameters()?;
+
+    // Changing a single parameter leaves the others untouched
+    helper.set_quorum(dec!(30000))?;
+    let parameters_2 = helper.get_governance_parameters()?;
+
+    assert_eq!(parameters.fee, dec!(5000));
+    assert_eq!(parameters.proposal_duration, 5);
+    assert_eq!(parameters.quorum, dec!(20000));
+    assert_eq!(parameters.approval_threshold, dec!("0.6"));
+    assert_eq!(parameters.maximum_proposal_submit_delay, 14);
+    assert_eq!(parameters.min_proposer_stake, dec!(1000));
+    assert_eq!(parameters_2.fee, dec!(5000));
+    assert_eq!(parameters_2.proposal_duration, 5);
+    assert_eq!(parameters_2.quorum, dec!(30000));
+    assert_eq!(parameters_2.approval_threshold, dec!("0.6"));
+    assert_eq!(parameters_2.maximum_proposal_submit_delay, 14);
+    assert_eq!(parameters_2.min_proposer_stake, dec!(1000));
+
+    Ok(())
+}
+
+#[test]
+fn test_individual_parameter_setter_bounds() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Attempt to set every parameter out of bounds (should all fail)
+    let fee_failure = helper.set_fee(dec!(0));
+    let duration_failure = helper.set_proposal_duration(0);
+    let quorum_failure = helper.set_quorum(dec!(0));
+    let threshold_zero_failure = helper.set_approval_threshold(dec!(0));
+    let threshold_above_one_failure = helper.set_approval_threshold(dec!("1.1"));
+    let submit_delay_failure = helper.set_maximum_proposal_submit_delay(0);
+    let proposer_stake_failure = helper.set_min_proposer_stake(dec!(-1));
+
+    // Setting the approval threshold to exactly 1 is allowed
+    helper.set_approval_threshold(dec!(1))?;
+
+    // The failed setters left the parameters untouched
+    let parameters = helper.get_governance_parameters()?;
+
+    assert!(fee_failure.is_err());
+    assert!(duration_failure.is_err());
+    assert!(quorum_failure.is_err());
+    assert!(threshold_zero_failure.is_err());
+    assert!(threshold_above_one_failure.is_err());
+    assert!(submit_delay_failure.is_err());
+    assert!(proposer_stake_failure.is_err());
+    assert_eq!(parameters.fee, dec!(10000));
+    assert_eq!(parameters.proposal_duration, 3);
+    assert_eq!(parameters.quorum, dec!(10000));
+    assert_eq!(parameters.approval_threshold, dec!(1));
+    assert_eq!(parameters.maximum_proposal_submit_delay, 7);
+    assert_eq!(parameters.min_proposer_stake, dec!(0));
+
+    Ok(())
+}
+
+#[test]
+fn test_proposal_lifecycle_events() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake tokens for two voters
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_1 = helper.stake_without_id(bucket_1)?.0.unwrap();
+    let bucket_2 = helper.ilis.take(dec!(5000), &mut helper.env)?;
+    let stake_id_2 = helper.stake_without_id(bucket_2)?.0.unwrap();
+
+    // Create and submit a proposal, the first voter votes for it and the second against it
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let _ = helper.submit_proposal(proposal_bucket)?;
+    let _ = helper.vote_on_proposal(true, stake_id_1, 0)?;
+    let _ = helper.vote_on_proposal(false, stake_id_2, 0)?;
+
+    // Advance time past the deadline, finish voting and execute the proposal
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+    helper.finish_voting(0)?;
+    helper.execute_proposal_step(0, 1)?;
+
+    let created: Vec<ProposalCreatedEvent> = helper.get_emitted_events("ProposalCreatedEvent");
+    let submitted: Vec<ProposalSubmittedEvent> =
+        helper.get_emitted_events("ProposalSubmittedEvent");
+    let votes: Vec<VoteCastEvent> = helper.get_emitted_events("VoteCastEvent");
+    let finished: Vec<VotingFinishedEvent> = helper.get_emitted_events("VotingFinishedEvent");
+    let executed: Vec<ProposalStepExecutedEvent> =
+        helper.get_emitted_events("ProposalStepExecutedEvent");
+
+    assert_eq!(created.len(), 1);
+    assert_eq!(created[0].proposal_id, 0);
+    assert_eq!(created[0].fee_paid, dec!(10000));
+    assert_eq!(submitted.len(), 1);
+    assert_eq!(submitted[0].proposal_id, 0);
+    assert_eq!(votes.len(), 2);
+    assert!(votes[0].for_against);
+    assert!(votes[0].vote_power > dec!(0));
+    assert_eq!(votes[0].votes_for, votes[0].vote_power);
+    assert!(!votes[1].for_against);
+    assert_eq!(votes[1].votes_for, votes[0].vote_power);
+    assert_eq!(votes[1].votes_against, votes[1].vote_power);
+    assert_eq!(finished.len(), 1);
+    assert!(finished[0].status == ProposalStatus::Accepted);
+    assert!(finished[0].reached_quorum);
+    assert_eq!(executed.len(), 1);
+    assert_eq!(executed[0].proposal_id, 0);
+    assert_eq!(executed[0].step_index, 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_get_proposal_info() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake tokens for a single voter
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+
+    // Create a proposal with two steps
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let proposal_bucket = helper.add_normal_proposal_step(proposal_bucket)?;
+    let info_building = helper.get_proposal_info(0)?.unwrap();
+
+    // Submit the proposal and vote for it
+    let _ = helper.submit_proposal(proposal_bucket)?;
+    let _ = helper.vote_on_proposal(true, stake_id, 0)?;
+    let info_ongoing = helper.get_proposal_info(0)?.unwrap();
+
+    // A proposal that doesn't exist has no info
+    let info_nonexistent = helper.get_proposal_info(1)?;
+
+    assert_eq!(info_building.title, "Test Proposal".to_string());
+    assert_eq!(
+        info_building.description,
+        "This is a test proposal".to_string()
+    );
+    assert!(info_building.status == ProposalStatus::Building);
+    assert_eq!(info_building.steps, 2);
+    assert_eq!(info_building.next_index, 0);
+    assert!(!info_building.reentrancy);
+    assert_eq!(info_building.votes_for, dec!(0));
+    assert!(info_ongoing.status == ProposalStatus::Ongoing);
+    assert!(info_ongoing.votes_for > dec!(0));
+    assert_eq!(info_ongoing.votes_against, dec!(0));
+    assert!(info_ongoing.deadline.compare(
+        helper.env.get_current_time().add_days(2).unwrap(),
+        TimeComparisonOperator::Gt
+    ));
+    assert!(info_nonexistent.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_min_vote_power() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Require a vote power of 5000 to cast a vote
+    helper.set_min_vote_power(dec!(5000))?;
+
+    // Stake tokens for a dust voter and a regular voter
+    let bucket_1 = helper.ilis.take(dec!(1000), &mut helper.env)?;
+    let stake_id_1 = helper.stake_without_id(bucket_1)?.0.unwrap();
+    let bucket_2 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_2 = helper.stake_without_id(bucket_2)?.0.unwrap();
+
+    // Create and submit a proposal, the regular voter can vote on it
+    let (_bucket_return_payment, proposal_bucket) = helper.create_basic_proposal(dec!(10000))?;
+    let _ = helper.submit_proposal(proposal_bucket)?;
+    let _ = helper.vote_on_proposal(true, stake_id_2, 0)?;
+
+    // The dust voter can't vote on it
+    let failure = helper.vote_on_proposal(true, stake_id_1, 0);
+
+    // Attempt to set a negative minimum vote power (should fail)
+    let failure_negative = helper.set_min_vote_power(dec!(-1));
+
+    let info = helper.get_proposal_info(0)?.unwrap();
+
+    assert!(failure.is_err());
+    assert!(failure_negative.is_err());
+    assert!(info.votes_for > dec!(5000));
+
+    Ok(())
+}
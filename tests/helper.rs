@@ -1,13 +1,23 @@
 #![allow(dead_code)]
 
 use dao::bootstrap::bootstrap_test::*;
+use dao::bootstrap::BootstrapParticipation;
 use dao::dao::dao_test::*;
+use dao::dao::File;
+use dao::dao::StakingSystem;
 use dao::governance::governance_test::*;
+use dao::governance::ExecutionPermission;
+use dao::governance::GovernanceParameters;
+use dao::governance::GovernanceStats;
+use dao::governance::ProposalInfo;
+use dao::governance::RejectedFeeHandling;
 use dao::incentives::incentives_test::*;
+use dao::incentives::BootstrapBonus;
 use dao::incentives::IncentivesId;
 use dao::reentrancy::reentrancy_test::*;
 use dao::staking::staking_test::*;
 use dao::staking::Id;
+use dao::staking::VoteDecay;
 use scrypto::prelude::ResourceSpecifier;
 use scrypto_test::prelude::*;
 
@@ -42,10 +52,16 @@ pub struct Job {
     pub recurring: bool,
     pub title: String,
     pub description: String,
+    pub nft_grant: Option<(ResourceAddress, IndexSet<NonFungibleLocalId>)>,
+    pub late_payment_bonus_rate: Option<Decimal>,
 }
 
 impl Helper {
     pub fn new() -> Result<Self, RuntimeError> {
+        Self::new_with_burnable_mother_token(false)
+    }
+
+    pub fn new_with_burnable_mother_token(burnable: bool) -> Result<Self, RuntimeError> {
         let fake_dex_address = GlobalAddress::try_from_hex(
             "0df7665160fd68a27b3961ca504d0ecc12294d426c9ad56537a3f3e88d60",
         )
@@ -64,18 +80,29 @@ impl Helper {
             CompileProfile::Standard,
         )?;
 
-        let ilis = ResourceBuilder::new_fungible(OwnerRole::None)
+        let admin = ResourceBuilder::new_fungible(OwnerRole::None)
             .divisibility(18)
-            .mint_initial_supply(1000000, &mut env)?;
+            .mint_initial_supply(100, &mut env)?;
+        let admin_address = admin.resource_address(&mut env)?;
+
+        let ilis = if burnable {
+            ResourceBuilder::new_fungible(OwnerRole::None)
+                .divisibility(18)
+                .burn_roles(burn_roles! {
+                    burner => rule!(require_amount(dec!("0.75"), admin_address));
+                    burner_updater => rule!(deny_all);
+                })
+                .mint_initial_supply(1000000, &mut env)?
+        } else {
+            ResourceBuilder::new_fungible(OwnerRole::None)
+                .divisibility(18)
+                .mint_initial_supply(1000000, &mut env)?
+        };
         let xrd = ResourceBuilder::new_fungible(OwnerRole::None)
             .divisibility(18)
             .mint_initial_supply(10000, &mut env)?;
-        let admin = ResourceBuilder::new_fungible(OwnerRole::None)
-            .divisibility(18)
-            .mint_initial_supply(100, &mut env)?;
 
         let ilis_address = ilis.resource_address(&mut env)?;
-        let admin_address = admin.resource_address(&mut env)?;
         let xrd_address = xrd.resource_address(&mut env)?;
 
         let dapp_definition: ComponentAddress = env
@@ -160,6 +187,53 @@ impl Helper {
     //////////////////// DAO ////////////////////////
     /////////////////////////////////////////////////
 
+    pub fn instantiate_dao_with_allocations(
+        &mut self,
+        founder_allocation: Decimal,
+        bootstrap_allocation: Decimal,
+        staking_allocation: Decimal,
+        incentives_allocation: Decimal,
+    ) -> Result<(), RuntimeError> {
+        let dapp_definition: ComponentAddress = self
+            .env
+            .call_function_typed::<_, AccountCreateOutput>(
+                ACCOUNT_PACKAGE,
+                ACCOUNT_BLUEPRINT,
+                ACCOUNT_CREATE_IDENT,
+                &AccountCreateInput {},
+            )?
+            .0
+             .0
+            .into();
+
+        let _ = Dao::instantiate_dao(
+            self.ilis.take(dec!(100000), &mut self.env)?,
+            founder_allocation,
+            bootstrap_allocation,
+            staking_allocation,
+            incentives_allocation,
+            self.admin.take(dec!(5), &mut self.env)?,
+            "ILIS DAO".to_string(),
+            "ILIS".to_string(),
+            self.xrd.take(dec!(500), &mut self.env)?,
+            dapp_definition,
+            true,
+            7,
+            dec!(5000),
+            7,
+            UncheckedUrl::of("https://blabla.com").into(),
+            UncheckedUrl::of("https://blabla.com").into(),
+            UncheckedUrl::of("https://blabla.com").into(),
+            UncheckedUrl::of("https://blabla.com").into(),
+            UncheckedUrl::of("https://blabla.com").into(),
+            UncheckedUrl::of("https://blabla.com").into(),
+            self.package_address,
+            &mut self.env,
+        )?;
+
+        Ok(())
+    }
+
     pub fn dao_get_token_amount(
         &mut self,
         resource_address: ResourceAddress,
@@ -169,6 +243,36 @@ impl Helper {
         Ok(amount)
     }
 
+    pub fn dao_get_treasury_flow(
+        &mut self,
+        resource_address: ResourceAddress,
+    ) -> Result<(Decimal, Decimal), RuntimeError> {
+        let flow = self.dao.get_treasury_flow(resource_address, &mut self.env)?;
+
+        Ok(flow)
+    }
+
+    pub fn dao_set_reserve_floor(
+        &mut self,
+        address: ResourceAddress,
+        amount: Decimal,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self.dao.set_reserve_floor(address, amount, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn dao_get_reserve_floor(
+        &mut self,
+        address: ResourceAddress,
+    ) -> Result<Decimal, RuntimeError> {
+        let reserve_floor = self.dao.get_reserve_floor(address, &mut self.env)?;
+
+        Ok(reserve_floor)
+    }
+
     pub fn dao_send_tokens(
         &mut self,
         address: ResourceAddress,
@@ -186,6 +290,72 @@ impl Helper {
         Ok(())
     }
 
+    pub fn set_send_approval(
+        &mut self,
+        approver_badges: IndexSet<ResourceAddress>,
+        required_approvals: u64,
+        send_approval_thresholds: IndexMap<ResourceAddress, Decimal>,
+    ) -> Result<(), RuntimeError> {
+        let _ = self.dao.set_send_approval(
+            approver_badges,
+            required_approvals,
+            send_approval_thresholds,
+            &mut self.env,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn approve_pending_send(
+        &mut self,
+        send_id: u64,
+        approver_badge: &Bucket,
+    ) -> Result<(), RuntimeError> {
+        let approver_proof = approver_badge.create_proof_of_all(&mut self.env)?;
+        let _ = self
+            .dao
+            .approve_pending_send(send_id, approver_proof, &mut self.env)?;
+
+        Ok(())
+    }
+
+    pub fn execute_pending_send(&mut self, send_id: u64) -> Result<(), RuntimeError> {
+        let _ = self.dao.execute_pending_send(send_id, &mut self.env)?;
+
+        Ok(())
+    }
+
+    pub fn propose_owner_transfer(&mut self, new_owner_rule: AccessRule) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .dao
+            .propose_owner_transfer(new_owner_rule, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn accept_owner_transfer(&mut self) -> Result<(), RuntimeError> {
+        let _ = self.dao.accept_owner_transfer(&mut self.env)?;
+
+        Ok(())
+    }
+
+    pub fn dao_send_tokens_multi(
+        &mut self,
+        sends: Vec<(ResourceAddress, ResourceSpecifier)>,
+        recipient: ComponentAddress,
+    ) -> Result<(), RuntimeError> {
+        let _ = self.dao.send_tokens_multi(
+            sends,
+            recipient,
+            "put_tokens".to_string(),
+            &mut self.env,
+        )?;
+
+        Ok(())
+    }
+
     pub fn dao_put_tokens(&mut self, bucket: Bucket) -> Result<(), RuntimeError> {
         self.dao.put_tokens(bucket, &mut self.env)?;
 
@@ -242,6 +412,67 @@ impl Helper {
         Ok(())
     }
 
+    pub fn can_airdrop(
+        &mut self,
+        claimants: IndexMap<Reference, ResourceSpecifier>,
+        address: ResourceAddress,
+    ) -> Result<(bool, Decimal), RuntimeError> {
+        let feasibility = self.dao.can_airdrop(claimants, address, &mut self.env)?;
+
+        Ok(feasibility)
+    }
+
+    pub fn airdrop_to_components(
+        &mut self,
+        targets: IndexMap<ComponentAddress, ResourceSpecifier>,
+        address: ResourceAddress,
+    ) -> Result<(), RuntimeError> {
+        self.dao.airdrop_to_components(
+            targets,
+            address,
+            "put_tokens".to_string(),
+            &mut self.env,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn get_remaining_incentives_rewards(&mut self) -> Result<Decimal, RuntimeError> {
+        let rewards = self.incentives.get_remaining_rewards(&mut self.env)?;
+
+        Ok(rewards)
+    }
+
+    pub fn fund_incentives_bonuses(&mut self, bucket: Bucket) -> Result<(), RuntimeError> {
+        let _ = self.incentives.fund_bonuses(bucket, &mut self.env)?;
+
+        Ok(())
+    }
+
+    pub fn wind_down_incentives_rewards(&mut self) -> Result<Bucket, RuntimeError> {
+        self.env.disable_auth_module();
+        let rewards = self.incentives.wind_down_rewards(&mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(rewards)
+    }
+
+    pub fn freeze_incentives_rewards(&mut self) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self.incentives.freeze_rewards(&mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn unfreeze_incentives_rewards(&mut self) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self.incentives.unfreeze_rewards(&mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
     pub fn create_job(
         &mut self,
         employee: Option<Reference>,
@@ -251,6 +482,7 @@ impl Helper {
         recurring: bool,
         title: String,
         description: String,
+        nft_grant: Option<(ResourceAddress, IndexSet<NonFungibleLocalId>)>,
     ) -> Result<(), RuntimeError> {
         let _ = self.env.call_method_typed::<_, _, ()>(
             self.dao.0,
@@ -263,12 +495,113 @@ impl Helper {
                 recurring,
                 title,
                 description,
+                nft_grant,
             ),
         )?;
 
         Ok(())
     }
 
+    pub fn count_controller_badges(
+        &mut self,
+    ) -> Result<(Option<Decimal>, IndexMap<ComponentAddress, Decimal>), RuntimeError> {
+        let count = self.dao.count_controller_badges(&mut self.env)?;
+
+        Ok(count)
+    }
+
+    pub fn add_claimed_entity(&mut self, address: GlobalAddress) -> Result<(), RuntimeError> {
+        self.dao.add_claimed_entity(address, &mut self.env)?;
+
+        Ok(())
+    }
+
+    pub fn set_dao_metadata(
+        &mut self,
+        key: &str,
+        value: MetadataValue,
+    ) -> Result<(), RuntimeError> {
+        self.dao
+            .set_dao_metadata(key.to_string(), value, &mut self.env)?;
+
+        Ok(())
+    }
+
+    pub fn get_claimed_entities(&mut self) -> Result<Vec<GlobalAddress>, RuntimeError> {
+        let claimed_entities = self.dao.get_claimed_entities(&mut self.env)?;
+
+        Ok(claimed_entities)
+    }
+
+    pub fn propose_add_stakable(
+        &mut self,
+        address: ResourceAddress,
+        reward_amount: Decimal,
+        payment: Decimal,
+        max_duration: i64,
+        unlock_payment: Decimal,
+        reward_coefficient: Decimal,
+        fee_amount: Decimal,
+    ) -> Result<(Bucket, Bucket), RuntimeError> {
+        let fee_payment = self.ilis.take(fee_amount, &mut self.env)?;
+        let result = self.dao.propose_add_stakable(
+            "Add stakable".to_string(),
+            "This proposal adds a stakable".to_string(),
+            address,
+            reward_amount,
+            payment,
+            max_duration,
+            unlock_payment,
+            reward_coefficient,
+            None,
+            fee_payment,
+            None,
+            None,
+            &mut self.env,
+        )?;
+
+        Ok(result)
+    }
+
+    pub fn propose_send_tokens(
+        &mut self,
+        address: ResourceAddress,
+        specifier: ResourceSpecifier,
+        recipient: ComponentAddress,
+        fee_amount: Decimal,
+    ) -> Result<(Bucket, Bucket), RuntimeError> {
+        let fee_payment = self.ilis.take(fee_amount, &mut self.env)?;
+        let result = self.dao.propose_send_tokens(
+            "Send tokens".to_string(),
+            "This proposal sends tokens from the treasury".to_string(),
+            address,
+            specifier,
+            recipient,
+            "put_tokens".to_string(),
+            fee_payment,
+            None,
+            None,
+            &mut self.env,
+        )?;
+
+        Ok(result)
+    }
+
+    pub fn get_payroll_summary(
+        &mut self,
+        start_job_id: u64,
+        limit: u64,
+    ) -> Result<Vec<(Reference, Vec<u64>, IndexMap<ResourceAddress, Decimal>)>, RuntimeError> {
+        let summary: Vec<(Reference, Vec<u64>, IndexMap<ResourceAddress, Decimal>)> =
+            self.env.call_method_typed::<_, _, _>(
+                self.dao.0,
+                "get_payroll_summary",
+                &(start_job_id, limit),
+            )?;
+
+        Ok(summary)
+    }
+
     pub fn employ(&mut self, job_id: u64, employee: Reference) -> Result<(), RuntimeError> {
         let _ =
             self.env
@@ -291,6 +624,71 @@ impl Helper {
         Ok(())
     }
 
+    pub fn set_late_payment_bonus_rate(
+        &mut self,
+        job_id: u64,
+        late_payment_bonus_rate: Option<Decimal>,
+    ) -> Result<(), RuntimeError> {
+        let _ = self.env.call_method_typed::<_, _, ()>(
+            self.dao.0,
+            "set_late_payment_bonus_rate",
+            &(job_id, late_payment_bonus_rate),
+        )?;
+
+        Ok(())
+    }
+
+    pub fn set_allowed_payment_tokens(
+        &mut self,
+        allowed_payment_tokens: Option<IndexSet<ResourceAddress>>,
+    ) -> Result<(), RuntimeError> {
+        let _ = self.env.call_method_typed::<_, _, ()>(
+            self.dao.0,
+            "set_allowed_payment_tokens",
+            &(allowed_payment_tokens,),
+        )?;
+
+        Ok(())
+    }
+
+    pub fn migrate_position(
+        &mut self,
+        from_system: StakingSystem,
+        to_system: StakingSystem,
+        amount: Decimal,
+        from_id: &Bucket,
+        to_id: Option<&Bucket>,
+    ) -> Result<(Option<Bucket>, Option<Bucket>), RuntimeError> {
+        let from_id_proof = NonFungibleProof(from_id.create_proof_of_all(&mut self.env)?);
+        let to_id_proof = match to_id {
+            Some(to_id) => Some(to_id.create_proof_of_all(&mut self.env)?),
+            None => None,
+        };
+        let result = self.dao.migrate_position(
+            from_system,
+            to_system,
+            amount,
+            from_id_proof,
+            to_id_proof,
+            &mut self.env,
+        )?;
+
+        Ok(result)
+    }
+
+    pub fn set_max_jobs_per_employee(
+        &mut self,
+        max_jobs_per_employee: Option<u64>,
+    ) -> Result<(), RuntimeError> {
+        let _ = self.env.call_method_typed::<_, _, ()>(
+            self.dao.0,
+            "set_max_jobs_per_employee",
+            &(max_jobs_per_employee,),
+        )?;
+
+        Ok(())
+    }
+
     pub fn fire(
         &mut self,
         employee: Reference,
@@ -320,6 +718,16 @@ impl Helper {
         Ok(())
     }
 
+    pub fn get_announcements(
+        &mut self,
+        start: u64,
+        limit: u64,
+    ) -> Result<Vec<(u64, String, Option<Vec<File>>)>, RuntimeError> {
+        let announcements = self.dao.get_announcements(start, limit, &mut self.env)?;
+
+        Ok(announcements)
+    }
+
     pub fn rewarded_update(&mut self) -> Result<Bucket, RuntimeError> {
         let bucket = self.dao.rewarded_update(&mut self.env)?;
 
@@ -343,16 +751,81 @@ impl Helper {
         Ok(())
     }
 
+    pub fn set_auto_reward_topup(
+        &mut self,
+        amount: Decimal,
+        threshold: Decimal,
+    ) -> Result<(), RuntimeError> {
+        self.dao
+            .set_auto_reward_topup(amount, threshold, &mut self.env)?;
+
+        Ok(())
+    }
+
     //////////////////////////////////////////////////
     //////////////////// BOOTSTRAP ///////////////////
     //////////////////////////////////////////////////
 
     pub fn bootstrap_swap(&mut self, payment: Bucket) -> Result<Bucket, RuntimeError> {
-        let return_bucket = self.bootstrap.swap(payment, &mut self.env)?;
+        self.bootstrap_swap_with_min_output(payment, dec!(0))
+    }
+
+    pub fn bootstrap_swap_with_min_output(
+        &mut self,
+        payment: Bucket,
+        min_output: Decimal,
+    ) -> Result<Bucket, RuntimeError> {
+        let (return_bucket, _) = self.bootstrap.swap(payment, min_output, &mut self.env)?;
 
         Ok(return_bucket)
     }
 
+    pub fn bootstrap_swap_with_participation(
+        &mut self,
+        payment: Bucket,
+    ) -> Result<(Bucket, Option<Bucket>), RuntimeError> {
+        let result = self.bootstrap.swap(payment, dec!(0), &mut self.env)?;
+
+        Ok(result)
+    }
+
+    pub fn set_bootstrap_participation_bonus(
+        &mut self,
+        bonus: Option<Decimal>,
+        requires_holding: bool,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .bootstrap
+            .set_participation_bonus(bonus, requires_holding, &mut self.env)?;
+        self.env.enable_auth_module();
+        Ok(())
+    }
+
+    pub fn fund_bootstrap_participation_bonus(
+        &mut self,
+        bonus_tokens: Bucket,
+    ) -> Result<(), RuntimeError> {
+        let _ = self
+            .bootstrap
+            .fund_participation_bonus(bonus_tokens, &mut self.env)?;
+
+        Ok(())
+    }
+
+    pub fn claim_bootstrap_participation_bonus(
+        &mut self,
+        participation: &Bucket,
+    ) -> Result<Bucket, RuntimeError> {
+        let participation_proof =
+            NonFungibleProof(participation.create_proof_of_all(&mut self.env)?);
+        let bonus = self
+            .bootstrap
+            .claim_participation_bonus(participation_proof, &mut self.env)?;
+
+        Ok(bonus)
+    }
+
     pub fn start_bootstrap(&mut self) -> Result<(), RuntimeError> {
         self.env.disable_auth_module();
         let _ = self.bootstrap.start_bootstrap(&mut self.env)?;
@@ -366,6 +839,12 @@ impl Helper {
         Ok(())
     }
 
+    pub fn is_bootstrap_active(&mut self) -> Result<bool, RuntimeError> {
+        let active = self.bootstrap.is_bootstrap_active(&mut self.env)?;
+
+        Ok(active)
+    }
+
     pub fn reclaim_bootstrap_initial(
         &mut self,
         boot_badge: Bucket,
@@ -375,6 +854,94 @@ impl Helper {
         Ok(return_bucket)
     }
 
+    pub fn get_bootstrap_reclaimable_amounts(
+        &mut self,
+    ) -> Result<(bool, Decimal, Decimal), RuntimeError> {
+        let amounts = self.bootstrap.get_reclaimable_amounts(&mut self.env)?;
+
+        Ok(amounts)
+    }
+
+    pub fn get_bootstrap_reclaimable(&mut self) -> Result<(Decimal, Decimal), RuntimeError> {
+        let amounts = self.bootstrap.get_reclaimable(&mut self.env)?;
+
+        Ok(amounts)
+    }
+
+    pub fn get_bootstrap_post_balances(&mut self) -> Result<(Decimal, Decimal), RuntimeError> {
+        let balances = self.bootstrap.get_post_bootstrap_balances(&mut self.env)?;
+
+        Ok(balances)
+    }
+
+    pub fn instantiate_bootstrap(&mut self, refund_initial: bool) -> Result<(), RuntimeError> {
+        let dapp_definition: ComponentAddress = self
+            .env
+            .call_function_typed::<_, AccountCreateOutput>(
+                ACCOUNT_PACKAGE,
+                ACCOUNT_BLUEPRINT,
+                ACCOUNT_CREATE_IDENT,
+                &AccountCreateInput {},
+            )?
+            .0
+             .0
+            .into();
+
+        let (bootstrap, _non_bucket, boot) = LinearBootstrapPool::new(
+            self.xrd.take(dec!(500), &mut self.env)?,
+            self.ilis.take(dec!(50000), &mut self.env)?,
+            dec!("0.99"),
+            dec!("0.01"),
+            dec!("0.5"),
+            dec!("0.5"),
+            dec!("0.002"),
+            7,
+            dapp_definition,
+            refund_initial,
+            GlobalAddress::from(dapp_definition),
+            UncheckedUrl::of("https://blabla.com").into(),
+            self.admin_address,
+            self.package_address,
+            &mut self.env,
+        )?;
+        self.bootstrap = bootstrap;
+        self.boot = boot;
+
+        Ok(())
+    }
+
+    pub fn bootstrap_add_single_sided(
+        &mut self,
+        payment: Bucket,
+    ) -> Result<(Bucket, Option<Bucket>), RuntimeError> {
+        let result = self.bootstrap.add_single_sided(payment, &mut self.env)?;
+
+        Ok(result)
+    }
+
+    pub fn bootstrap_redeem_single_sided(
+        &mut self,
+        claim: Bucket,
+    ) -> Result<(Bucket, Bucket), RuntimeError> {
+        let result = self.bootstrap.redeem_single_sided(claim, &mut self.env)?;
+
+        Ok(result)
+    }
+
+    pub fn set_bootstrap_single_sided(&mut self, enabled: bool) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self.bootstrap.set_single_sided(enabled, &mut self.env)?;
+        self.env.enable_auth_module();
+        Ok(())
+    }
+
+    pub fn prune_bootstrap_ledger(&mut self, before: Instant) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self.bootstrap.prune_ledger(before, &mut self.env)?;
+        self.env.enable_auth_module();
+        Ok(())
+    }
+
     //////////////////////////////////////////////////
     //////////////////// STAKING /////////////////////
     //////////////////////////////////////////////////
@@ -407,6 +974,19 @@ impl Helper {
         Ok((bucket1, bucket2, stake_id))
     }
 
+    pub fn stake_and_lock(
+        &mut self,
+        stake_bucket: Bucket,
+        duration: i64,
+        for_reward: bool,
+    ) -> Result<(Option<Bucket>, Option<Bucket>), RuntimeError> {
+        let (bucket1, bucket2) =
+            self.staking
+                .stake_and_lock(stake_bucket, None, duration, for_reward, &mut self.env)?;
+
+        Ok((bucket1, bucket2))
+    }
+
     pub fn start_unstake(
         &mut self,
         stake_id: Bucket,
@@ -420,6 +1000,20 @@ impl Helper {
         Ok((bucket1, stake_id))
     }
 
+    pub fn emergency_unstake(
+        &mut self,
+        stake_id: Bucket,
+        amount: Decimal,
+        penalty_bps: u16,
+    ) -> Result<(Bucket, Bucket), RuntimeError> {
+        let stake_id_proof = NonFungibleProof(stake_id.create_proof_of_all(&mut self.env)?);
+        let bucket1 =
+            self.staking
+                .emergency_unstake(stake_id_proof, amount, penalty_bps, &mut self.env)?;
+
+        Ok((bucket1, stake_id))
+    }
+
     pub fn start_unstake_transfer(
         &mut self,
         stake_id: Bucket,
@@ -433,12 +1027,62 @@ impl Helper {
         Ok((bucket1, stake_id))
     }
 
+    pub fn transfer_full_position(
+        &mut self,
+        stake_id: Bucket,
+        recipient: Reference,
+    ) -> Result<Bucket, RuntimeError> {
+        let stake_id_proof = NonFungibleProof(stake_id.create_proof_of_all(&mut self.env)?);
+        let _ = self
+            .staking
+            .transfer_full_position(stake_id_proof, recipient, &mut self.env)?;
+
+        Ok(stake_id)
+    }
+
+    pub fn get_stake_transfer_receipt_address(&mut self) -> Result<ResourceAddress, RuntimeError> {
+        let address: ResourceAddress = self
+            .env
+            .with_component_state::<StakingState, _, _, _>(self.staking.0, |state, _| {
+                state.stake_transfer_receipt_manager.address()
+            })?;
+
+        Ok(address)
+    }
+
+    pub fn settle_staking_warmup(&mut self, id: NonFungibleLocalId) -> Result<(), RuntimeError> {
+        self.staking.settle_warmup(id, &mut self.env)?;
+
+        Ok(())
+    }
+
     pub fn finish_unstake(&mut self, receipt: Bucket) -> Result<Bucket, RuntimeError> {
         let unstake_bucket = self.staking.finish_unstake(receipt, &mut self.env)?;
 
         Ok(unstake_bucket)
     }
 
+    pub fn finish_unstake_batch(&mut self, receipts: Vec<Bucket>) -> Result<Bucket, RuntimeError> {
+        let unstake_bucket = self.staking.finish_unstake_batch(receipts, &mut self.env)?;
+
+        Ok(unstake_bucket)
+    }
+
+    pub fn finish_unstake_and_restake(
+        &mut self,
+        receipt: Bucket,
+        stake_id: Bucket,
+    ) -> Result<(Option<Bucket>, Option<Bucket>, Bucket), RuntimeError> {
+        let stake_id_proof = stake_id.create_proof_of_all(&mut self.env)?;
+        let (bucket1, bucket2) = self.staking.finish_unstake_and_restake(
+            receipt,
+            Some(stake_id_proof),
+            &mut self.env,
+        )?;
+
+        Ok((bucket1, bucket2, stake_id))
+    }
+
     pub fn delegate_vote(
         &mut self,
         stake_id: Bucket,
@@ -452,6 +1096,21 @@ impl Helper {
         Ok(stake_id)
     }
 
+    pub fn get_effective_vote_power(
+        &mut self,
+        id: NonFungibleLocalId,
+    ) -> Result<Decimal, RuntimeError> {
+        let vote_power = self.staking.get_effective_vote_power(id, &mut self.env)?;
+
+        Ok(vote_power)
+    }
+
+    pub fn get_total_delegated(&mut self) -> Result<Decimal, RuntimeError> {
+        let total_delegated = self.staking.get_total_delegated(&mut self.env)?;
+
+        Ok(total_delegated)
+    }
+
     pub fn undelegate_vote(&mut self, stake_id: Bucket) -> Result<Bucket, RuntimeError> {
         let stake_id_proof = NonFungibleProof(stake_id.create_proof_of_all(&mut self.env)?);
         let _ = self
@@ -467,6 +1126,68 @@ impl Helper {
         Ok(rewards)
     }
 
+    pub fn set_secondary_reward(
+        &mut self,
+        address: ResourceAddress,
+        reward_amount: Decimal,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .staking
+            .set_secondary_reward(address, reward_amount, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn put_secondary_tokens(&mut self, bucket: Bucket) -> Result<(), RuntimeError> {
+        self.staking.put_secondary_tokens(bucket, &mut self.env)?;
+
+        Ok(())
+    }
+
+    pub fn claim_secondary_rewards(
+        &mut self,
+        stake_id: Bucket,
+    ) -> Result<(Bucket, Bucket), RuntimeError> {
+        let stake_id_proof = NonFungibleProof(stake_id.create_proof_of_all(&mut self.env)?);
+        let rewards = self
+            .staking
+            .claim_secondary_rewards(stake_id_proof, &mut self.env)?;
+
+        Ok((rewards.0, stake_id))
+    }
+
+    pub fn wind_down_staking_rewards(&mut self) -> Result<Bucket, RuntimeError> {
+        self.env.disable_auth_module();
+        let rewards = self.staking.wind_down_rewards(&mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(rewards)
+    }
+
+    pub fn freeze_staking_rewards(&mut self) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self.staking.freeze_rewards(&mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn unfreeze_staking_rewards(&mut self) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self.staking.unfreeze_rewards(&mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn get_total_distributed_staking_rewards(&mut self) -> Result<Decimal, RuntimeError> {
+        let distributed = self.staking.get_total_distributed(&mut self.env)?;
+
+        Ok(distributed)
+    }
+
     pub fn lock_stake(
         &mut self,
         stake_id: Bucket,
@@ -495,44 +1216,234 @@ impl Helper {
         Ok((stake_id, leftover_payment))
     }
 
-    pub fn get_real_amount(&mut self) -> Result<Decimal, RuntimeError> {
-        let amount = self.staking.get_real_amount(dec!(1), &mut self.env)?;
+    pub fn get_unstake_receipts_info(
+        &mut self,
+        local_ids: Vec<NonFungibleLocalId>,
+    ) -> Result<Vec<(Decimal, Instant)>, RuntimeError> {
+        let info = self
+            .staking
+            .get_unstake_receipts_info(local_ids, &mut self.env)?;
 
-        Ok(amount)
+        Ok(info)
     }
 
-    //////////////////////////////////////////////////
-    //////////////////// INCENTIVES //////////////////
-    //////////////////////////////////////////////////
+    pub fn get_share_price_history(
+        &mut self,
+        limit: u64,
+    ) -> Result<Vec<(Instant, Decimal)>, RuntimeError> {
+        let history = self.staking.get_share_price_history(limit, &mut self.env)?;
 
-    pub fn add_stakable(
+        Ok(history)
+    }
+
+    pub fn force_unlock(&mut self, id: NonFungibleLocalId, duration: i64) -> Result<(), RuntimeError> {
+        let _ = self.staking.force_unlock(id, duration, &mut self.env)?;
+
+        Ok(())
+    }
+
+    pub fn set_staking_caller_reward(&mut self, reward: Decimal) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self.staking.set_caller_reward(reward, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn set_staking_unstake_delay(&mut self, new_delay: i64) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self.staking.set_unstake_delay(new_delay, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn set_staking_warmup_days(&mut self, warmup_days: i64) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self.staking.set_warmup_days(warmup_days, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn set_lock_clawback(&mut self, penalty: Option<Decimal>) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self.staking.set_lock_clawback(penalty, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn set_emergency_penalty(&mut self, penalty: Decimal) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self.staking.set_emergency_penalty(penalty, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn edit_staking_stakable(
         &mut self,
-        address: ResourceAddress,
         reward_amount: Decimal,
         payment: Decimal,
         max_duration: i64,
-        unlock_multiplier: Decimal,
+        unlock_payment: Decimal,
     ) -> Result<(), RuntimeError> {
-        let _ = self.incentives.add_stakable(
-            address,
+        self.env.disable_auth_module();
+        let _ = self.staking.edit_stakable(
             reward_amount,
             payment,
             max_duration,
-            unlock_multiplier,
-            dec!(1),
+            unlock_payment,
             &mut self.env,
         )?;
+        self.env.enable_auth_module();
 
         Ok(())
     }
 
-    pub fn stake_incentives_without_id(
-        &mut self,
-        stake_bucket: Bucket,
-    ) -> Result<(Option<Bucket>, Option<Bucket>), RuntimeError> {
-        let (bucket1, bucket2) = self.incentives.stake(stake_bucket, None, &mut self.env)?;
+    pub fn set_vote_decay(&mut self, vote_decay: Option<VoteDecay>) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self.staking.set_vote_decay(vote_decay, &mut self.env)?;
+        self.env.enable_auth_module();
 
-        Ok((bucket1, bucket2))
+        Ok(())
+    }
+
+    pub fn set_reward_halvings(
+        &mut self,
+        reward_halvings: Vec<(Decimal, Decimal)>,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .staking
+            .set_reward_halvings(reward_halvings, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn get_reward_halvings(&mut self) -> Result<Vec<(Decimal, Decimal)>, RuntimeError> {
+        let reward_halvings = self.staking.get_reward_halvings(&mut self.env)?;
+
+        Ok(reward_halvings)
+    }
+
+    pub fn accelerate_unstake_receipts(
+        &mut self,
+        new_redemption_time: Instant,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .staking
+            .accelerate_unstake_receipts(new_redemption_time, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn set_staking_transfer_receipt_delay(
+        &mut self,
+        new_delay: i64,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .staking
+            .set_transfer_receipt_delay(new_delay, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn rewarded_update_staking_period(&mut self) -> Result<Bucket, RuntimeError> {
+        let reward = self.staking.rewarded_update_period(&mut self.env)?;
+
+        Ok(reward.0)
+    }
+
+    pub fn get_real_amount(&mut self) -> Result<Decimal, RuntimeError> {
+        let amount = self.staking.get_real_amount(dec!(1), &mut self.env)?;
+
+        Ok(amount)
+    }
+
+    pub fn contribute_to_mother_pool(&mut self, bucket: Bucket) -> Result<Bucket, RuntimeError> {
+        let mother_pool: ComponentAddress = self
+            .env
+            .with_component_state::<StakingState, _, _, _>(self.staking.0, |state, _| {
+                state.mother_pool.address()
+            })?;
+
+        self.env.disable_auth_module();
+        let pool_tokens = self
+            .env
+            .call_method_typed::<_, _, OneResourcePoolContributeOutput>(
+                mother_pool.as_node_id().clone(),
+                ONE_RESOURCE_POOL_CONTRIBUTE_IDENT,
+                &OneResourcePoolContributeInput { bucket },
+            )?;
+        self.env.enable_auth_module();
+
+        Ok(pool_tokens)
+    }
+
+    //////////////////////////////////////////////////
+    //////////////////// INCENTIVES //////////////////
+    //////////////////////////////////////////////////
+
+    pub fn add_stakable(
+        &mut self,
+        address: ResourceAddress,
+        reward_amount: Decimal,
+        payment: Decimal,
+        max_duration: i64,
+        unlock_multiplier: Decimal,
+    ) -> Result<(), RuntimeError> {
+        let _ = self.incentives.add_stakable(
+            address,
+            reward_amount,
+            payment,
+            max_duration,
+            unlock_multiplier,
+            dec!(1),
+            None,
+            &mut self.env,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn add_stakable_with_bootstrap_bonus(
+        &mut self,
+        address: ResourceAddress,
+        reward_amount: Decimal,
+        bootstrap_bonus: BootstrapBonus,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self.incentives.add_stakable(
+            address,
+            reward_amount,
+            dec!(1.001),
+            365,
+            dec!(1.002),
+            dec!(1),
+            Some(bootstrap_bonus),
+            &mut self.env,
+        )?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn stake_incentives_without_id(
+        &mut self,
+        stake_bucket: Bucket,
+    ) -> Result<(Option<Bucket>, Option<Bucket>), RuntimeError> {
+        let (bucket1, bucket2, _) =
+            self.incentives
+                .stake(stake_bucket, None, false, &mut self.env)?;
+
+        Ok((bucket1, bucket2))
     }
 
     pub fn stake_incentives_with_id(
@@ -541,13 +1452,39 @@ impl Helper {
         stake_id: Bucket,
     ) -> Result<(Option<Bucket>, Option<Bucket>, Bucket), RuntimeError> {
         let stake_id_proof = stake_id.create_proof_of_all(&mut self.env)?;
-        let (bucket1, bucket2) =
+        let (bucket1, bucket2, _) =
             self.incentives
-                .stake(stake_bucket, Some(stake_id_proof), &mut self.env)?;
+                .stake(stake_bucket, Some(stake_id_proof), false, &mut self.env)?;
 
         Ok((bucket1, bucket2, stake_id))
     }
 
+    pub fn incentives_vote(
+        &mut self,
+        address: ResourceAddress,
+        voting_until: Instant,
+        id: NonFungibleLocalId,
+    ) -> Result<Decimal, RuntimeError> {
+        let vote_power = self
+            .incentives
+            .vote(address, voting_until, id, &mut self.env)?;
+
+        Ok(vote_power)
+    }
+
+    pub fn stake_incentives_with_id_auto_claim(
+        &mut self,
+        stake_bucket: Bucket,
+        stake_id: Bucket,
+    ) -> Result<(Option<Bucket>, Option<Bucket>, Bucket), RuntimeError> {
+        let stake_id_proof = stake_id.create_proof_of_all(&mut self.env)?;
+        let (_, bucket, rewards) =
+            self.incentives
+                .stake(stake_bucket, Some(stake_id_proof), true, &mut self.env)?;
+
+        Ok((bucket, rewards.map(|rewards| rewards.0), stake_id))
+    }
+
     pub fn start_incentives_unstake(
         &mut self,
         address: ResourceAddress,
@@ -582,6 +1519,17 @@ impl Helper {
         Ok(unstake_bucket)
     }
 
+    pub fn finish_incentives_unstake_batch(
+        &mut self,
+        receipts: Vec<Bucket>,
+    ) -> Result<Bucket, RuntimeError> {
+        let unstake_bucket = self
+            .incentives
+            .finish_unstake_batch(receipts, &mut self.env)?;
+
+        Ok(unstake_bucket)
+    }
+
     pub fn lock_incentives_stake(
         &mut self,
         address: ResourceAddress,
@@ -620,11 +1568,76 @@ impl Helper {
         stake_id: Bucket,
     ) -> Result<(Bucket, Bucket), RuntimeError> {
         let stake_id_proof = NonFungibleProof(stake_id.create_proof_of_all(&mut self.env)?);
-        let rewards = self.incentives.update_id(stake_id_proof, &mut self.env)?;
+        let rewards = self
+            .incentives
+            .update_id(stake_id_proof, None, &mut self.env)?;
+
+        Ok((stake_id, rewards.0))
+    }
+
+    pub fn get_pending_incentives_rewards(
+        &mut self,
+        stake_id: &Bucket,
+    ) -> Result<Decimal, RuntimeError> {
+        let stake_id_proof = NonFungibleProof(stake_id.create_proof_of_all(&mut self.env)?);
+        let pending_rewards = self
+            .incentives
+            .get_pending_rewards(stake_id_proof, &mut self.env)?;
+
+        Ok(pending_rewards)
+    }
+
+    pub fn update_incentives_id_to(
+        &mut self,
+        stake_id: Bucket,
+        claim_to: Reference,
+    ) -> Result<(Bucket, Bucket), RuntimeError> {
+        let stake_id_proof = NonFungibleProof(stake_id.create_proof_of_all(&mut self.env)?);
+        let rewards = self
+            .incentives
+            .update_id(stake_id_proof, Some(claim_to), &mut self.env)?;
+
+        Ok((stake_id, rewards.0))
+    }
+
+    pub fn update_incentives_id_for(
+        &mut self,
+        address: ResourceAddress,
+        stake_id: Bucket,
+    ) -> Result<(Bucket, Bucket), RuntimeError> {
+        let stake_id_proof = NonFungibleProof(stake_id.create_proof_of_all(&mut self.env)?);
+        let rewards =
+            self.incentives
+                .update_id_for(stake_id_proof, address, None, &mut self.env)?;
 
         Ok((stake_id, rewards.0))
     }
 
+    pub fn claim_and_lock_incentives(
+        &mut self,
+        stake_id: Bucket,
+        duration: i64,
+    ) -> Result<(Bucket, Bucket), RuntimeError> {
+        let stake_id_proof = NonFungibleProof(stake_id.create_proof_of_all(&mut self.env)?);
+        let lock_reward =
+            self.incentives
+                .claim_and_lock(stake_id_proof, duration, &mut self.env)?;
+
+        Ok((stake_id, lock_reward.0))
+    }
+
+    pub fn claim_and_restake_incentives(
+        &mut self,
+        stake_id: Bucket,
+    ) -> Result<(Bucket, Option<Bucket>), RuntimeError> {
+        let stake_id_proof = NonFungibleProof(stake_id.create_proof_of_all(&mut self.env)?);
+        let lock_reward = self
+            .incentives
+            .claim_and_restake(stake_id_proof, &mut self.env)?;
+
+        Ok((stake_id, lock_reward))
+    }
+
     //////////////////////////////////////////////////
     /////////////////// GOVERNANCE ///////////////////
     //////////////////////////////////////////////////
@@ -632,8 +1645,58 @@ impl Helper {
     pub fn create_basic_proposal(
         &mut self,
         payment_amount: Decimal,
+    ) -> Result<(Bucket, Bucket), RuntimeError> {
+        self.create_proposal_with_text(
+            "Test Proposal".to_string(),
+            "This is a test proposal".to_string(),
+            payment_amount,
+        )
+    }
+
+    pub fn create_proposal_with_text(
+        &mut self,
+        title: String,
+        description: String,
+        payment_amount: Decimal,
+    ) -> Result<(Bucket, Bucket), RuntimeError> {
+        self.create_proposal_with_max_fee(title, description, payment_amount, None)
+    }
+
+    pub fn create_proposal_with_max_fee(
+        &mut self,
+        title: String,
+        description: String,
+        payment_amount: Decimal,
+        max_fee: Option<Decimal>,
+    ) -> Result<(Bucket, Bucket), RuntimeError> {
+        let value: ScryptoValue = scrypto_decode(&scrypto_encode(&(dec!(100),)).unwrap()).unwrap();
+        let result = self.governance.create_proposal(
+            title,
+            description,
+            None,
+            ComponentAddress::try_from(self.dao.0.clone()).unwrap(),
+            self.admin_address,
+            "set_update_reward".to_string(),
+            value,
+            false,
+            false,
+            self.ilis.take(payment_amount, &mut self.env)?,
+            max_fee,
+            None,
+            None,
+            &mut self.env,
+        )?;
+
+        Ok(result)
+    }
+
+    pub fn create_basic_proposal_with_stake_id(
+        &mut self,
+        payment_amount: Decimal,
+        stake_id: &Bucket,
     ) -> Result<(Bucket, Bucket), RuntimeError> {
         let value: ScryptoValue = scrypto_decode(&scrypto_encode(&(dec!(100),)).unwrap()).unwrap();
+        let proposer_id_proof = NonFungibleProof(stake_id.create_proof_of_all(&mut self.env)?);
         let result = self.governance.create_proposal(
             "Test Proposal".to_string(),
             "This is a test proposal".to_string(),
@@ -645,12 +1708,68 @@ impl Helper {
             false,
             false,
             self.ilis.take(payment_amount, &mut self.env)?,
+            None,
+            None,
+            Some(proposer_id_proof),
+            &mut self.env,
+        )?;
+
+        Ok(result)
+    }
+
+    pub fn create_and_submit_basic_proposal(
+        &mut self,
+        payment_amount: Decimal,
+    ) -> Result<(Bucket, Bucket), RuntimeError> {
+        self.create_and_submit_basic_proposal_with_proof(payment_amount, None)
+    }
+
+    pub fn create_and_submit_basic_proposal_as_proposer(
+        &mut self,
+        payment_amount: Decimal,
+        proposer_badge: &Bucket,
+    ) -> Result<(Bucket, Bucket), RuntimeError> {
+        let proposer_proof = proposer_badge.create_proof_of_all(&mut self.env)?;
+        self.create_and_submit_basic_proposal_with_proof(payment_amount, Some(proposer_proof))
+    }
+
+    fn create_and_submit_basic_proposal_with_proof(
+        &mut self,
+        payment_amount: Decimal,
+        proposer_proof: Option<Proof>,
+    ) -> Result<(Bucket, Bucket), RuntimeError> {
+        let value: ScryptoValue = scrypto_decode(&scrypto_encode(&(dec!(100),)).unwrap()).unwrap();
+        let result = self.governance.create_and_submit_proposal(
+            "Test Proposal".to_string(),
+            "This is a test proposal".to_string(),
+            None,
+            ComponentAddress::try_from(self.dao.0.clone()).unwrap(),
+            self.admin_address,
+            "set_update_reward".to_string(),
+            value,
+            false,
+            false,
+            self.ilis.take(payment_amount, &mut self.env)?,
+            None,
+            proposer_proof,
+            None,
             &mut self.env,
         )?;
 
         Ok(result)
     }
 
+    pub fn create_account_owner_badge(&mut self) -> Result<Bucket, RuntimeError> {
+        let (_, owner_badge) = self.env.call_function_typed::<_, AccountCreateOutput>(
+            ACCOUNT_PACKAGE,
+            ACCOUNT_BLUEPRINT,
+            ACCOUNT_CREATE_IDENT,
+            &AccountCreateInput {},
+        )?;
+
+        Ok(owner_badge)
+    }
+
     pub fn add_normal_proposal_step(
         &mut self,
         proposal_receipt: Bucket,
@@ -683,7 +1802,8 @@ impl Helper {
             self.admin_address,
             "set_parameters".to_string(),
             scrypto_decode(
-                &scrypto_encode(&(dec!(5000), 7i64, dec!(10000), dec!(0.5), 7i64)).unwrap(),
+                &scrypto_encode(&(dec!(5000), 7i64, dec!(10000), dec!(0.5), 7i64, dec!(0)))
+                    .unwrap(),
             )
             .unwrap(),
             false,
@@ -704,17 +1824,73 @@ impl Helper {
         Ok(proposal_receipt)
     }
 
+    pub fn cosponsor_proposal(
+        &mut self,
+        proposal_receipt: Bucket,
+        sponsor_id: Bucket,
+    ) -> Result<(Bucket, Bucket), RuntimeError> {
+        let proposal_receipt_proof =
+            NonFungibleProof(proposal_receipt.create_proof_of_all(&mut self.env)?);
+        let sponsor_id_proof = NonFungibleProof(sponsor_id.create_proof_of_all(&mut self.env)?);
+        let _ = self.governance.cosponsor_proposal(
+            proposal_receipt_proof,
+            sponsor_id_proof,
+            &mut self.env,
+        )?;
+
+        Ok((proposal_receipt, sponsor_id))
+    }
+
     pub fn vote_on_proposal(
         &mut self,
         for_against: bool,
         vote_id: Bucket,
         proposal_id: u64,
     ) -> Result<Bucket, RuntimeError> {
-        let vote_id_proof = NonFungibleProof(vote_id.create_proof_of_all(&mut self.env)?);
-        let _ = self.governance.vote_on_proposal(
-            proposal_id,
+        self.vote_on_proposal_with_lock(for_against, vote_id, proposal_id, None)
+    }
+
+    pub fn vote_on_proposal_with_extra_lock(
+        &mut self,
+        for_against: bool,
+        vote_id: Bucket,
+        proposal_id: u64,
+        extra_lock_days: i64,
+    ) -> Result<Bucket, RuntimeError> {
+        self.vote_on_proposal_with_lock(for_against, vote_id, proposal_id, Some(extra_lock_days))
+    }
+
+    fn vote_on_proposal_with_lock(
+        &mut self,
+        for_against: bool,
+        vote_id: Bucket,
+        proposal_id: u64,
+        extra_lock_days: Option<i64>,
+    ) -> Result<Bucket, RuntimeError> {
+        let vote_id_proof = NonFungibleProof(vote_id.create_proof_of_all(&mut self.env)?);
+        let _ = self.governance.vote_on_proposal(
+            proposal_id,
             for_against,
             vote_id_proof,
+            extra_lock_days,
+            &mut self.env,
+        )?;
+
+        Ok(vote_id)
+    }
+
+    pub fn change_vote(
+        &mut self,
+        for_against: bool,
+        vote_id: Bucket,
+        proposal_id: u64,
+    ) -> Result<Bucket, RuntimeError> {
+        let vote_id_proof = NonFungibleProof(vote_id.create_proof_of_all(&mut self.env)?);
+        let _ = self.governance.change_vote(
+            proposal_id,
+            for_against,
+            vote_id_proof,
+            None,
             &mut self.env,
         )?;
 
@@ -734,7 +1910,24 @@ impl Helper {
     ) -> Result<(), RuntimeError> {
         let _ = self
             .governance
-            .execute_proposal_step(proposal_id, steps, &mut self.env)?;
+            .execute_proposal_step(proposal_id, steps, None, &mut self.env)?;
+
+        Ok(())
+    }
+
+    pub fn execute_proposal_step_with_proof(
+        &mut self,
+        proposal_id: u64,
+        steps: i64,
+        proof_bucket: &Bucket,
+    ) -> Result<(), RuntimeError> {
+        let executor_proof = proof_bucket.create_proof_of_all(&mut self.env)?;
+        let _ = self.governance.execute_proposal_step(
+            proposal_id,
+            steps,
+            Some(executor_proof),
+            &mut self.env,
+        )?;
 
         Ok(())
     }
@@ -745,6 +1938,39 @@ impl Helper {
         Ok(())
     }
 
+    pub fn get_pending_reentrancy_step(
+        &mut self,
+        proposal_id: u64,
+    ) -> Result<Option<(ComponentAddress, String)>, RuntimeError> {
+        let pending_step = self
+            .reentrancy
+            .get_pending_step(proposal_id, &mut self.env)?;
+
+        Ok(pending_step)
+    }
+
+    pub fn abort_reentrancy_step(
+        &mut self,
+        proposal_id: u64,
+        mark_failed: bool,
+    ) -> Result<(), RuntimeError> {
+        let _ = self
+            .governance
+            .abort_reentrancy_step(proposal_id, mark_failed, &mut self.env)?;
+
+        Ok(())
+    }
+
+    pub fn fail_stuck_proposal(&mut self, proposal_id: u64) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .governance
+            .fail_stuck_proposal(proposal_id, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
     pub fn retrieve_fee(&mut self, proposal_receipt: Bucket) -> Result<Bucket, RuntimeError> {
         let proposal_receipt_proof =
             NonFungibleProof(proposal_receipt.create_proof_of_all(&mut self.env)?);
@@ -755,6 +1981,287 @@ impl Helper {
         Ok(fee)
     }
 
+    pub fn set_early_voter_reward(
+        &mut self,
+        early_voter_reward: Decimal,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .governance
+            .set_early_voter_reward(early_voter_reward, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn set_rejected_fee_handling(
+        &mut self,
+        rejected_fee_handling: RejectedFeeHandling,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .governance
+            .set_rejected_fee_handling(rejected_fee_handling, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn get_governance_token_amount(
+        &mut self,
+        address: ResourceAddress,
+    ) -> Result<Decimal, RuntimeError> {
+        let amount = self.governance.get_token_amount(address, &mut self.env)?;
+
+        Ok(amount)
+    }
+
+    pub fn set_allow_veto_recovery(
+        &mut self,
+        allow_veto_recovery: bool,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .governance
+            .set_allow_veto_recovery(allow_veto_recovery, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn set_proposer_discount(
+        &mut self,
+        proposer_discount: Decimal,
+        max_proposer_discount: Decimal,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self.governance.set_proposer_discount(
+            proposer_discount,
+            max_proposer_discount,
+            &mut self.env,
+        )?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn set_governance_parameters(
+        &mut self,
+        fee: Decimal,
+        proposal_duration: i64,
+        quorum: Decimal,
+        approval_threshold: Decimal,
+        maximum_proposal_submit_delay: i64,
+        min_proposer_stake: Decimal,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self.governance.set_parameters(
+            fee,
+            proposal_duration,
+            quorum,
+            approval_threshold,
+            maximum_proposal_submit_delay,
+            min_proposer_stake,
+            &mut self.env,
+        )?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn set_fee(&mut self, fee: Decimal) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self.governance.set_fee(fee, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn set_proposal_duration(&mut self, proposal_duration: i64) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .governance
+            .set_proposal_duration(proposal_duration, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn set_quorum(&mut self, quorum: Decimal) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self.governance.set_quorum(quorum, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn set_approval_threshold(
+        &mut self,
+        approval_threshold: Decimal,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .governance
+            .set_approval_threshold(approval_threshold, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn set_maximum_proposal_submit_delay(
+        &mut self,
+        maximum_proposal_submit_delay: i64,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .governance
+            .set_maximum_proposal_submit_delay(maximum_proposal_submit_delay, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn set_min_proposer_stake(
+        &mut self,
+        min_proposer_stake: Decimal,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .governance
+            .set_min_proposer_stake(min_proposer_stake, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn set_min_vote_power(&mut self, min_vote_power: Decimal) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .governance
+            .set_min_vote_power(min_vote_power, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn set_min_sponsor_stake(
+        &mut self,
+        min_sponsor_stake: Decimal,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .governance
+            .set_min_sponsor_stake(min_sponsor_stake, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn set_min_text_lengths(
+        &mut self,
+        min_title_len: u64,
+        min_description_len: u64,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self.governance.set_min_text_lengths(
+            min_title_len,
+            min_description_len,
+            &mut self.env,
+        )?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn set_conviction_voting(
+        &mut self,
+        conviction_multiplier_per_day: Decimal,
+        max_extra_lock_days: i64,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self.governance.set_conviction_voting(
+            conviction_multiplier_per_day,
+            max_extra_lock_days,
+            &mut self.env,
+        )?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn set_execution_permission(
+        &mut self,
+        execution_permission: ExecutionPermission,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .governance
+            .set_execution_permission(execution_permission, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn set_max_steps_per_execution(
+        &mut self,
+        max_steps_per_execution: i64,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .governance
+            .set_max_steps_per_execution(max_steps_per_execution, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn set_proposer_cooldown(
+        &mut self,
+        proposer_cooldown_days: i64,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .governance
+            .set_proposer_cooldown(proposer_cooldown_days, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn set_max_vote_weight_fraction(
+        &mut self,
+        max_vote_weight_fraction: Decimal,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .governance
+            .set_max_vote_weight_fraction(max_vote_weight_fraction, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn fund_early_voter_rewards(&mut self, tokens: Bucket) -> Result<(), RuntimeError> {
+        let _ = self
+            .governance
+            .fund_early_voter_rewards(tokens, &mut self.env)?;
+
+        Ok(())
+    }
+
+    pub fn claim_early_voter_reward(
+        &mut self,
+        proposal_id: u64,
+        vote_id: Bucket,
+    ) -> Result<(Bucket, Bucket), RuntimeError> {
+        let vote_id_proof = NonFungibleProof(vote_id.create_proof_of_all(&mut self.env)?);
+        let reward =
+            self.governance
+                .claim_early_voter_reward(proposal_id, vote_id_proof, &mut self.env)?;
+
+        Ok((reward, vote_id))
+    }
+
     pub fn hurry_proposal(
         &mut self,
         proposal_id: u64,
@@ -767,6 +2274,79 @@ impl Helper {
         Ok(())
     }
 
+    pub fn set_approval_threshold_bps(
+        &mut self,
+        approval_threshold_bps: u16,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .governance
+            .set_approval_threshold_bps(approval_threshold_bps, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn get_emitted_events<T: ScryptoDecode>(&mut self, event_name: &str) -> Vec<T> {
+        self.env.with_kernel(|kernel| {
+            kernel
+                .kernel_callback()
+                .modules
+                .events()
+                .iter()
+                .filter(|event| event.type_identifier.1 == event_name)
+                .map(|event| scrypto_decode::<T>(&event.payload).unwrap())
+                .collect()
+        })
+    }
+
+    pub fn get_proposal_info(
+        &mut self,
+        proposal_id: u64,
+    ) -> Result<Option<ProposalInfo>, RuntimeError> {
+        let info = self
+            .governance
+            .get_proposal_info(proposal_id, &mut self.env)?;
+
+        Ok(info)
+    }
+
+    pub fn get_governance_parameters(&mut self) -> Result<GovernanceParameters, RuntimeError> {
+        let parameters = self.governance.get_parameters(&mut self.env)?;
+
+        Ok(parameters)
+    }
+
+    pub fn get_governance_stats(&mut self) -> Result<GovernanceStats, RuntimeError> {
+        let stats = self.governance.get_governance_stats(&mut self.env)?;
+
+        Ok(stats)
+    }
+
+    pub fn is_executable(&mut self, proposal_id: u64) -> Result<(bool, String), RuntimeError> {
+        let executable = self.governance.is_executable(proposal_id, &mut self.env)?;
+
+        Ok(executable)
+    }
+
+    pub fn get_locked_fee_total(&mut self) -> Result<Decimal, RuntimeError> {
+        let locked_fee_total = self.governance.get_locked_fee_total(&mut self.env)?;
+
+        Ok(locked_fee_total)
+    }
+
+    pub fn verify_fee_accounting(&mut self) -> Result<bool, RuntimeError> {
+        let verified = self.governance.verify_fee_accounting(&mut self.env)?;
+
+        Ok(verified)
+    }
+
+    pub fn get_approval_threshold_bps(&mut self) -> Result<u16, RuntimeError> {
+        let approval_threshold_bps = self.governance.get_approval_threshold_bps(&mut self.env)?;
+
+        Ok(approval_threshold_bps)
+    }
+
     /////////////////////////////////////////////////
     //////////////////// TEST HELPERS ///////////////
     /////////////////////////////////////////////////
@@ -831,6 +2411,99 @@ impl Helper {
         Ok(nft_data)
     }
 
+    pub fn set_stakable_paused(
+        &mut self,
+        address: ResourceAddress,
+        paused: bool,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .incentives
+            .set_stakable_paused(address, paused, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn set_incentives_warmup_days(
+        &mut self,
+        address: ResourceAddress,
+        warmup_days: i64,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .incentives
+            .set_warmup_days(address, warmup_days, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn drain_and_remove_stakable(
+        &mut self,
+        address: ResourceAddress,
+    ) -> Result<Bucket, RuntimeError> {
+        self.env.disable_auth_module();
+        let recovered = self
+            .incentives
+            .drain_and_remove_stakable(address, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(recovered)
+    }
+
+    pub fn set_max_removal_residual(&mut self, max_residual: Decimal) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .incentives
+            .set_max_removal_residual(max_residual, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn set_incentives_reward_tiers(
+        &mut self,
+        address: ResourceAddress,
+        reward_tiers: Vec<(Decimal, Decimal)>,
+    ) -> Result<(), RuntimeError> {
+        self.env.disable_auth_module();
+        let _ = self
+            .incentives
+            .set_reward_tiers(address, reward_tiers, &mut self.env)?;
+        self.env.enable_auth_module();
+
+        Ok(())
+    }
+
+    pub fn get_stakables(&mut self) -> Result<Vec<(ResourceAddress, Decimal, Decimal)>, RuntimeError> {
+        let stakables = self.incentives.get_stakables(&mut self.env)?;
+
+        Ok(stakables)
+    }
+
+    pub fn get_period_info(&mut self) -> Result<(i64, Instant, i64), RuntimeError> {
+        let period_info = self.incentives.get_period_info(&mut self.env)?;
+
+        Ok(period_info)
+    }
+
+    pub fn get_bootstrap_participation_data(
+        &mut self,
+        participation: &Bucket,
+    ) -> Result<BootstrapParticipation, RuntimeError> {
+        let resource_manager = ResourceManager(participation.resource_address(&mut self.env)?);
+        let id = participation
+            .non_fungible_local_ids(&mut self.env)?
+            .first()
+            .unwrap()
+            .clone();
+        let nft_data = resource_manager
+            .get_non_fungible_data::<_, _, BootstrapParticipation>(id, &mut self.env)?;
+
+        Ok(nft_data)
+    }
+
     pub fn get_incentive_data(
         &mut self,
         id: NonFungibleLocalId,
@@ -1,6 +1,7 @@
 mod helper;
 use helper::Helper;
 
+use dao::incentives::BootstrapBonus;
 use scrypto_test::prelude::*;
 
 #[test]
@@ -52,6 +53,113 @@ fn test_incentives_stake_without_and_with_id() -> Result<(), RuntimeError> {
     Ok(())
 }
 
+#[test]
+fn test_get_stakables() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+    let _ = helper.add_stakable(helper.ilis_address, dec!(10000), dec!(1.001), 365, dec!(1.002))?;
+    helper.env.enable_auth_module();
+
+    // Stake 10000 tokens
+    let stake_bucket = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let _ = helper.stake_incentives_without_id(stake_bucket)?;
+
+    // Assert the stakable is listed with its reward amount and staked amount
+    let stakables = helper.get_stakables()?;
+    assert_eq!(
+        stakables,
+        vec![(helper.ilis_address, dec!(10000), dec!(10000))]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_add_stakable_twice() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+    let _ = helper.add_stakable(helper.ilis_address, dec!(10000), dec!(1.001), 365, dec!(1.002))?;
+    helper.env.enable_auth_module();
+
+    // Stake 10000 tokens
+    let stake_bucket = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let _ = helper.stake_incentives_without_id(stake_bucket)?;
+
+    // Attempt to add the same stakable again (should fail)
+    helper.env.disable_auth_module();
+    let failure = helper.add_stakable(
+        helper.ilis_address,
+        dec!(5000),
+        dec!(1.001),
+        365,
+        dec!(1.002),
+    );
+    helper.env.enable_auth_module();
+
+    // The existing stakable is left untouched
+    let stakables = helper.get_stakables()?;
+    assert_eq!(
+        stakables,
+        vec![(helper.ilis_address, dec!(10000), dec!(10000))]
+    );
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_get_period_info() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    let start_time = helper.env.get_current_time();
+
+    // First period ends after the 7 day period interval
+    let period_info_1 = helper.get_period_info()?;
+
+    // Advance time by 7 days and update rewards
+    let new_time_1 = start_time.add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.rewarded_update()?;
+
+    let period_info_2 = helper.get_period_info()?;
+
+    assert_eq!(period_info_1, (0, start_time.add_days(7).unwrap(), 7));
+    assert_eq!(period_info_2, (1, start_time.add_days(14).unwrap(), 7));
+
+    Ok(())
+}
+
+#[test]
+fn test_freeze_incentives_rewards() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    let start_time = helper.env.get_current_time();
+
+    // Freeze rewards, after which no period passes
+    helper.freeze_incentives_rewards()?;
+
+    let new_time_1 = start_time.add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.rewarded_update()?;
+
+    let period_info_1 = helper.get_period_info()?;
+
+    // Unfreezing moves the next period forward by the frozen window
+    helper.unfreeze_incentives_rewards()?;
+    let period_info_2 = helper.get_period_info()?;
+
+    let new_time_2 = start_time.add_days(14).unwrap();
+    helper.env.set_current_time(new_time_2);
+    let _ = helper.rewarded_update()?;
+
+    let period_info_3 = helper.get_period_info()?;
+
+    assert_eq!(period_info_1, (0, start_time.add_days(7).unwrap(), 7));
+    assert_eq!(period_info_2, (0, start_time.add_days(14).unwrap(), 7));
+    assert_eq!(period_info_3, (1, start_time.add_days(21).unwrap(), 7));
+
+    Ok(())
+}
+
 #[test]
 fn test_incentives_stake_and_unstake_with_id() -> Result<(), RuntimeError> {
     let mut helper = Helper::new().unwrap();
@@ -113,6 +221,195 @@ fn test_incentives_stake_and_unstake_with_id() -> Result<(), RuntimeError> {
     Ok(())
 }
 
+#[test]
+fn test_incentives_vote_only_callable_by_owner() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+    let _ = helper.add_stakable(helper.ilis_address, dec!(10000), dec!(1.001), 365, dec!(1.002))?;
+    helper.env.enable_auth_module();
+
+    // Stake 10000 tokens
+    let stake_bucket = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let _ = helper.stake_incentives_without_id(stake_bucket)?;
+
+    let voting_until = helper.env.get_current_time().add_days(4).unwrap();
+
+    // Voting lock can't be set by anyone other than the owner
+    let failure = helper.incentives_vote(
+        helper.ilis_address,
+        voting_until,
+        NonFungibleLocalId::integer(1),
+    );
+
+    // The owner (the DAO) can set a voting lock
+    helper.env.disable_auth_module();
+    let vote_power = helper.incentives_vote(
+        helper.ilis_address,
+        voting_until,
+        NonFungibleLocalId::integer(1),
+    )?;
+
+    // Voting lock can't end in the past
+    let past_time = helper.env.get_current_time().add_days(-1).unwrap();
+    let failure_2 = helper.incentives_vote(
+        helper.ilis_address,
+        past_time,
+        NonFungibleLocalId::integer(1),
+    );
+    helper.env.enable_auth_module();
+
+    let id_data = helper.get_incentive_data(NonFungibleLocalId::integer(1))?;
+
+    assert_eq!(vote_power, dec!(10000));
+    assert_eq!(
+        id_data
+            .resources
+            .get(&helper.ilis_address)
+            .unwrap()
+            .voting_until
+            .unwrap(),
+        voting_until
+    );
+    assert!(failure.is_err());
+    assert!(failure_2.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_incentives_finish_unstake_batch() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+    let _ = helper.add_stakable(helper.ilis_address, dec!(10000), dec!(1.001), 365, dec!(1.002))?;
+    helper.env.enable_auth_module();
+
+    // Stake 10000 tokens and unstake three times, creating three unstake receipts
+    let stake_bucket = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let result = helper.stake_incentives_without_id(stake_bucket)?;
+    let (unstake_receipt_1, stake_id_1) =
+        helper.start_incentives_unstake(helper.ilis_address, result.0.unwrap(), dec!(1000))?;
+    let (unstake_receipt_2, stake_id_2) =
+        helper.start_incentives_unstake(helper.ilis_address, stake_id_1, dec!(2000))?;
+    let (unstake_receipt_3, _) =
+        helper.start_incentives_unstake(helper.ilis_address, stake_id_2, dec!(3000))?;
+
+    // Attempt to finish the unstakes before the redemption time (should fail)
+    let failure = helper.finish_incentives_unstake_batch(vec![unstake_receipt_1]);
+
+    // Advance time by 7 days
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+
+    // Finish the remaining unstakes at once and assert the combined amount
+    let unstaked_bucket =
+        helper.finish_incentives_unstake_batch(vec![unstake_receipt_2, unstake_receipt_3])?;
+    assert_eq!(unstaked_bucket.amount(&mut helper.env)?, dec!(5000));
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_drain_and_remove_stakable() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+    let _ = helper.add_stakable(helper.ilis_address, dec!(10000), dec!(1.001), 365, dec!(1.002))?;
+    helper.env.enable_auth_module();
+
+    // Stake 10000 tokens and allow a residual of 1 token when removing a stakable
+    let stake_bucket = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let result = helper.stake_incentives_without_id(stake_bucket)?;
+    helper.set_max_removal_residual(dec!(1))?;
+
+    // Attempt to drain the stakable while live stake is left (should fail)
+    let failure_live = helper.drain_and_remove_stakable(helper.ilis_address);
+
+    // Unstake all but a residual amount
+    let (unstake_receipt, stake_id) =
+        helper.start_incentives_unstake(helper.ilis_address, result.0.unwrap(), dec!(9999.9))?;
+
+    // Drain and remove the stakable, recovering the residual stake
+    let recovered = helper.drain_and_remove_stakable(helper.ilis_address)?;
+    helper.assert_bucket_eq(&recovered, helper.ilis_address, dec!(0.1))?;
+
+    // The position holding the recovered residual can still be unstaked, clearing it
+    let (_residual_receipt, _stake_id) =
+        helper.start_incentives_unstake(helper.ilis_address, stake_id, dec!(0.1))?;
+    let id_data = helper.get_incentive_data(NonFungibleLocalId::integer(1))?;
+    assert_eq!(
+        id_data
+            .resources
+            .get(&helper.ilis_address)
+            .unwrap()
+            .amount_staked,
+        dec!(0)
+    );
+
+    let stakables = helper.get_stakables()?;
+    assert!(stakables.is_empty());
+
+    // Advance time by 7 days
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+
+    // The outstanding unstake receipt can still be redeemed
+    let unstaked_bucket = helper.finish_incentives_unstake(unstake_receipt)?;
+    helper.assert_bucket_eq(&unstaked_bucket, helper.ilis_address, dec!(9999.9))?;
+
+    // The removed stakable can't be staked to, or added again
+    let stake_bucket_2 = helper.ilis.take(dec!(1000), &mut helper.env)?;
+    let failure = helper.stake_incentives_without_id(stake_bucket_2);
+    helper.env.disable_auth_module();
+    let failure_2 = helper.add_stakable(
+        helper.ilis_address,
+        dec!(10000),
+        dec!(1.001),
+        365,
+        dec!(1.002),
+    );
+    helper.env.enable_auth_module();
+
+    assert!(failure_live.is_err());
+    assert!(failure.is_err());
+    assert!(failure_2.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_incentives_paused_stakable() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+    let _ = helper.add_stakable(helper.ilis_address, dec!(10000), dec!(1.001), 365, dec!(1.002))?;
+    helper.env.enable_auth_module();
+
+    // Stake 10000 tokens
+    let stake_bucket = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let result = helper.stake_incentives_without_id(stake_bucket)?;
+
+    // Pause the stakable
+    helper.set_stakable_paused(helper.ilis_address, true)?;
+
+    // Unstaking is still possible while paused
+    let (unstake_receipt, stake_id) =
+        helper.start_incentives_unstake(helper.ilis_address, result.0.unwrap(), dec!(5000))?;
+
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+
+    let unstaked_bucket = helper.finish_incentives_unstake(unstake_receipt)?;
+    helper.assert_bucket_eq(&unstaked_bucket, helper.ilis_address, dec!(5000))?;
+
+    // Staking more is not possible while paused
+    let new_stake_bucket = helper.ilis.take(dec!(1000), &mut helper.env)?;
+    let failure = helper.stake_incentives_with_id(new_stake_bucket, stake_id);
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
 #[test]
 fn test_incentives_unstake_before_time() -> Result<(), RuntimeError> {
     let mut helper = Helper::new().unwrap();
@@ -225,41 +522,365 @@ fn test_incentives_staking_rewards() -> Result<(), RuntimeError> {
     helper.env.set_current_time(new_time_3);
     let _ = helper.rewarded_update()?;
 
-    // Do nothing (simulating no claim)
+    // Do nothing (simulating no claim)
+
+    // Advance time by 7 days and update rewards
+    let new_time_4 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_4);
+    let _ = helper.rewarded_update()?;
+
+    // Check rewards for the second stake (should be double due to unclaimed previous period)
+    let (stake_id_2_returned, rewards) = helper.update_incentives_id(stake_id_2_returned)?;
+    helper.assert_bucket_eq(&rewards, helper.ilis_address, dec!(16000))?;
+
+    // Advance time by 7 days and update rewards
+    let new_time_5 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_5);
+    let _ = helper.rewarded_update()?;
+
+    // Check rewards for the second stake
+    let (_stake_id_2_returned, rewards) = helper.update_incentives_id(stake_id_2_returned)?;
+    helper.assert_bucket_eq(&rewards, helper.ilis_address, dec!(8000))?;
+
+    // Advance time by 7 days and update rewards
+    let new_time_6 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_6);
+    let _ = helper.rewarded_update()?;
+
+    // Do nothing (simulating no claim)
+
+    // Advance time by 7 days and update rewards
+    let new_time_7 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_7);
+    let _ = helper.rewarded_update()?;
+
+    // Check rewards for the first stake (should be a max of 5 periods, even though 7 periods have passed without claim, due to the max claim delay of 5 periods)
+    let (_stake_id_returned, rewards) = helper.update_incentives_id(stake_id_returned)?;
+    helper.assert_bucket_eq(&rewards, helper.ilis_address, dec!(10000))?;
+
+    Ok(())
+}
+
+#[test]
+fn test_incentives_warmup() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+    let _ = helper.add_stakable(helper.ilis_address, dec!(10000), dec!(1.001), 365, dec!(1.002))?;
+    helper.env.enable_auth_module();
+    helper.set_incentives_warmup_days(helper.ilis_address, 7)?;
+
+    // Stake 10000 tokens right before the period ends
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_incentives_without_id(bucket_1)?.0.unwrap();
+
+    // Advance time by 7 days and update rewards
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.rewarded_update()?;
+
+    // No rewards for the period the stake was still warming up
+    let (stake_id, rewards) = helper.update_incentives_id(stake_id)?;
+    helper.assert_bucket_eq(&rewards, helper.ilis_address, dec!(0))?;
+
+    // Advance time by 7 days and update rewards
+    let new_time_2 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_2);
+    let _ = helper.rewarded_update()?;
+
+    // Full rewards after the warm-up
+    let (_stake_id, rewards) = helper.update_incentives_id(stake_id)?;
+    helper.assert_bucket_eq(&rewards, helper.ilis_address, dec!(10000))?;
+
+    Ok(())
+}
+
+#[test]
+fn test_incentives_bootstrap_bonus() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Add stakables doubling the rewards of the first staker of at least 1000 ILIS during the first week,
+    // and of stakers of at least 500 XRD within a day
+    helper.add_stakable_with_bootstrap_bonus(
+        helper.ilis_address,
+        dec!(10000),
+        BootstrapBonus {
+            multiplier: dec!(2),
+            max_stakers: 1,
+            window_days: 7,
+            min_stake: dec!(1000),
+        },
+    )?;
+    helper.add_stakable_with_bootstrap_bonus(
+        helper.xrd_address,
+        dec!(5000),
+        BootstrapBonus {
+            multiplier: dec!(2),
+            max_stakers: 0,
+            window_days: 1,
+            min_stake: dec!(500),
+        },
+    )?;
+
+    // The bootstrap bonus is paid from the bonus vault
+    let bonus_tokens = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    helper.fund_incentives_bonuses(bonus_tokens)?;
+
+    // Two stakers stake the same amount of ILIS, the first one also stakes XRD within the window
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_1 = helper.stake_incentives_without_id(bucket_1)?.0.unwrap();
+    let bucket_2 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_2 = helper.stake_incentives_without_id(bucket_2)?.0.unwrap();
+    let bucket_3 = helper.xrd.take(dec!(1000), &mut helper.env)?;
+    let (_, _, stake_id_1) = helper.stake_incentives_with_id(bucket_3, stake_id_1)?;
+
+    // The second staker first stakes XRD below the minimum stake within the window, then adds to it
+    let bucket_4 = helper.xrd.take(dec!(100), &mut helper.env)?;
+    let (_, _, stake_id_2) = helper.stake_incentives_with_id(bucket_4, stake_id_2)?;
+    let bucket_5 = helper.xrd.take(dec!(900), &mut helper.env)?;
+    let (_, _, stake_id_2) = helper.stake_incentives_with_id(bucket_5, stake_id_2)?;
+
+    let id_data_1 = helper.get_incentive_data(NonFungibleLocalId::integer(1))?;
+    let id_data_2 = helper.get_incentive_data(NonFungibleLocalId::integer(2))?;
+    let early_1 = (
+        id_data_1.resources[&helper.ilis_address].early_stake,
+        id_data_1.resources[&helper.xrd_address].early_stake,
+    );
+    let early_2 = (
+        id_data_2.resources[&helper.ilis_address].early_stake,
+        id_data_2.resources[&helper.xrd_address].early_stake,
+    );
+
+    // Advance time to the end of the period and update rewards
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.rewarded_update()?;
+
+    // The early staker earns double rewards on both stakables
+    let (stake_id_1, rewards_1) = helper.update_incentives_id(stake_id_1)?;
+    let (stake_id_2, rewards_2) = helper.update_incentives_id(stake_id_2)?;
+
+    helper.assert_bucket_eq(&rewards_1, helper.ilis_address, dec!(15000))?;
+    helper.assert_bucket_eq(&rewards_2, helper.ilis_address, dec!(7500))?;
+
+    // After the bootstrap window, the early staker no longer earns the bonus
+    let new_time_2 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_2);
+    let _ = helper.rewarded_update()?;
+
+    let (stake_id_1, rewards_1_after) = helper.update_incentives_id(stake_id_1)?;
+    let (_stake_id_2, rewards_2_after) = helper.update_incentives_id(stake_id_2)?;
+
+    helper.assert_bucket_eq(&rewards_1_after, helper.ilis_address, dec!(7500))?;
+    helper.assert_bucket_eq(&rewards_2_after, helper.ilis_address, dec!(7500))?;
+
+    // Unstaking lowers the early stake, which doesn't grow again by staking more
+    let (_receipt, stake_id_1) =
+        helper.start_incentives_unstake(helper.xrd_address, stake_id_1, dec!(400))?;
+    let bucket_6 = helper.xrd.take(dec!(400), &mut helper.env)?;
+    let (_, _, _stake_id_1) = helper.stake_incentives_with_id(bucket_6, stake_id_1)?;
+    let id_data_1_after = helper.get_incentive_data(NonFungibleLocalId::integer(1))?;
+    let early_1_after = id_data_1_after.resources[&helper.xrd_address].early_stake;
+
+    // Multipliers above the maximum can't be set
+    let failure_multiplier = helper.add_stakable_with_bootstrap_bonus(
+        helper.admin_address,
+        dec!(1000),
+        BootstrapBonus {
+            multiplier: dec!(4),
+            max_stakers: 0,
+            window_days: 1,
+            min_stake: dec!(0),
+        },
+    );
+
+    assert_eq!(early_1, (dec!(10000), dec!(1000)));
+    assert_eq!(early_2, (dec!(0), dec!(0)));
+    assert_eq!(early_1_after, dec!(600));
+    assert!(failure_multiplier.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_incentives_reward_tiers() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+    let _ = helper.add_stakable(helper.ilis_address, dec!(10000), dec!(1.001), 365, dec!(1.002))?;
+    helper.env.enable_auth_module();
+
+    // Stakes of at least 2000 earn 1.5x rewards, stakes of at least 5000 earn 2x rewards
+    helper.set_incentives_reward_tiers(
+        helper.ilis_address,
+        vec![(dec!(2000), dec!(1.5)), (dec!(5000), dec!(2))],
+    )?;
+
+    // Rewards above the regular rewards are paid from the bonus vault, which can pay only part of them
+    let bonus_tokens = helper.ilis.take(dec!(6000), &mut helper.env)?;
+    helper.fund_incentives_bonuses(bonus_tokens)?;
+
+    // Stake below the first tier, exactly on the first tier, and above the second tier
+    let bucket_1 = helper.ilis.take(dec!(1000), &mut helper.env)?;
+    let stake_id_1 = helper.stake_incentives_without_id(bucket_1)?.0.unwrap();
+    let bucket_2 = helper.ilis.take(dec!(2000), &mut helper.env)?;
+    let stake_id_2 = helper.stake_incentives_without_id(bucket_2)?.0.unwrap();
+    let bucket_3 = helper.ilis.take(dec!(7000), &mut helper.env)?;
+    let stake_id_3 = helper.stake_incentives_without_id(bucket_3)?.0.unwrap();
+
+    // Advance time by 7 days and update rewards
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.rewarded_update()?;
+
+    // Each stake earns 1 token per staked token, multiplied by its tier
+    let (_stake_id_1, rewards_1) = helper.update_incentives_id(stake_id_1)?;
+    let (_stake_id_2, rewards_2) = helper.update_incentives_id(stake_id_2)?;
+    let (_stake_id_3, rewards_3) = helper.update_incentives_id(stake_id_3)?;
+
+    helper.assert_bucket_eq(&rewards_1, helper.ilis_address, dec!(1000))?;
+    helper.assert_bucket_eq(&rewards_2, helper.ilis_address, dec!(3000))?;
+
+    // The bonus vault only has 5000 tokens left, so the last staker gets only part of their bonus
+    helper.assert_bucket_eq(&rewards_3, helper.ilis_address, dec!(12000))?;
+
+    Ok(())
+}
+
+#[test]
+fn test_incentives_reward_tiers_below_one() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+    let _ = helper.add_stakable(helper.ilis_address, dec!(10000), dec!(1.001), 365, dec!(1.002))?;
+    helper.env.enable_auth_module();
+
+    // Stakes of at least 5000 earn half the rewards, favoring small holders
+    helper.set_incentives_reward_tiers(helper.ilis_address, vec![(dec!(5000), dec!(0.5))])?;
+
+    // Stake just below and just above the tier
+    let bucket_1 = helper.ilis.take(dec!(4999), &mut helper.env)?;
+    let stake_id_1 = helper.stake_incentives_without_id(bucket_1)?.0.unwrap();
+    let bucket_2 = helper.ilis.take(dec!(5001), &mut helper.env)?;
+    let stake_id_2 = helper.stake_incentives_without_id(bucket_2)?.0.unwrap();
+
+    // Advance time by 7 days and update rewards
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.rewarded_update()?;
+
+    let (_stake_id_1, rewards_1) = helper.update_incentives_id(stake_id_1)?;
+    let (_stake_id_2, rewards_2) = helper.update_incentives_id(stake_id_2)?;
+
+    helper.assert_bucket_eq(&rewards_1, helper.ilis_address, dec!(4999))?;
+    helper.assert_bucket_eq(&rewards_2, helper.ilis_address, dec!(2500.5))?;
+
+    // Reward tiers that aren't in ascending order of minimum stake can't be set
+    let failure = helper.set_incentives_reward_tiers(
+        helper.ilis_address,
+        vec![(dec!(5000), dec!(2)), (dec!(2000), dec!(1.5))],
+    );
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_wind_down_incentives_rewards() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+    let _ = helper.add_stakable(helper.ilis_address, dec!(10000), dec!(1.001), 365, dec!(1.002))?;
+    helper.env.enable_auth_module();
+    let rewards_before = helper.get_remaining_incentives_rewards()?;
+
+    // Stake 10000 tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_incentives_without_id(bucket_1)?.0.unwrap();
+
+    // Advance time by 7 days and wind down, keeping the unclaimed rewards of the passed period
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let remaining_rewards = helper.wind_down_incentives_rewards()?;
+
+    helper.assert_bucket_eq(
+        &remaining_rewards,
+        helper.ilis_address,
+        rewards_before - dec!(10000),
+    )?;
+
+    // The staker can still claim the rewards of the passed period
+    let (stake_id, rewards) = helper.update_incentives_id(stake_id)?;
+    helper.assert_bucket_eq(&rewards, helper.ilis_address, dec!(10000))?;
+
+    // No rewards are emitted afterwards
+    let new_time_2 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_2);
+    let _ = helper.rewarded_update()?;
+
+    let (stake_id, rewards) = helper.update_incentives_id(stake_id)?;
+    helper.assert_bucket_eq(&rewards, helper.ilis_address, dec!(0))?;
+
+    // Locking no longer pays a lock reward
+    let (_stake_id, lock_reward) =
+        helper.lock_incentives_stake(helper.ilis_address, stake_id, 10)?;
+    helper.assert_bucket_eq(&lock_reward, helper.ilis_address, dec!(0))?;
+
+    Ok(())
+}
+
+#[test]
+fn test_incentives_claim_rewards_per_resource() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+
+    // Add two stakable resources with specific parameters
+    let _ = helper.add_stakable(helper.ilis_address, dec!(10000), dec!(1.001), 365, dec!(1.002))?;
+    let _ = helper.add_stakable(helper.xrd_address, dec!(5000), dec!(1.001), 365, dec!(1.002))?;
+    helper.env.enable_auth_module();
+
+    // Stake both resources to the same ID
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_incentives_without_id(bucket_1)?.0.unwrap();
+    let bucket_2 = helper.xrd.take(dec!(1000), &mut helper.env)?;
+    let (_, _, stake_id) = helper.stake_incentives_with_id(bucket_2, stake_id)?;
 
     // Advance time by 7 days and update rewards
-    let new_time_4 = helper.env.get_current_time().add_days(7).unwrap();
-    helper.env.set_current_time(new_time_4);
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
     let _ = helper.rewarded_update()?;
 
-    // Check rewards for the second stake (should be double due to unclaimed previous period)
-    let (stake_id_2_returned, rewards) = helper.update_incentives_id(stake_id_2_returned)?;
-    helper.assert_bucket_eq(&rewards, helper.ilis_address, dec!(16000))?;
+    // Claim rewards for the staked ILIS only
+    let (stake_id, rewards) = helper.update_incentives_id_for(helper.ilis_address, stake_id)?;
+    helper.assert_bucket_eq(&rewards, helper.ilis_address, dec!(10000))?;
 
-    // Advance time by 7 days and update rewards
-    let new_time_5 = helper.env.get_current_time().add_days(7).unwrap();
-    helper.env.set_current_time(new_time_5);
-    let _ = helper.rewarded_update()?;
+    // Claim the remaining (XRD) rewards, which were left accruing
+    let (_stake_id, rewards) = helper.update_incentives_id(stake_id)?;
+    helper.assert_bucket_eq(&rewards, helper.ilis_address, dec!(5000))?;
 
-    // Check rewards for the second stake
-    let (_stake_id_2_returned, rewards) = helper.update_incentives_id(stake_id_2_returned)?;
-    helper.assert_bucket_eq(&rewards, helper.ilis_address, dec!(8000))?;
+    Ok(())
+}
 
-    // Advance time by 7 days and update rewards
-    let new_time_6 = helper.env.get_current_time().add_days(7).unwrap();
-    helper.env.set_current_time(new_time_6);
-    let _ = helper.rewarded_update()?;
+#[test]
+fn test_incentives_claim_to_account() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+    let _ = helper.add_stakable(helper.ilis_address, dec!(10000), dec!(1.001), 365, dec!(1.002))?;
 
-    // Do nothing (simulating no claim)
+    // Stake 10000 tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_incentives_without_id(bucket_1)?.0.unwrap();
 
     // Advance time by 7 days and update rewards
-    let new_time_7 = helper.env.get_current_time().add_days(7).unwrap();
-    helper.env.set_current_time(new_time_7);
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
     let _ = helper.rewarded_update()?;
 
-    // Check rewards for the first stake (should be a max of 5 periods, even though 7 periods have passed without claim, due to the max claim delay of 5 periods)
-    let (_stake_id_returned, rewards) = helper.update_incentives_id(stake_id_returned)?;
-    helper.assert_bucket_eq(&rewards, helper.ilis_address, dec!(10000))?;
+    // Claim the rewards into an account
+    let account = helper.create_account()?;
+    let (_stake_id, rewards) = helper.update_incentives_id_to(stake_id, account)?;
+    let deposited = helper.withdraw_from_account(account, helper.ilis_address, dec!(10000))?;
+
+    // The rewards land in the account instead of being returned
+    helper.assert_bucket_eq(&rewards, helper.ilis_address, dec!(0))?;
+    helper.assert_bucket_eq(&deposited, helper.ilis_address, dec!(10000))?;
 
     Ok(())
 }
@@ -317,6 +938,120 @@ fn test_incentives_locking() -> Result<(), RuntimeError> {
     Ok(())
 }
 
+#[test]
+fn test_incentives_claim_and_lock() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+
+    // Add a stakable resource with specific parameters
+    let _ = helper.add_stakable(helper.ilis_address, dec!(10000), dec!(1.001), 365, dec!(1.002))?;
+    helper.env.enable_auth_module();
+
+    // Stake 10000 tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_incentives_without_id(bucket_1)?.0.unwrap();
+
+    // Advance time by 7 days and update rewards
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.rewarded_update()?;
+
+    // Claim the rewards and lock them (together with the existing stake) for 10 days
+    let (_stake_id, lock_reward) = helper.claim_and_lock_incentives(stake_id, 10)?;
+
+    // Check that the rewards were staked and the position locked
+    let member_data = helper.get_incentive_data(NonFungibleLocalId::integer(1))?;
+    let resource = member_data.resources.get(&helper.ilis_address).unwrap();
+    assert_eq!(resource.amount_staked, dec!(20000));
+    assert_eq!(
+        resource.locked_until.unwrap(),
+        helper.env.get_current_time().add_days(10).unwrap()
+    );
+
+    // Check that only the lock reward was returned
+    assert!(lock_reward.amount(&mut helper.env)? > dec!(200));
+    assert!(lock_reward.amount(&mut helper.env)? < dec!(202));
+
+    Ok(())
+}
+
+#[test]
+fn test_incentives_claim_and_restake() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+
+    // Add a stakable resource with specific parameters
+    let _ = helper.add_stakable(helper.ilis_address, dec!(10000), dec!(1.001), 365, dec!(1.002))?;
+    helper.env.enable_auth_module();
+
+    // Stake 10000 tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_incentives_without_id(bucket_1)?.0.unwrap();
+
+    // Advance time by 21 days, updating rewards each week
+    for _ in 0..3 {
+        let new_time = helper.env.get_current_time().add_days(7).unwrap();
+        helper.env.set_current_time(new_time);
+        let _ = helper.rewarded_update()?;
+    }
+
+    // Claim the rewards and restake them to the same ID
+    let remaining_rewards_1 = helper.get_remaining_incentives_rewards()?;
+    let (stake_id, lock_reward) = helper.claim_and_restake_incentives(stake_id)?;
+    let remaining_rewards_2 = helper.get_remaining_incentives_rewards()?;
+    let reward_amount = remaining_rewards_1 - remaining_rewards_2;
+
+    // Check that the staked amount grew by the claimed rewards
+    let member_data = helper.get_incentive_data(NonFungibleLocalId::integer(1))?;
+    let resource = member_data.resources.get(&helper.ilis_address).unwrap();
+    assert!(reward_amount > dec!(0));
+    assert_eq!(resource.amount_staked, dec!(10000) + reward_amount);
+    assert!(lock_reward.is_none());
+
+    // Attempt to claim and restake again within the same period (should fail)
+    let failure = helper.claim_and_restake_incentives(stake_id);
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_incentives_pending_rewards() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+
+    // Add a stakable resource with specific parameters
+    let _ = helper.add_stakable(helper.ilis_address, dec!(10000), dec!(1.001), 365, dec!(1.002))?;
+    helper.env.enable_auth_module();
+
+    // Stake 10000 tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_incentives_without_id(bucket_1)?.0.unwrap();
+
+    // Nothing is pending directly after staking
+    let pending_rewards_1 = helper.get_pending_incentives_rewards(&stake_id)?;
+
+    // Advance time by 14 days, updating rewards each week
+    for _ in 0..2 {
+        let new_time = helper.env.get_current_time().add_days(7).unwrap();
+        helper.env.set_current_time(new_time);
+        let _ = helper.rewarded_update()?;
+    }
+
+    // Preview the pending rewards, then actually claim them
+    let pending_rewards_2 = helper.get_pending_incentives_rewards(&stake_id)?;
+    let (stake_id, rewards) = helper.update_incentives_id(stake_id)?;
+
+    // Check that the preview equals the claimed amount, and that nothing is pending after claiming
+    assert_eq!(pending_rewards_1, dec!(0));
+    assert!(pending_rewards_2 > dec!(0));
+    assert_eq!(rewards.amount(&mut helper.env)?, pending_rewards_2);
+    assert_eq!(helper.get_pending_incentives_rewards(&stake_id)?, dec!(0));
+
+    Ok(())
+}
+
 #[test]
 fn test_incentives_lock_too_long() -> Result<(), RuntimeError> {
     let mut helper = Helper::new().unwrap();
@@ -340,6 +1075,43 @@ fn test_incentives_lock_too_long() -> Result<(), RuntimeError> {
     Ok(())
 }
 
+#[test]
+fn test_incentives_relock_near_cap() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+    let _ = helper.add_stakable(helper.ilis_address, dec!(10000), dec!(1.001), 365, dec!(1.002))?;
+    helper.env.enable_auth_module();
+
+    // Stake 10000 tokens and lock them for 360 days
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let result = helper.stake_incentives_without_id(bucket_1)?;
+    let (stake_id, _) = helper.lock_incentives_stake(helper.ilis_address, result.0.unwrap(), 360)?;
+
+    // Advance time by 10 days, so the lock ends in 350 days
+    let new_time = helper.env.get_current_time().add_days(10).unwrap();
+    helper.env.set_current_time(new_time);
+
+    // Relocking up to the cap extends the existing lock
+    let (stake_id, _) = helper.lock_incentives_stake(helper.ilis_address, stake_id, 15)?;
+    let id_data = helper.get_incentive_data(NonFungibleLocalId::integer(1))?;
+    assert_eq!(
+        id_data
+            .resources
+            .get(&helper.ilis_address)
+            .unwrap()
+            .locked_until
+            .unwrap(),
+        new_time.add_days(365).unwrap()
+    );
+
+    // Relocking beyond the cap fails
+    let failure = helper.lock_incentives_stake(helper.ilis_address, stake_id, 1);
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
 #[test]
 fn test_incentives_lock_and_unstake() -> Result<(), RuntimeError> {
     let mut helper = Helper::new().unwrap();
@@ -502,3 +1274,176 @@ fn test_incentives_unlock_to_unstake_partial_pay_off() -> Result<(), RuntimeErro
 
     Ok(())
 }
+
+#[test]
+fn test_incentives_stake_across_period_requires_claim() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+    let _ = helper.add_stakable(helper.ilis_address, dec!(10000), dec!(1.001), 365, dec!(1.002))?;
+    helper.env.enable_auth_module();
+
+    // Stake 10000 tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_incentives_without_id(bucket_1)?.0.unwrap();
+
+    // Advance time by 7 days and update rewards
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.rewarded_update()?;
+
+    // Claim rewards, after which staking more is possible
+    let (stake_id, _rewards) = helper.update_incentives_id(stake_id)?;
+    let bucket_2 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let (_, _, stake_id) = helper.stake_incentives_with_id(bucket_2, stake_id)?;
+
+    let id_data = helper.get_incentive_data(NonFungibleLocalId::integer(1))?;
+    assert_eq!(
+        id_data
+            .resources
+            .get(&helper.ilis_address)
+            .unwrap()
+            .amount_staked,
+        dec!(20000)
+    );
+
+    // Advance time by 7 days and update rewards
+    let new_time_2 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_2);
+    let _ = helper.rewarded_update()?;
+
+    // Staking more without claiming fails
+    let bucket_3 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let failure = helper.stake_incentives_with_id(bucket_3, stake_id);
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_incentives_stake_with_auto_claim() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+    let _ = helper.add_stakable(helper.ilis_address, dec!(10000), dec!(1.001), 365, dec!(1.002))?;
+    helper.env.enable_auth_module();
+
+    // Stake 10000 tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_incentives_without_id(bucket_1)?.0.unwrap();
+
+    // Staking more before a period has passed claims nothing
+    let bucket_2 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let (_, rewards, stake_id) = helper.stake_incentives_with_id_auto_claim(bucket_2, stake_id)?;
+
+    assert!(rewards.is_none());
+
+    // Advance time by 7 days and update rewards
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.rewarded_update()?;
+
+    // Staking more with auto-claim claims the unclaimed rewards instead of failing
+    let bucket_3 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let (_, rewards, _stake_id) = helper.stake_incentives_with_id_auto_claim(bucket_3, stake_id)?;
+
+    helper.assert_bucket_eq(&rewards.unwrap(), helper.ilis_address, dec!(10000))?;
+
+    let id_data = helper.get_incentive_data(NonFungibleLocalId::integer(1))?;
+    assert_eq!(
+        id_data
+            .resources
+            .get(&helper.ilis_address)
+            .unwrap()
+            .amount_staked,
+        dec!(30000)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_incentives_restake_after_full_unstake_across_period() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+    let _ = helper.add_stakable(helper.ilis_address, dec!(10000), dec!(1.001), 365, dec!(1.002))?;
+    helper.env.enable_auth_module();
+
+    // Stake 10000 tokens and unstake them all
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_incentives_without_id(bucket_1)?.0.unwrap();
+    let (_unstake_receipt, stake_id) =
+        helper.start_incentives_unstake(helper.ilis_address, stake_id, dec!(10000))?;
+
+    // Advance time by 7 days and update rewards
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.rewarded_update()?;
+
+    // Staking to the emptied ID works without claiming first
+    let bucket_2 = helper.ilis.take(dec!(5000), &mut helper.env)?;
+    let _ = helper.stake_incentives_with_id(bucket_2, stake_id)?;
+
+    let id_data = helper.get_incentive_data(NonFungibleLocalId::integer(1))?;
+    assert_eq!(
+        id_data
+            .resources
+            .get(&helper.ilis_address)
+            .unwrap()
+            .amount_staked,
+        dec!(5000)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_incentives_rewards_match_per_period_accounting() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.env.disable_auth_module();
+    let _ = helper.add_stakable(helper.ilis_address, dec!(10000), dec!(1.001), 365, dec!(1.002))?;
+    helper.env.enable_auth_module();
+
+    // Stake 10000 tokens to a first ID and 30000 tokens to a second ID
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_1 = helper.stake_incentives_without_id(bucket_1)?.0.unwrap();
+    let bucket_2 = helper.ilis.take(dec!(30000), &mut helper.env)?;
+    let stake_id_2 = helper.stake_incentives_without_id(bucket_2)?.0.unwrap();
+
+    // Advance time by 7 days, first ID claims and stakes 10000 more tokens
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let (stake_id_1, rewards_1) = helper.update_incentives_id(stake_id_1)?;
+    let bucket_3 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let (_, _, stake_id_1) = helper.stake_incentives_with_id(bucket_3, stake_id_1)?;
+
+    // Advance time by 14 days (2 periods) and update rewards
+    let new_time_2 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_2);
+    let _ = helper.rewarded_update()?;
+    let new_time_3 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_3);
+    let _ = helper.rewarded_update()?;
+
+    // Both IDs claim
+    let (_stake_id_1, rewards_2) = helper.update_incentives_id(stake_id_1)?;
+    let (_stake_id_2, rewards_3) = helper.update_incentives_id(stake_id_2)?;
+
+    // Rewards per staked token per period: 10000 / 40000 in period 0, 10000 / 50000 in periods 1 and 2
+    let period_0: Decimal = dec!(10000) / dec!(40000);
+    let period_1: Decimal = dec!(10000) / dec!(50000);
+    let period_2: Decimal = dec!(10000) / dec!(50000);
+
+    helper.assert_bucket_eq(&rewards_1, helper.ilis_address, period_0 * dec!(10000))?;
+    helper.assert_bucket_eq(
+        &rewards_2,
+        helper.ilis_address,
+        period_1 * dec!(20000) + period_2 * dec!(20000),
+    )?;
+    helper.assert_bucket_eq(
+        &rewards_3,
+        helper.ilis_address,
+        period_0 * dec!(30000) + period_1 * dec!(30000) + period_2 * dec!(30000),
+    )?;
+
+    Ok(())
+}
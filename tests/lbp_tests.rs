@@ -60,3 +60,303 @@ fn test_bootstrap_lifetime() -> Result<(), RuntimeError> {
 
     Ok(())
 }
+
+#[test]
+fn test_bootstrap_swap_disabled_after_end() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    let xrd_bucket = helper.xrd.take(dec!(1), &mut helper.env)?;
+    let xrd_bucket_2 = helper.xrd.take(dec!(1), &mut helper.env)?;
+
+    // The bootstrap is only active once started
+    assert!(!helper.is_bootstrap_active()?);
+    let _ = helper.start_bootstrap()?;
+    assert!(helper.is_bootstrap_active()?);
+
+    let _bucket = helper.bootstrap_swap(xrd_bucket)?;
+
+    // Advance time past the scheduled end of the bootstrap
+    let new_time = helper.env.get_current_time().add_days(10).unwrap();
+    helper.env.set_current_time(new_time);
+
+    assert!(!helper.is_bootstrap_active()?);
+
+    // Swapping after the scheduled end fails, even before the bootstrap is finished
+    let failure = helper.bootstrap_swap(xrd_bucket_2);
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_bootstrap_participation_bonus() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Pay a bonus of 10% of the purchase, escrowing purchases until the bootstrap has finished
+    helper.set_bootstrap_participation_bonus(Some(dec!("0.1")), true)?;
+
+    let xrd_bucket = helper.xrd.take(dec!(1), &mut helper.env)?;
+    let xrd_bucket_2 = helper.xrd.take(dec!(1), &mut helper.env)?;
+    let _ = helper.start_bootstrap()?;
+
+    // Buyers receive a participation NFT, while their purchase is escrowed
+    let (purchase_1, participation_1) = helper.bootstrap_swap_with_participation(xrd_bucket)?;
+    let participation_1 = participation_1.unwrap();
+    let participation_data_1 = helper.get_bootstrap_participation_data(&participation_1)?;
+    let purchase_amount_1 = participation_data_1.purchase_amount;
+    assert_eq!(participation_data_1.bonus_rate, dec!("0.1"));
+    helper.assert_bucket_eq(&purchase_1, helper.ilis_address, dec!(0))?;
+
+    // The bonus rate is recorded at the time of purchase, and purchases aren't escrowed without a holding requirement
+    helper.set_bootstrap_participation_bonus(Some(dec!("0.2")), false)?;
+    let (purchase_2, participation_2) = helper.bootstrap_swap_with_participation(xrd_bucket_2)?;
+    let participation_2 = participation_2.unwrap();
+    let purchase_amount_2 = purchase_2.amount(&mut helper.env)?;
+
+    // Selling doesn't mint a participation NFT
+    let sold_tokens = purchase_2.take(dec!(1), &mut helper.env)?;
+    let (_xrd_returned, no_participation) =
+        helper.bootstrap_swap_with_participation(sold_tokens)?;
+
+    // The bonus can't be claimed before the bootstrap has finished
+    let failure_early = helper.claim_bootstrap_participation_bonus(&participation_1);
+
+    // Advance time past the scheduled end of the bootstrap and finish it
+    let new_time = helper.env.get_current_time().add_days(10).unwrap();
+    helper.env.set_current_time(new_time);
+    let _ = helper.finish_bootstrap()?;
+
+    // While the bonus vault is empty, an escrowed purchase is still released, but a bonus alone can't be claimed
+    let payout_1 = helper.claim_bootstrap_participation_bonus(&participation_1)?;
+    helper.assert_bucket_eq(&payout_1, helper.ilis_address, purchase_amount_1)?;
+    let failure_unfunded = helper.claim_bootstrap_participation_bonus(&participation_2);
+
+    // With an underfunded bonus vault, the available part of the bonus is paid
+    let partial_bonus = purchase_amount_1 * dec!("0.05");
+    let bonus_tokens = helper.ilis.take(partial_bonus, &mut helper.env)?;
+    helper.fund_bootstrap_participation_bonus(bonus_tokens)?;
+    let payout_1_partial = helper.claim_bootstrap_participation_bonus(&participation_1)?;
+    helper.assert_bucket_eq(&payout_1_partial, helper.ilis_address, partial_bonus)?;
+
+    // Once funded, the rest of the bonus at the recorded rate is paid
+    let bonus_tokens_2 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    helper.fund_bootstrap_participation_bonus(bonus_tokens_2)?;
+    let payout_1_rest = helper.claim_bootstrap_participation_bonus(&participation_1)?;
+    helper.assert_bucket_eq(
+        &payout_1_rest,
+        helper.ilis_address,
+        purchase_amount_1 * dec!("0.1") - partial_bonus,
+    )?;
+
+    let bonus_2 = helper.claim_bootstrap_participation_bonus(&participation_2)?;
+    helper.assert_bucket_eq(
+        &bonus_2,
+        helper.ilis_address,
+        purchase_amount_2 * dec!("0.2"),
+    )?;
+
+    // A participation bonus can't be claimed again once fully paid
+    let failure_claimed = helper.claim_bootstrap_participation_bonus(&participation_1);
+
+    assert!(no_participation.is_none());
+    assert!(failure_early.is_err());
+    assert!(failure_unfunded.is_err());
+    assert!(failure_claimed.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_bootstrap_swap_min_output() -> Result<(), RuntimeError> {
+    // Initialize a new helper instance
+    let mut helper = Helper::new().unwrap();
+
+    // Create buckets for testing
+    let xrd_bucket = helper.xrd.take(dec!(1), &mut helper.env)?;
+    let xrd_bucket_2 = helper.xrd.take(dec!(1), &mut helper.env)?;
+
+    // Start the bootstrap process
+    let _ = helper.start_bootstrap()?;
+
+    // Perform initial swap, which moves the price up
+    let bucket = helper.bootstrap_swap(xrd_bucket)?;
+    let output = bucket.amount(&mut helper.env)?;
+
+    // Swapping the same amount again while requiring the same output fails
+    let failure = helper.bootstrap_swap_with_min_output(xrd_bucket_2, output);
+
+    assert!(output > dec!(0));
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_bootstrap_refund_initial() -> Result<(), RuntimeError> {
+    // Initialize a new helper instance, with a bootstrap refunding the initial contribution
+    let mut helper = Helper::new().unwrap();
+    let _ = helper.instantiate_bootstrap(true)?;
+    let boot_bucket = helper.boot.take(dec!(1), &mut helper.env)?;
+
+    // Start the bootstrap process, advance time by 10 days and finish it
+    let _ = helper.start_bootstrap()?;
+    let new_time = helper.env.get_current_time().add_days(10).unwrap();
+    helper.env.set_current_time(new_time);
+    let _ = helper.finish_bootstrap()?;
+
+    // The initial contribution is reclaimable, and its mother token equivalent refunded to the DAO
+    let (refund_initial, reclaimable, mother_refund) =
+        helper.get_bootstrap_reclaimable_amounts()?;
+    assert!(refund_initial);
+    assert_eq!(reclaimable, dec!(500));
+    assert!(mother_refund > dec!(0));
+
+    // Reclaim the initial contribution
+    let retrieved_initial = helper.reclaim_bootstrap_initial(boot_bucket)?;
+    let _ = helper.assert_bucket_eq(&retrieved_initial, helper.xrd_address, dec!(500))?;
+
+    let (_, reclaimable_after, _) = helper.get_bootstrap_reclaimable_amounts()?;
+    assert_eq!(reclaimable_after, dec!(0));
+
+    Ok(())
+}
+
+#[test]
+fn test_bootstrap_balance_reads() -> Result<(), RuntimeError> {
+    // Initialize a new helper instance, with a bootstrap refunding the initial contribution
+    let mut helper = Helper::new().unwrap();
+    let _ = helper.instantiate_bootstrap(true)?;
+    let boot_bucket = helper.boot.take(dec!(1), &mut helper.env)?;
+
+    // Start the bootstrap process, nothing is reclaimable or raised yet
+    let _ = helper.start_bootstrap()?;
+    let reclaimable_before = helper.get_bootstrap_reclaimable()?;
+    let balances_before = helper.get_bootstrap_post_balances()?;
+
+    // Advance time by 10 days and finish the bootstrap process
+    let new_time = helper.env.get_current_time().add_days(10).unwrap();
+    helper.env.set_current_time(new_time);
+    let _ = helper.finish_bootstrap()?;
+
+    // The reclaimable amounts match the ones reported with the refund flag, and liquidity is raised
+    let (reclaimable, mother_refund) = helper.get_bootstrap_reclaimable()?;
+    let (_, reclaimable_amount, mother_refund_amount) =
+        helper.get_bootstrap_reclaimable_amounts()?;
+    let (resource1_balance, resource2_balance) = helper.get_bootstrap_post_balances()?;
+
+    // Reclaim the initial contribution, which receives exactly the reclaimable amount
+    let retrieved_initial = helper.reclaim_bootstrap_initial(boot_bucket)?;
+    let (reclaimable_after, _) = helper.get_bootstrap_reclaimable()?;
+
+    assert_eq!(reclaimable_before, (dec!(0), dec!(0)));
+    assert_eq!(balances_before, (dec!(0), dec!(0)));
+    assert_eq!(reclaimable, dec!(500));
+    assert_eq!(reclaimable, reclaimable_amount);
+    assert_eq!(mother_refund, mother_refund_amount);
+    assert!(resource1_balance > dec!(0));
+    assert!(resource2_balance > dec!(0));
+    helper.assert_bucket_eq(&retrieved_initial, helper.xrd_address, reclaimable)?;
+    assert_eq!(reclaimable_after, dec!(0));
+
+    Ok(())
+}
+
+#[test]
+fn test_bootstrap_no_refund_initial() -> Result<(), RuntimeError> {
+    // Initialize a new helper instance, with a bootstrap not refunding the initial contribution
+    let mut helper = Helper::new().unwrap();
+    let _ = helper.instantiate_bootstrap(false)?;
+    let boot_bucket = helper.boot.take(dec!(1), &mut helper.env)?;
+
+    // Start the bootstrap process, advance time by 10 days and finish it
+    let _ = helper.start_bootstrap()?;
+    let new_time = helper.env.get_current_time().add_days(10).unwrap();
+    helper.env.set_current_time(new_time);
+    let _ = helper.finish_bootstrap()?;
+
+    // Nothing is reclaimable, the initial contribution is part of the raised liquidity
+    let (refund_initial, reclaimable, mother_refund) =
+        helper.get_bootstrap_reclaimable_amounts()?;
+    assert!(!refund_initial);
+    assert_eq!(reclaimable, dec!(0));
+    assert_eq!(mother_refund, dec!(0));
+
+    // Attempt to reclaim the initial contribution (should fail)
+    let failure = helper.reclaim_bootstrap_initial(boot_bucket);
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_bootstrap_single_sided_contribution() -> Result<(), RuntimeError> {
+    // Initialize a new helper instance
+    let mut helper = Helper::new().unwrap();
+
+    // Start the bootstrap process and enable single-sided contributions
+    let _ = helper.start_bootstrap()?;
+    let _ = helper.set_bootstrap_single_sided(true)?;
+
+    // Contribute XRD single-sided
+    let xrd_bucket = helper.xrd.take(dec!(100), &mut helper.env)?;
+    let (claim, _leftover) = helper.bootstrap_add_single_sided(xrd_bucket)?;
+    assert!(claim.amount(&mut helper.env)? > dec!(0));
+
+    // Advance time by 10 days and finish the bootstrap process
+    let new_time = helper.env.get_current_time().add_days(10).unwrap();
+    helper.env.set_current_time(new_time);
+    let _ = helper.finish_bootstrap()?;
+
+    // Redeem the claim for both resources
+    let (resource1, resource2) = helper.bootstrap_redeem_single_sided(claim)?;
+    assert!(resource1.amount(&mut helper.env)? > dec!(0));
+    assert!(resource2.amount(&mut helper.env)? > dec!(0));
+
+    Ok(())
+}
+
+#[test]
+fn test_bootstrap_prune_ledger_before_finish() -> Result<(), RuntimeError> {
+    // Initialize a new helper instance
+    let mut helper = Helper::new().unwrap();
+    let xrd_bucket = helper.xrd.take(dec!(1), &mut helper.env)?;
+    let xrd_bucket_2 = helper.xrd.take(dec!(1), &mut helper.env)?;
+
+    // Start the bootstrap process and perform a swap
+    let _ = helper.start_bootstrap()?;
+    let _ = helper.bootstrap_swap(xrd_bucket)?;
+
+    // Advance time by 5 days and perform another swap
+    let new_time = helper.env.get_current_time().add_days(5).unwrap();
+    helper.env.set_current_time(new_time);
+    let _ = helper.bootstrap_swap(xrd_bucket_2)?;
+
+    // Pruning is not possible before the bootstrap has finished
+    let failure = helper.prune_bootstrap_ledger(new_time);
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_bootstrap_prune_ledger_after_finish() -> Result<(), RuntimeError> {
+    // Initialize a new helper instance
+    let mut helper = Helper::new().unwrap();
+    let xrd_bucket = helper.xrd.take(dec!(1), &mut helper.env)?;
+
+    // Start the bootstrap process and perform a swap
+    let _ = helper.start_bootstrap()?;
+    let _ = helper.bootstrap_swap(xrd_bucket)?;
+
+    // Advance time by 10 days and finish the bootstrap process
+    let new_time = helper.env.get_current_time().add_days(10).unwrap();
+    helper.env.set_current_time(new_time);
+    let _ = helper.finish_bootstrap()?;
+
+    // Prune the entire ledger, twice to check pruning is idempotent
+    let _ = helper.prune_bootstrap_ledger(new_time)?;
+    let _ = helper.prune_bootstrap_ledger(new_time)?;
+
+    Ok(())
+}
@@ -31,114 +31,908 @@ fn test_stake_without_and_with_id() -> Result<(), RuntimeError> {
     Ok(())
 }
 
+#[test]
+fn test_stake_mother_tokens_converts_to_pool_tokens() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake 10000 tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let _ = helper.stake_without_id(bucket_1)?;
+
+    // Advance time by 1 day and update rewards, doubling the value of a pool token
+    let new_time_1 = helper.env.get_current_time().add_days(1).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.rewarded_update()?;
+
+    // Stake another 10000 mother tokens to a new ID
+    let bucket_2 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let _ = helper.stake_without_id(bucket_2)?;
+    let id_data = helper.get_member_data(NonFungibleLocalId::integer(2))?;
+
+    // The mother tokens are recorded as pool tokens
+    assert_eq!(helper.get_real_amount()?, dec!(2));
+    assert_eq!(id_data.pool_amount_staked, dec!(5000));
+
+    Ok(())
+}
+
+#[test]
+fn test_stake_pool_tokens() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake 10000 tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let _ = helper.stake_without_id(bucket_1)?;
+
+    // Advance time by 1 day and update rewards, doubling the value of a pool token
+    let new_time_1 = helper.env.get_current_time().add_days(1).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.rewarded_update()?;
+
+    // Get pool tokens for 10000 mother tokens and stake them as they are
+    let bucket_2 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let pool_tokens = helper.contribute_to_mother_pool(bucket_2)?;
+    helper.assert_bucket_eq(&pool_tokens, helper.pool_token, dec!(5000))?;
+    let stake_id = helper.stake_without_id(pool_tokens)?.0.unwrap();
+    let id_data = helper.get_member_data(NonFungibleLocalId::integer(2))?;
+
+    // Staking any other token fails
+    let bucket_3 = helper.xrd.take(dec!(100), &mut helper.env)?;
+    let failure = helper.stake_with_id(bucket_3, stake_id);
+
+    // The pool tokens are worth as much as the mother tokens they were made of
+    assert_eq!(id_data.pool_amount_staked, dec!(5000));
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
 #[test]
 fn test_stake_and_unstake_with_id() -> Result<(), RuntimeError> {
     let mut helper = Helper::new().unwrap();
 
     // Stake 10000 tokens
-    let stake_bucket = helper.ilis.take(dec!(10000), &mut helper.env)?;
-    let result = helper.stake_without_id(stake_bucket)?;
+    let stake_bucket = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let result = helper.stake_without_id(stake_bucket)?;
+
+    // Unstake 5000 tokens
+    let (unstake_receipt_1, stake_id_1) = helper.start_unstake(result.0.unwrap(), dec!(5000))?;
+    let id_data_2 = helper.get_member_data(NonFungibleLocalId::integer(1))?;
+
+    // Assert the remaining staked amount
+    assert_eq!(id_data_2.pool_amount_staked, dec!(5000));
+
+    // Unstake another 1000 tokens
+    let (_unstake_receipt_2, stake_id_2) = helper.start_unstake(stake_id_1, dec!(1000))?;
+
+    // Unstake the remaining 4000 tokens
+    let (unstake_receipt_3, _) = helper.start_unstake(stake_id_2, dec!(6000))?;
+    let id_data_3 = helper.get_member_data(NonFungibleLocalId::integer(1))?;
+
+    // Assert that no tokens are left staked
+    assert_eq!(id_data_3.pool_amount_staked, dec!(0));
+
+    // Advance time by 7 days
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+
+    // Finish unstaking and assert the returned amounts
+    let unstaked_bucket_1 = helper.finish_unstake(unstake_receipt_1)?;
+    let unstaked_bucket_2 = helper.finish_unstake(unstake_receipt_3)?;
+
+    helper.assert_bucket_eq(&unstaked_bucket_1, helper.ilis_address, dec!(5000))?;
+    helper.assert_bucket_eq(&unstaked_bucket_2, helper.ilis_address, dec!(4000))?;
+
+    Ok(())
+}
+
+#[test]
+fn test_finish_unstake_and_restake() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake 10000 tokens
+    let stake_bucket = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let result = helper.stake_without_id(stake_bucket)?;
+
+    // Unstake 6000 tokens
+    let (unstake_receipt_1, stake_id_1) = helper.start_unstake(result.0.unwrap(), dec!(6000))?;
+
+    // Advance time by 7 days
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+
+    // Redeem the receipt and restake the tokens to the same ID
+    let (new_id, _, stake_id_2) =
+        helper.finish_unstake_and_restake(unstake_receipt_1, stake_id_1)?;
+    assert!(new_id.is_none());
+
+    let id_data = helper.get_member_data(NonFungibleLocalId::integer(1))?;
+    assert_eq!(id_data.pool_amount_staked, dec!(10000));
+
+    // Restaking a receipt before its redemption time fails
+    let (unstake_receipt_2, stake_id_3) = helper.start_unstake(stake_id_2, dec!(1000))?;
+    let failure = helper.finish_unstake_and_restake(unstake_receipt_2, stake_id_3);
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_unstake_before_time() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake 10000 tokens
+    let stake_bucket = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let result = helper.stake_without_id(stake_bucket)?;
+
+    // Start unstaking 5000 tokens
+    let (unstake_receipt_1, _stake_id_1) = helper.start_unstake(result.0.unwrap(), dec!(5000))?;
+
+    // Attempt to finish unstaking immediately (should fail)
+    let unstaked_bucket_fail = helper.finish_unstake(unstake_receipt_1);
+
+    assert!(unstaked_bucket_fail.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_finish_unstake_batch() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake 10000 tokens
+    let stake_bucket = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let result = helper.stake_without_id(stake_bucket)?;
+
+    // Unstake three times, creating three unstake receipts
+    let (unstake_receipt_1, stake_id_1) = helper.start_unstake(result.0.unwrap(), dec!(1000))?;
+    let (unstake_receipt_2, stake_id_2) = helper.start_unstake(stake_id_1, dec!(2000))?;
+    let (unstake_receipt_3, stake_id_3) = helper.start_unstake(stake_id_2, dec!(3000))?;
+
+    // Advance time by 7 days
+    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_1);
+
+    // Finish all unstakes at once and assert the combined amount
+    let unstaked_bucket = helper.finish_unstake_batch(vec![
+        unstake_receipt_1,
+        unstake_receipt_2,
+        unstake_receipt_3,
+    ])?;
+    assert_eq!(unstaked_bucket.amount(&mut helper.env)?, dec!(6000));
+
+    // Attempt to finish a batch containing a receipt that isn't redeemable yet (should fail)
+    let (unstake_receipt_4, stake_id_4) = helper.start_unstake(stake_id_3, dec!(1000))?;
+    let new_time_2 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_2);
+    let (unstake_receipt_5, _) = helper.start_unstake(stake_id_4, dec!(1000))?;
+    let failure = helper.finish_unstake_batch(vec![unstake_receipt_4, unstake_receipt_5]);
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_emergency_unstake() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake 10000 tokens on two different staking IDs
+    let stake_bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_1 = helper.stake_without_id(stake_bucket_1)?.0.unwrap();
+    let stake_bucket_2 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_2 = helper.stake_without_id(stake_bucket_2)?.0.unwrap();
+    let real_amount_1 = helper.get_real_amount()?;
+
+    // Enable emergency unstaking with a 10% penalty and emergency unstake 5000 tokens
+    helper.set_emergency_penalty(dec!("0.1"))?;
+    let (unstaked_bucket, stake_id_1) = helper.emergency_unstake(stake_id_1, dec!(5000), 1000)?;
+    let real_amount_2 = helper.get_real_amount()?;
+
+    // The caller receives the reduced amount right away, and the remaining pool tokens are worth more
+    helper.assert_bucket_eq(&unstaked_bucket, helper.ilis_address, dec!(4500))?;
+    assert!(real_amount_2 > real_amount_1);
+
+    // Attempt to emergency unstake while accepting a lower penalty (should fail)
+    let failure_1 = helper.emergency_unstake(stake_id_1, dec!(1000), 500);
+
+    // Attempt to emergency unstake after disabling it again (should fail)
+    helper.set_emergency_penalty(dec!(0))?;
+    let failure_2 = helper.emergency_unstake(stake_id_2, dec!(1000), 1000);
+
+    assert!(failure_1.is_err());
+    assert!(failure_2.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_emergency_unstake_last_staker() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake 10000 tokens on a single staking ID
+    let stake_bucket = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(stake_bucket)?.0.unwrap();
+    let rewards_before = helper.get_remaining_staking_rewards()?;
+
+    // The only staker emergency unstakes everything with a 10% penalty
+    helper.set_emergency_penalty(dec!("0.1"))?;
+    let (unstaked_bucket, _stake_id) = helper.emergency_unstake(stake_id, dec!(10000), 1000)?;
+    let rewards_after = helper.get_remaining_staking_rewards()?;
+
+    // The caller receives the reduced amount, and with no stakers left the penalty goes to the reward vault
+    helper.assert_bucket_eq(&unstaked_bucket, helper.ilis_address, dec!(9000))?;
+    assert_eq!(rewards_after - rewards_before, dec!(1000));
+
+    Ok(())
+}
+
+#[test]
+fn test_accelerate_unstake_receipts() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake 10000 tokens and unstake 5000 of them
+    let stake_bucket = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let result = helper.stake_without_id(stake_bucket)?;
+    let (unstake_receipt_1, stake_id) = helper.start_unstake(result.0.unwrap(), dec!(5000))?;
+
+    // Accelerate outstanding unstake receipts to be redeemable after 1 day
+    let accelerated_time = helper.env.get_current_time().add_days(1).unwrap();
+    helper.accelerate_unstake_receipts(accelerated_time)?;
+
+    // Unstake the other 5000 tokens after accelerating
+    let (unstake_receipt_2, _stake_id) = helper.start_unstake(stake_id, dec!(5000))?;
+
+    // Advance time by 1 day
+    let new_time_1 = helper.env.get_current_time().add_days(1).unwrap();
+    helper.env.set_current_time(new_time_1);
+
+    // The accelerated receipt can be redeemed, the later one still has the normal delay
+    let unstaked_bucket_1 = helper.finish_unstake(unstake_receipt_1)?;
+    helper.assert_bucket_eq(&unstaked_bucket_1, helper.ilis_address, dec!(5000))?;
+
+    let unstaked_bucket_fail = helper.finish_unstake(unstake_receipt_2);
+
+    assert!(unstaked_bucket_fail.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_accelerations_scoped_to_earlier_receipts() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    let start_time = helper.env.get_current_time();
+
+    // Stake 10000 tokens and unstake 4000 of them
+    let stake_bucket = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let result = helper.stake_without_id(stake_bucket)?;
+    let (_unstake_receipt_1, stake_id) = helper.start_unstake(result.0.unwrap(), dec!(4000))?;
+
+    // Accelerate the first receipt to be redeemable after 2 days, then unstake 6000 more
+    let accelerated_time_1 = start_time.add_days(2).unwrap();
+    helper.accelerate_unstake_receipts(accelerated_time_1)?;
+    let (_unstake_receipt_2, _stake_id) = helper.start_unstake(stake_id, dec!(6000))?;
+
+    // Accelerate again to a later time, which only moves up the second receipt
+    let accelerated_time_2 = start_time.add_days(4).unwrap();
+    helper.accelerate_unstake_receipts(accelerated_time_2)?;
+
+    let info = helper.get_unstake_receipts_info(vec![
+        NonFungibleLocalId::integer(1),
+        NonFungibleLocalId::integer(2),
+    ])?;
+
+    assert_eq!(
+        info,
+        vec![
+            (dec!(4000), accelerated_time_1),
+            (dec!(6000), accelerated_time_2)
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_get_unstake_receipts_info() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    let start_time = helper.env.get_current_time();
+
+    // Stake 10000 tokens and unstake 4000 of them
+    let stake_bucket = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let result = helper.stake_without_id(stake_bucket)?;
+    let (_unstake_receipt_1, stake_id) = helper.start_unstake(result.0.unwrap(), dec!(4000))?;
+
+    // Accelerate outstanding unstake receipts to be redeemable after 1 day, then unstake 6000 more
+    let accelerated_time = start_time.add_days(1).unwrap();
+    helper.accelerate_unstake_receipts(accelerated_time)?;
+    let (_unstake_receipt_2, _stake_id) = helper.start_unstake(stake_id, dec!(6000))?;
+
+    // The first receipt shows the accelerated redemption time, the second the normal delay
+    let info = helper.get_unstake_receipts_info(vec![
+        NonFungibleLocalId::integer(1),
+        NonFungibleLocalId::integer(2),
+    ])?;
+
+    assert_eq!(
+        info,
+        vec![
+            (dec!(4000), accelerated_time),
+            (dec!(6000), start_time.add_days(7).unwrap())
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_transfer_stake() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake 10000 tokens
+    let stake_bucket = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let result = helper.stake_without_id(stake_bucket)?;
+
+    // Transfer 4000 tokens to a new stake
+    let (transfer_receipt, _stake_id) =
+        helper.start_unstake_transfer(result.0.unwrap(), dec!(4000))?;
+
+    let _result_2 = helper.stake_without_id(transfer_receipt)?;
+
+    // Assert the amounts in both stakes
+    let id_data_1 = helper.get_member_data(NonFungibleLocalId::integer(1))?;
+    assert_eq!(id_data_1.pool_amount_staked, dec!(6000));
+
+    let id_data_2 = helper.get_member_data(NonFungibleLocalId::integer(2))?;
+    assert_eq!(id_data_2.pool_amount_staked, dec!(4000));
+
+    Ok(())
+}
+
+#[test]
+fn test_transfer_full_position() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake 10000 tokens
+    let stake_bucket = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let result = helper.stake_without_id(stake_bucket)?;
+
+    // Transfer the whole position to a new account
+    let account_1: Reference = helper.create_account()?;
+    let _stake_id = helper.transfer_full_position(result.0.unwrap(), account_1)?;
+
+    let id_data_1 = helper.get_member_data(NonFungibleLocalId::integer(1))?;
+    assert_eq!(id_data_1.pool_amount_staked, dec!(0));
+
+    // The recipient redeems the transfer receipt for a new stake
+    let transfer_receipt_address = helper.get_stake_transfer_receipt_address()?;
+    let transfer_receipt = helper.withdraw_nft_from_account(
+        account_1,
+        transfer_receipt_address,
+        NonFungibleLocalId::integer(1),
+    )?;
+    let _result_2 = helper.stake_without_id(transfer_receipt)?;
+
+    let id_data_2 = helper.get_member_data(NonFungibleLocalId::integer(2))?;
+    assert_eq!(id_data_2.pool_amount_staked, dec!(10000));
+
+    Ok(())
+}
+
+#[test]
+fn test_transfer_full_position_while_locked() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake 10000 tokens and lock them
+    let stake_bucket = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let result = helper.stake_without_id(stake_bucket)?;
+    let stake_id = helper.lock_stake(result.0.unwrap(), 30, false)?;
+
+    // Transferring a locked position fails
+    let account_1: Reference = helper.create_account()?;
+    let failure = helper.transfer_full_position(stake_id, account_1);
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_transfer_stake_with_delay() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Require transfer receipts to be held for 3 days
+    helper.set_staking_transfer_receipt_delay(3)?;
+
+    // Stake 10000 tokens
+    let stake_bucket = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let result = helper.stake_without_id(stake_bucket)?;
+
+    // Transfer 4000 tokens
+    let (transfer_receipt_1, stake_id) =
+        helper.start_unstake_transfer(result.0.unwrap(), dec!(4000))?;
+
+    // Advance time by 3 days
+    let new_time = helper.env.get_current_time().add_days(3).unwrap();
+    helper.env.set_current_time(new_time);
+
+    // Transfer another 2000 tokens
+    let (transfer_receipt_2, _stake_id) = helper.start_unstake_transfer(stake_id, dec!(2000))?;
+
+    // First receipt can be redeemed now
+    let _result_2 = helper.stake_without_id(transfer_receipt_1)?;
+
+    let id_data_2 = helper.get_member_data(NonFungibleLocalId::integer(2))?;
+    assert_eq!(id_data_2.pool_amount_staked, dec!(4000));
+
+    // Second receipt can't be redeemed yet
+    let result_fail = helper.stake_without_id(transfer_receipt_2);
+
+    assert!(result_fail.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_staking_rewards() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake 10000 tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let _ = helper.stake_without_id(bucket_1)?;
+
+    // Advance time by 1 day
+    let new_time_1 = helper.env.get_current_time().add_days(1).unwrap();
+    helper.env.set_current_time(new_time_1);
+
+    // Update rewards
+    let _ = helper.rewarded_update()?;
+
+    // Assert the reward amount
+    let amount = helper.get_real_amount()?;
+    assert_eq!(amount, dec!(2));
+
+    // Stake another 10000 tokens
+    let bucket_2 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let _ = helper.stake_without_id(bucket_2)?;
+
+    // Assert the staked amount for the second stake
+    let member_data = helper.get_member_data(NonFungibleLocalId::integer(2))?;
+    assert_eq!(member_data.pool_amount_staked, dec!(5000));
+
+    Ok(())
+}
+
+#[test]
+fn test_share_price_history() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake 10000 tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let _ = helper.stake_without_id(bucket_1)?;
+
+    // Advance time by 1 day
+    let new_time_1 = helper.env.get_current_time().add_days(1).unwrap();
+    helper.env.set_current_time(new_time_1);
+
+    // Update rewards
+    let _ = helper.rewarded_update()?;
+
+    // Assert a snapshot of the new share price was recorded
+    let history = helper.get_share_price_history(10)?;
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0], (new_time_1, dec!(2)));
+
+    Ok(())
+}
+
+#[test]
+fn test_secondary_rewards() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake 10000 and 30000 tokens on two different staking IDs
+    let stake_bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_1 = helper.stake_without_id(stake_bucket_1)?.0.unwrap();
+    let stake_bucket_2 = helper.ilis.take(dec!(30000), &mut helper.env)?;
+    let stake_id_2 = helper.stake_without_id(stake_bucket_2)?.0.unwrap();
+
+    // Stream 1000 XRD per day as a secondary reward
+    helper.set_secondary_reward(helper.xrd_address, dec!(1000))?;
+    let secondary_bucket = helper.xrd.take(dec!(5000), &mut helper.env)?;
+    helper.put_secondary_tokens(secondary_bucket)?;
+
+    // Advance time by 2 days and update rewards
+    let new_time_1 = helper.env.get_current_time().add_days(2).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.rewarded_update()?;
+
+    // Both rewards are distributed, the secondary reward proportionally to the stake of each ID
+    let (secondary_rewards_1, stake_id_2) = helper.claim_secondary_rewards(stake_id_2)?;
+    helper.assert_bucket_eq(&secondary_rewards_1, helper.xrd_address, dec!(1500))?;
+    assert!(helper.get_real_amount()? > dec!(1));
+
+    // Unstake all tokens from the second ID and advance time by another day
+    let (_unstake_receipt, stake_id_2) = helper.start_unstake(stake_id_2, dec!(30000))?;
+    let new_time_2 = helper.env.get_current_time().add_days(1).unwrap();
+    helper.env.set_current_time(new_time_2);
+
+    // The first ID earned its share of the first 2 days and all of the last day, the second ID earned nothing more
+    let (secondary_rewards_2, _stake_id_1) = helper.claim_secondary_rewards(stake_id_1)?;
+    let (secondary_rewards_3, _stake_id_2) = helper.claim_secondary_rewards(stake_id_2)?;
+    helper.assert_bucket_eq(&secondary_rewards_2, helper.xrd_address, dec!(1500))?;
+    helper.assert_bucket_eq(&secondary_rewards_3, helper.xrd_address, dec!(0))?;
+
+    // Attempt to change the secondary reward token (should fail)
+    let failure = helper.set_secondary_reward(helper.ilis_address, dec!(1000));
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_total_distributed_rewards() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    let remaining_rewards = helper.get_remaining_staking_rewards()?;
+    assert_eq!(helper.get_total_distributed_staking_rewards()?, dec!(0));
+
+    // Stake 10000 tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let _ = helper.stake_without_id(bucket_1)?;
+
+    // Advance time by 1 day and update rewards
+    let new_time_1 = helper.env.get_current_time().add_days(1).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.rewarded_update()?;
+
+    // All rewards that left the reward vault are counted as distributed
+    let distributed = helper.get_total_distributed_staking_rewards()?;
+    assert!(distributed > dec!(0));
+    assert_eq!(
+        distributed,
+        remaining_rewards - helper.get_remaining_staking_rewards()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_reward_halving_mid_period() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Halve the daily reward from 10000 to 5000 once 15000 tokens have been distributed
+    helper.set_reward_halvings(vec![(dec!(15000), dec!(5000))])?;
+
+    // Stake 10000 tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let _ = helper.stake_without_id(bucket_1)?;
+
+    // Advance time by 2 days and update rewards, crossing the threshold after 1.5 days
+    let new_time_1 = helper.env.get_current_time().add_days(2).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.rewarded_update()?;
+
+    // 1.5 days at 10000 a day, 0.5 days at 5000 a day
+    assert_eq!(helper.get_total_distributed_staking_rewards()?, dec!(17500));
+    assert!(helper.get_reward_halvings()?.is_empty());
+
+    // The new reward amount sticks
+    let new_time_2 = helper.env.get_current_time().add_days(1).unwrap();
+    helper.env.set_current_time(new_time_2);
+    let _ = helper.rewarded_update()?;
+
+    assert_eq!(helper.get_total_distributed_staking_rewards()?, dec!(22500));
+
+    Ok(())
+}
+
+#[test]
+fn test_multiple_reward_halvings_in_one_period() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Halve the daily reward to 5000 after 5000 tokens, and to 2000 after 10000 tokens distributed
+    helper.set_reward_halvings(vec![(dec!(5000), dec!(5000)), (dec!(10000), dec!(2000))])?;
+
+    // Stake 10000 tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let _ = helper.stake_without_id(bucket_1)?;
+
+    // Advance time by 3 days and update rewards, crossing both thresholds
+    let new_time_1 = helper.env.get_current_time().add_days(3).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.rewarded_update()?;
+
+    // 0.5 days at 10000 a day, 1 day at 5000 a day, 1.5 days at 2000 a day
+    assert_eq!(helper.get_total_distributed_staking_rewards()?, dec!(13000));
+
+    // Thresholds that are not ascending are rejected
+    let failure =
+        helper.set_reward_halvings(vec![(dec!(30000), dec!(1000)), (dec!(20000), dec!(500))]);
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_wind_down_staking_rewards() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    let rewards_before = helper.get_remaining_staking_rewards()?;
+
+    // Stake 10000 tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let _ = helper.stake_without_id(bucket_1)?;
+
+    // Advance time by 1 day and wind down, streaming the last day of rewards
+    let new_time_1 = helper.env.get_current_time().add_days(1).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let remaining_rewards = helper.wind_down_staking_rewards()?;
+
+    helper.assert_bucket_eq(
+        &remaining_rewards,
+        helper.ilis_address,
+        rewards_before - dec!(10000),
+    )?;
+    assert_eq!(helper.get_remaining_staking_rewards()?, dec!(0));
+
+    // No rewards are emitted afterwards
+    let new_time_2 = helper.env.get_current_time().add_days(1).unwrap();
+    helper.env.set_current_time(new_time_2);
+    let _ = helper.rewarded_update()?;
+
+    assert_eq!(helper.get_total_distributed_staking_rewards()?, dec!(10000));
+
+    Ok(())
+}
+
+#[test]
+fn test_lock_after_wind_down_staking_rewards() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake 10000 tokens and lock them for a reward
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let (stake_id, _) = helper.stake_and_lock(bucket_1, 100, true)?;
+
+    // Wind down the rewards
+    let _ = helper.wind_down_staking_rewards()?;
+
+    // Staking to the locked ID and locking for a reward still work, without taking lock rewards
+    let bucket_2 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let (_, _, stake_id) = helper.stake_with_id(bucket_2, stake_id.unwrap())?;
+    let _stake_id = helper.lock_stake(stake_id, 200, true)?;
+
+    assert_eq!(helper.get_remaining_staking_rewards()?, dec!(0));
+
+    Ok(())
+}
+
+#[test]
+fn test_freeze_staking_rewards() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake 10000 tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let _ = helper.stake_without_id(bucket_1)?;
 
-    // Unstake 5000 tokens
-    let (unstake_receipt_1, stake_id_1) = helper.start_unstake(result.0.unwrap(), dec!(5000))?;
-    let id_data_2 = helper.get_member_data(NonFungibleLocalId::integer(1))?;
+    // Advance time by 1 day and freeze, distributing the rewards of that day
+    let new_time_1 = helper.env.get_current_time().add_days(1).unwrap();
+    helper.env.set_current_time(new_time_1);
+    helper.freeze_staking_rewards()?;
 
-    // Assert the remaining staked amount
-    assert_eq!(id_data_2.pool_amount_staked, dec!(5000));
+    // No rewards are distributed while frozen
+    let new_time_2 = helper.env.get_current_time().add_days(2).unwrap();
+    helper.env.set_current_time(new_time_2);
+    let _ = helper.rewarded_update()?;
 
-    // Unstake another 1000 tokens
-    let (_unstake_receipt_2, stake_id_2) = helper.start_unstake(stake_id_1, dec!(1000))?;
+    assert_eq!(helper.get_total_distributed_staking_rewards()?, dec!(10000));
 
-    // Unstake the remaining 4000 tokens
-    let (unstake_receipt_3, _) = helper.start_unstake(stake_id_2, dec!(6000))?;
-    let id_data_3 = helper.get_member_data(NonFungibleLocalId::integer(1))?;
+    // After unfreezing, rewards accrue again, without those of the frozen window
+    helper.unfreeze_staking_rewards()?;
+    let new_time_3 = helper.env.get_current_time().add_days(1).unwrap();
+    helper.env.set_current_time(new_time_3);
+    let _ = helper.rewarded_update()?;
 
-    // Assert that no tokens are left staked
-    assert_eq!(id_data_3.pool_amount_staked, dec!(0));
+    assert_eq!(helper.get_total_distributed_staking_rewards()?, dec!(20000));
 
-    // Advance time by 7 days
-    let new_time_1 = helper.env.get_current_time().add_days(7).unwrap();
+    // Unfreezing twice fails
+    let failure = helper.unfreeze_staking_rewards();
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_caller_reward_update_period() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    helper.set_staking_caller_reward(dec!(1))?;
+
+    // Advance time by 1 day
+    let new_time_1 = helper.env.get_current_time().add_days(1).unwrap();
     helper.env.set_current_time(new_time_1);
 
-    // Finish unstaking and assert the returned amounts
-    let unstaked_bucket_1 = helper.finish_unstake(unstake_receipt_1)?;
-    let unstaked_bucket_2 = helper.finish_unstake(unstake_receipt_3)?;
+    // First caller is rewarded
+    let reward_1 = helper.rewarded_update_staking_period()?;
+    assert_eq!(reward_1.amount(&mut helper.env)?, dec!(1));
 
-    helper.assert_bucket_eq(&unstaked_bucket_1, helper.ilis_address, dec!(5000))?;
-    helper.assert_bucket_eq(&unstaked_bucket_2, helper.ilis_address, dec!(4000))?;
+    // Calling again right away is not rewarded
+    let reward_2 = helper.rewarded_update_staking_period()?;
+    assert_eq!(reward_2.amount(&mut helper.env)?, dec!(0));
 
     Ok(())
 }
 
 #[test]
-fn test_unstake_before_time() -> Result<(), RuntimeError> {
+fn test_warmup_unstake_forfeits_rewards() -> Result<(), RuntimeError> {
     let mut helper = Helper::new().unwrap();
+    helper.set_staking_warmup_days(7)?;
 
-    // Stake 10000 tokens
-    let stake_bucket = helper.ilis.take(dec!(10000), &mut helper.env)?;
-    let result = helper.stake_without_id(stake_bucket)?;
+    // Stake 10000 tokens right before rewards are distributed
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+    let remaining_rewards = helper.get_remaining_staking_rewards()?;
 
-    // Start unstaking 5000 tokens
-    let (unstake_receipt_1, _stake_id_1) = helper.start_unstake(result.0.unwrap(), dec!(5000))?;
+    // Advance time by 1 day and update rewards
+    let new_time_1 = helper.env.get_current_time().add_days(1).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.rewarded_update()?;
 
-    // Attempt to finish unstaking immediately (should fail)
-    let unstaked_bucket_fail = helper.finish_unstake(unstake_receipt_1);
+    // Unstake everything while still in warm-up
+    let (receipt, _stake_id) = helper.start_unstake(stake_id, dec!(10000))?;
 
-    assert!(unstaked_bucket_fail.is_err());
+    // Advance time by 7 days and finish unstaking
+    let new_time_2 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_2);
+    let unstaked = helper.finish_unstake(receipt)?;
+
+    // Assert only the staked tokens are returned, and the rewards went back to the reward vault
+    let unstaked_amount = unstaked.amount(&mut helper.env)?;
+    assert!(unstaked_amount <= dec!(10000));
+    assert!(unstaked_amount > dec!("9999.99"));
+    assert!(helper.get_remaining_staking_rewards()? > remaining_rewards - dec!("0.01"));
 
     Ok(())
 }
 
 #[test]
-fn test_transfer_stake() -> Result<(), RuntimeError> {
+fn test_passed_warmup_forfeits_rewards() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    helper.set_staking_warmup_days(7)?;
+
+    // Stake 10000 tokens right before rewards are distributed
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+    let remaining_rewards = helper.get_remaining_staking_rewards()?;
+
+    // Advance time by 1 day and update rewards
+    let new_time_1 = helper.env.get_current_time().add_days(1).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.rewarded_update()?;
+
+    // Try to settle the warm-up while it is still ongoing
+    let failed_settle = helper.settle_staking_warmup(NonFungibleLocalId::integer(1));
+
+    // Advance time by 7 days so the warm-up has passed, and settle it
+    let new_time_2 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_2);
+    helper.settle_staking_warmup(NonFungibleLocalId::integer(1))?;
+
+    // Assert the rewards earned during the warm-up went back to the reward vault
+    let id_data = helper.get_member_data(NonFungibleLocalId::integer(1))?;
+    let real_amount = id_data.pool_amount_staked * helper.get_real_amount()?;
+    assert!(id_data.warmup_tranches.is_empty());
+    assert!(real_amount <= dec!(10000));
+    assert!(real_amount > dec!("9999.99"));
+    assert!(helper.get_remaining_staking_rewards()? > remaining_rewards - dec!("0.01"));
+
+    // Assert the settled tokens earn rewards again, and the unstaked amount includes them
+    let new_time_3 = helper.env.get_current_time().add_days(1).unwrap();
+    helper.env.set_current_time(new_time_3);
+    let _ = helper.rewarded_update()?;
+    let (receipt, _stake_id) = helper.start_unstake(stake_id, dec!(10000))?;
+    let new_time_4 = helper.env.get_current_time().add_days(7).unwrap();
+    helper.env.set_current_time(new_time_4);
+    let unstaked = helper.finish_unstake(receipt)?;
+    assert!(unstaked.amount(&mut helper.env)? > dec!(10000));
+
+    assert!(failed_settle.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_settling_warmup_keeps_later_rewards() -> Result<(), RuntimeError> {
     let mut helper = Helper::new().unwrap();
+    helper.set_staking_warmup_days(7)?;
 
     // Stake 10000 tokens
-    let stake_bucket = helper.ilis.take(dec!(10000), &mut helper.env)?;
-    let result = helper.stake_without_id(stake_bucket)?;
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let _stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
 
-    // Transfer 4000 tokens to a new stake
-    let (transfer_receipt, _stake_id) =
-        helper.start_unstake_transfer(result.0.unwrap(), dec!(4000))?;
+    // Update rewards daily for 10 days, so rewards are earned during and after the warm-up
+    for _ in 0..10 {
+        let new_time = helper.env.get_current_time().add_days(1).unwrap();
+        helper.env.set_current_time(new_time);
+        let _ = helper.rewarded_update()?;
+    }
 
-    let _result_2 = helper.stake_without_id(transfer_receipt)?;
+    let id_data_before = helper.get_member_data(NonFungibleLocalId::integer(1))?;
+    let real_amount_before = id_data_before.pool_amount_staked * helper.get_real_amount()?;
+    let remaining_rewards_before = helper.get_remaining_staking_rewards()?;
 
-    // Assert the amounts in both stakes
-    let id_data_1 = helper.get_member_data(NonFungibleLocalId::integer(1))?;
-    assert_eq!(id_data_1.pool_amount_staked, dec!(6000));
+    // Settle the warm-up
+    helper.settle_staking_warmup(NonFungibleLocalId::integer(1))?;
 
-    let id_data_2 = helper.get_member_data(NonFungibleLocalId::integer(2))?;
-    assert_eq!(id_data_2.pool_amount_staked, dec!(4000));
+    // Assert only the rewards earned during the warm-up are forfeited, the rewards earned after it are kept
+    let id_data_after = helper.get_member_data(NonFungibleLocalId::integer(1))?;
+    let real_amount_after = id_data_after.pool_amount_staked * helper.get_real_amount()?;
+    assert!(real_amount_after < real_amount_before);
+    assert!(real_amount_after > dec!(10000));
+    assert!(helper.get_remaining_staking_rewards()? > remaining_rewards_before);
 
     Ok(())
 }
 
 #[test]
-fn test_staking_rewards() -> Result<(), RuntimeError> {
+fn test_staking_during_warmup_keeps_earlier_warmup() -> Result<(), RuntimeError> {
     let mut helper = Helper::new().unwrap();
+    helper.set_staking_warmup_days(7)?;
 
     // Stake 10000 tokens
     let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
-    let _ = helper.stake_without_id(bucket_1)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
 
-    // Advance time by 1 day
-    let new_time_1 = helper.env.get_current_time().add_days(1).unwrap();
+    // Advance time by 4 days, update rewards and stake another 5000 tokens to the same ID
+    let new_time_1 = helper.env.get_current_time().add_days(4).unwrap();
     helper.env.set_current_time(new_time_1);
+    let _ = helper.rewarded_update()?;
+    let bucket_2 = helper.ilis.take(dec!(5000), &mut helper.env)?;
+    let _ = helper.stake_with_id(bucket_2, stake_id)?;
 
-    // Update rewards
+    // Advance time by 3 days, the warm-up of the first tokens has passed and can be settled
+    let new_time_2 = helper.env.get_current_time().add_days(3).unwrap();
+    helper.env.set_current_time(new_time_2);
     let _ = helper.rewarded_update()?;
+    helper.settle_staking_warmup(NonFungibleLocalId::integer(1))?;
 
-    // Assert the reward amount
-    let amount = helper.get_real_amount()?;
-    assert_eq!(amount, dec!(2));
+    // Assert only the later tokens are still in warm-up
+    let id_data = helper.get_member_data(NonFungibleLocalId::integer(1))?;
+    assert_eq!(id_data.warmup_tranches.len(), 1);
 
-    // Stake another 10000 tokens
-    let bucket_2 = helper.ilis.take(dec!(10000), &mut helper.env)?;
-    let _ = helper.stake_without_id(bucket_2)?;
+    Ok(())
+}
 
-    // Assert the staked amount for the second stake
-    let member_data = helper.get_member_data(NonFungibleLocalId::integer(2))?;
-    assert_eq!(member_data.pool_amount_staked, dec!(5000));
+#[test]
+fn test_no_rewards_streamed_without_stakers() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+    let remaining_rewards = helper.get_remaining_staking_rewards()?;
+
+    // Update rewards for several days without anyone staked
+    for _ in 0..3 {
+        let new_time = helper.env.get_current_time().add_days(1).unwrap();
+        helper.env.set_current_time(new_time);
+        let _ = helper.rewarded_update()?;
+    }
+
+    // Assert no rewards were streamed into the empty pool
+    assert_eq!(helper.get_remaining_staking_rewards()?, remaining_rewards);
+    assert_eq!(helper.get_share_price_history(10)?.len(), 0);
+
+    // Stake 10000 tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let _ = helper.stake_without_id(bucket_1)?;
+
+    // Advance time by 1 day and update rewards
+    let new_time_1 = helper.env.get_current_time().add_days(1).unwrap();
+    helper.env.set_current_time(new_time_1);
+    let _ = helper.rewarded_update()?;
+
+    // Assert the first staker received all rewards accrued since the start (4 days of 10000)
+    let history = helper.get_share_price_history(10)?;
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0], (new_time_1, dec!(5)));
 
     Ok(())
 }
@@ -180,6 +974,33 @@ fn test_locking() -> Result<(), RuntimeError> {
     Ok(())
 }
 
+#[test]
+fn test_stake_and_lock() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake and lock 10000 tokens for 10 days in one call
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let result = helper.stake_and_lock(bucket_1, 10, true)?;
+
+    let stake_id = result.0.unwrap();
+
+    // Assert the locked amount and duration
+    let member_data = helper.get_member_data(NonFungibleLocalId::integer(1))?;
+    assert!(member_data.pool_amount_staked > dec!(10100));
+    assert!(member_data.pool_amount_staked < dec!(10101));
+    assert_eq!(
+        member_data.locked_until.unwrap(),
+        helper.env.get_current_time().add_days(10).unwrap()
+    );
+
+    // Attempt to unstake immediately (should fail)
+    let failure = helper.start_unstake(stake_id, dec!(5000));
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
 #[test]
 fn test_lock_too_long() -> Result<(), RuntimeError> {
     let mut helper = Helper::new().unwrap();
@@ -198,6 +1019,35 @@ fn test_lock_too_long() -> Result<(), RuntimeError> {
     Ok(())
 }
 
+#[test]
+fn test_relock_near_cap() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake 10000 tokens and lock them for 360 days
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let result = helper.stake_without_id(bucket_1)?;
+    let stake_id = helper.lock_stake(result.0.unwrap(), 360, true)?;
+
+    // Advance time by 10 days, so the lock ends in 350 days
+    let new_time = helper.env.get_current_time().add_days(10).unwrap();
+    helper.env.set_current_time(new_time);
+
+    // Relocking up to the cap extends the existing lock
+    let stake_id = helper.lock_stake(stake_id, 15, true)?;
+    let member_data = helper.get_member_data(NonFungibleLocalId::integer(1))?;
+    assert_eq!(
+        member_data.locked_until.unwrap(),
+        new_time.add_days(365).unwrap()
+    );
+
+    // Relocking beyond the cap fails
+    let failure = helper.lock_stake(stake_id, 1, true);
+
+    assert!(failure.is_err());
+
+    Ok(())
+}
+
 #[test]
 fn test_lock_and_unstake() -> Result<(), RuntimeError> {
     let mut helper = Helper::new().unwrap();
@@ -242,6 +1092,30 @@ fn test_lock_and_unstake_too_early() -> Result<(), RuntimeError> {
     Ok(())
 }
 
+#[test]
+fn test_force_unlock_and_unstake() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake 10000 tokens
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let result = helper.stake_without_id(bucket_1)?;
+
+    let stake_id = result.0.unwrap();
+
+    // Lock the stake for 10 days
+    let returned_stake_id = helper.lock_stake(stake_id, 10, true)?;
+
+    // Force unlock the stake for 10 days (as the DAO)
+    helper.env.disable_auth_module();
+    helper.force_unlock(NonFungibleLocalId::integer(1), 10)?;
+    helper.env.enable_auth_module();
+
+    // Unstake immediately (should succeed without payment)
+    let _ = helper.start_unstake(returned_stake_id, dec!(5000))?;
+
+    Ok(())
+}
+
 #[test]
 fn test_lock_and_unlock_too_far() -> Result<(), RuntimeError> {
     let mut helper = Helper::new().unwrap();
@@ -318,6 +1192,110 @@ fn test_unlock_to_unstake_partial_pay_off() -> Result<(), RuntimeError> {
     Ok(())
 }
 
+#[test]
+fn test_unlock_with_clawback_not_profitable() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Make unlocking cheaper than the lock reward, and enable the clawback with a 10% penalty
+    helper.edit_staking_stakable(dec!(10000), dec!(1.01), 365, dec!(1.001))?;
+    helper.set_lock_clawback(Some(dec!(0.1)))?;
+
+    // Stake 10000 tokens and lock them for 10 days for a reward
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+    let stake_id = helper.lock_stake(stake_id, 10, true)?;
+
+    let lock_reward = helper
+        .get_member_data(NonFungibleLocalId::integer(1))?
+        .lock_reward_granted;
+    assert!(lock_reward > dec!(0));
+
+    // Unlock all 10 days, which costs more than the lock reward
+    let payment_bucket = helper.ilis.take(dec!(2000), &mut helper.env)?;
+    let (_stake_id, leftover_payment) = helper.unlock_stake(stake_id, payment_bucket, 10)?;
+    let paid = dec!(2000) - leftover_payment.amount(&mut helper.env)?;
+
+    assert!(paid >= lock_reward * dec!(1.1));
+    assert_eq!(
+        helper
+            .get_member_data(NonFungibleLocalId::integer(1))?
+            .lock_reward_granted,
+        dec!(0)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_partial_unlock_with_clawback_not_profitable() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    helper.edit_staking_stakable(dec!(10000), dec!(1.01), 365, dec!(1.001))?;
+    helper.set_lock_clawback(Some(dec!(0)))?;
+
+    // Stake 10000 tokens and lock them for 10 days for a reward
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+    let stake_id = helper.lock_stake(stake_id, 10, true)?;
+
+    let lock_reward = helper
+        .get_member_data(NonFungibleLocalId::integer(1))?
+        .lock_reward_granted;
+
+    // Unlock 5 of the 10 days, which claws back half of the lock reward
+    let payment_bucket = helper.ilis.take(dec!(2000), &mut helper.env)?;
+    let (stake_id, leftover_payment) = helper.unlock_stake(stake_id, payment_bucket, 5)?;
+    let paid_1 = dec!(2000) - leftover_payment.amount(&mut helper.env)?;
+
+    assert_eq!(paid_1, lock_reward / dec!(2));
+
+    // Unlocking the other 5 days claws back the rest, so the full unlock repays the full lock reward
+    let payment_bucket = helper.ilis.take(dec!(2000), &mut helper.env)?;
+    let (_stake_id, leftover_payment) = helper.unlock_stake(stake_id, payment_bucket, 5)?;
+    let paid_2 = dec!(2000) - leftover_payment.amount(&mut helper.env)?;
+
+    assert_eq!(paid_1 + paid_2, lock_reward);
+
+    Ok(())
+}
+
+#[test]
+fn test_stake_to_locked_id_adds_lock_reward_granted() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    helper.edit_staking_stakable(dec!(10000), dec!(1.01), 365, dec!(1.001))?;
+    helper.set_lock_clawback(Some(dec!(0)))?;
+
+    // Stake 10000 tokens and lock them for 10 days for a reward
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id = helper.stake_without_id(bucket_1)?.0.unwrap();
+    let stake_id = helper.lock_stake(stake_id, 10, true)?;
+
+    let first_lock_reward = helper
+        .get_member_data(NonFungibleLocalId::integer(1))?
+        .lock_reward_granted;
+
+    // Staking more to the locked ID pays a lock reward for the added tokens, which is granted as well
+    let bucket_2 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let (_, second_lock_reward, stake_id) = helper.stake_with_id(bucket_2, stake_id)?;
+    let second_lock_reward = second_lock_reward.unwrap().amount(&mut helper.env)?;
+    let lock_reward = helper
+        .get_member_data(NonFungibleLocalId::integer(1))?
+        .lock_reward_granted;
+
+    assert!(second_lock_reward > dec!(0));
+    assert_eq!(lock_reward, first_lock_reward + second_lock_reward);
+
+    // Unlocking all 10 days claws back both lock rewards
+    let payment_bucket = helper.ilis.take(dec!(5000), &mut helper.env)?;
+    let (_stake_id, leftover_payment) = helper.unlock_stake(stake_id, payment_bucket, 10)?;
+    let paid = dec!(5000) - leftover_payment.amount(&mut helper.env)?;
+
+    assert!(paid >= lock_reward);
+
+    Ok(())
+}
+
 #[test]
 fn test_delegate_and_undelegate() -> Result<(), RuntimeError> {
     let mut helper = Helper::new().unwrap();
@@ -358,6 +1336,63 @@ fn test_delegate_and_undelegate() -> Result<(), RuntimeError> {
     Ok(())
 }
 
+#[test]
+fn test_effective_vote_power() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake 10000 tokens for two different stakes
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_1 = helper.stake_without_id(bucket_1)?.0.unwrap();
+    let bucket_2 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let _stake_id_2 = helper.stake_without_id(bucket_2)?.0.unwrap();
+
+    assert_eq!(
+        helper.get_effective_vote_power(NonFungibleLocalId::integer(2))?,
+        dec!(10000)
+    );
+
+    // Delegate voting power from stake 1 to stake 2, which now carries the vote power of both
+    let _stake_id_1 = helper.delegate_vote(stake_id_1, NonFungibleLocalId::integer(2))?;
+
+    assert_eq!(
+        helper.get_effective_vote_power(NonFungibleLocalId::integer(2))?,
+        dec!(20000)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_total_delegated() -> Result<(), RuntimeError> {
+    let mut helper = Helper::new().unwrap();
+
+    // Stake 10000 tokens for two different stakes
+    let bucket_1 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let stake_id_1 = helper.stake_without_id(bucket_1)?.0.unwrap();
+    let bucket_2 = helper.ilis.take(dec!(10000), &mut helper.env)?;
+    let _stake_id_2 = helper.stake_without_id(bucket_2)?.0.unwrap();
+
+    assert_eq!(helper.get_total_delegated()?, dec!(0));
+
+    // Delegate voting power from stake 1 to stake 2
+    let stake_id_1 = helper.delegate_vote(stake_id_1, NonFungibleLocalId::integer(2))?;
+
+    assert_eq!(helper.get_total_delegated()?, dec!(10000));
+
+    // Staking more to the delegating ID increases the delegated voting power
+    let bucket_3 = helper.ilis.take(dec!(5000), &mut helper.env)?;
+    let (_, _, stake_id_1) = helper.stake_with_id(bucket_3, stake_id_1)?;
+
+    assert_eq!(helper.get_total_delegated()?, dec!(15000));
+
+    // Undelegate voting power
+    let _ = helper.undelegate_vote(stake_id_1)?;
+
+    assert_eq!(helper.get_total_delegated()?, dec!(0));
+
+    Ok(())
+}
+
 #[test]
 fn test_delegate_and_fail_unstake() -> Result<(), RuntimeError> {
     let mut helper = Helper::new().unwrap();